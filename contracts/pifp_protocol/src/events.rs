@@ -1,4 +1,7 @@
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
+
+use crate::rbac::Role;
+use crate::types::ProjectStatus;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,12 +20,63 @@ pub struct ProjectFunded {
     pub amount: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deposited {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Project balance of `token` after this deposit.
+    pub new_total: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImpactReported {
+    pub project_id: u64,
+    /// Creator or oracle that reported the metric.
+    pub reporter: Address,
+    pub value: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositMemo {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Donor-supplied note or campaign tag.
+    pub memo: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayedDeposit {
+    pub project_id: u64,
+    /// Address that paid for the deposit.
+    pub relayer: Address,
+    /// Address credited with the deposit.
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectActive {
     pub project_id: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChanged {
+    pub project_id: u64,
+    pub old_status: ProjectStatus,
+    pub new_status: ProjectStatus,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectVerified {
@@ -31,6 +85,57 @@ pub struct ProjectVerified {
     pub proof_hash: BytesN<32>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApproved {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub approvals: u32,
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneReleased {
+    pub project_id: u64,
+    pub milestone_index: u32,
+    pub oracle: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub project_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoalUpdated {
+    pub project_id: u64,
+    pub old_goal: i128,
+    pub new_goal: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringScheduled {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub interval_secs: u64,
+    pub occurrences: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectCancelled {
+    pub project_id: u64,
+    pub creator: Address,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectExpired {
@@ -41,11 +146,75 @@ pub struct ProjectExpired {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FundsReleased {
+    pub project_id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsWithdrawn {
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NonceReplayed {
+    pub project_id: u64,
+    pub donator: Address,
+    pub nonce: BytesN<32>,
+    /// `true` if `nonce` was below the donor's latest one rather than equal.
+    pub stale: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlBumped {
+    pub project_id: u64,
+    /// Ledgers each entry is guaranteed to live after the bump.
+    pub ledgers: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollected {
     pub project_id: u64,
     pub token: Address,
+    pub treasury: Address,
     pub amount: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsSwept {
+    pub project_id: u64,
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchFunded {
+    pub project_id: u64,
+    pub sponsor: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchSettled {
+    pub project_id: u64,
+    pub token: Address,
+    pub matched: i128,
+    pub returned: i128,
+}
+
 pub fn emit_project_created(
     env: &Env,
     project_id: u64,
@@ -73,6 +242,89 @@ pub fn emit_project_funded(env: &Env, project_id: u64, donator: Address, amount:
     env.events().publish(topics, data);
 }
 
+pub fn emit_impact_reported(env: &Env, project_id: u64, reporter: Address, value: i128) {
+    let topics = (symbol_short!("impact"), project_id);
+    let data = ImpactReported {
+        project_id,
+        reporter,
+        value,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Follows the `deposit` event of a `deposit_with_memo` call.
+pub fn emit_deposit_memo(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    token: Address,
+    amount: i128,
+    memo: Symbol,
+) {
+    let topics = (symbol_short!("memo"), project_id);
+    let data = DepositMemo {
+        project_id,
+        donator,
+        token,
+        amount,
+        memo,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_relayed_deposit(
+    env: &Env,
+    project_id: u64,
+    relayer: Address,
+    beneficiary: Address,
+    token: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("relayed"), project_id);
+    let data = RelayedDeposit {
+        project_id,
+        relayer,
+        beneficiary,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_deposit(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    token: Address,
+    amount: i128,
+    new_total: i128,
+) {
+    let topics = (symbol_short!("deposit"), project_id, token.clone());
+    let data = Deposited {
+        project_id,
+        donator,
+        token,
+        amount,
+        new_total,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_status_change(
+    env: &Env,
+    project_id: u64,
+    old_status: ProjectStatus,
+    new_status: ProjectStatus,
+) {
+    let topics = (symbol_short!("status"), project_id);
+    let data = StatusChanged {
+        project_id,
+        old_status,
+        new_status,
+    };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_project_active(env: &Env, project_id: u64) {
     let topics = (symbol_short!("active"), project_id);
     let data = ProjectActive { project_id };
@@ -89,6 +341,98 @@ pub fn emit_project_verified(env: &Env, project_id: u64, oracle: Address, proof_
     env.events().publish(topics, data);
 }
 
+pub fn emit_release_pending(env: &Env, project_id: u64, unlock_at: u64) {
+    let topics = (symbol_short!("rel_pend"), project_id);
+    env.events().publish(topics, unlock_at);
+}
+
+pub fn emit_release_cancelled(env: &Env, project_id: u64, by: Address) {
+    let topics = (symbol_short!("rel_cncl"), project_id);
+    env.events().publish(topics, by);
+}
+
+pub fn emit_release_approved(
+    env: &Env,
+    project_id: u64,
+    oracle: Address,
+    approvals: u32,
+    threshold: u32,
+) {
+    let topics = (symbol_short!("approved"), project_id);
+    let data = ReleaseApproved {
+        project_id,
+        oracle,
+        approvals,
+        threshold,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_milestone_released(env: &Env, project_id: u64, milestone_index: u32, oracle: Address) {
+    let topics = (symbol_short!("milestone"), project_id);
+    let data = MilestoneReleased {
+        project_id,
+        milestone_index,
+        oracle,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_deadline_extended(env: &Env, project_id: u64, old_deadline: u64, new_deadline: u64) {
+    let topics = (symbol_short!("extended"), project_id);
+    let data = DeadlineExtended {
+        project_id,
+        old_deadline,
+        new_deadline,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_recurring_scheduled(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    token: Address,
+    amount: i128,
+    interval_secs: u64,
+    occurrences: u32,
+) {
+    let topics = (symbol_short!("recurring"), project_id);
+    let data = RecurringScheduled {
+        project_id,
+        donator,
+        token,
+        amount,
+        interval_secs,
+        occurrences,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_token_added(env: &Env, project_id: u64, token: Address) {
+    let topics = (symbol_short!("tok_added"), project_id);
+    env.events().publish(topics, token);
+}
+
+pub fn emit_goal_updated(env: &Env, project_id: u64, old_goal: i128, new_goal: i128) {
+    let topics = (symbol_short!("goal"), project_id);
+    let data = GoalUpdated {
+        project_id,
+        old_goal,
+        new_goal,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_cancelled(env: &Env, project_id: u64, creator: Address) {
+    let topics = (symbol_short!("cancelled"), project_id);
+    let data = ProjectCancelled {
+        project_id,
+        creator,
+    };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
     let topics = (symbol_short!("expired"), project_id);
     let data = ProjectExpired {
@@ -98,22 +442,150 @@ pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
     env.events().publish(topics, data);
 }
 
-pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i128) {
+pub fn emit_release(env: &Env, project_id: u64, creator: Address, token: Address, amount: i128) {
     let topics = (symbol_short!("released"), project_id, token.clone());
     let data = FundsReleased {
+        project_id,
+        creator,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_completed(env: &Env, project_id: u64) {
+    let topics = (symbol_short!("completed"), project_id);
+    env.events().publish(topics, project_id);
+}
+
+pub fn emit_fee_collected(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    treasury: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("fee"), project_id, token.clone());
+    let data = FeeCollected {
         project_id,
         token,
+        treasury,
         amount,
     };
     env.events().publish(topics, data);
 }
 
-pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128) {
+pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128, to: Address) {
     let topics = (symbol_short!("refunded"), project_id);
-    let data = (donator, amount);
+    let data = (donator, amount, to);
+    env.events().publish(topics, data);
+}
+
+pub fn emit_swept(env: &Env, project_id: u64, token: Address, treasury: Address, amount: i128) {
+    let topics = (symbol_short!("swept"), project_id, token.clone());
+    let data = FundsSwept {
+        project_id,
+        token,
+        treasury,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_match_funded(
+    env: &Env,
+    project_id: u64,
+    sponsor: Address,
+    token: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("match_add"), project_id);
+    let data = MatchFunded {
+        project_id,
+        sponsor,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+/// `matched` joined the project's payout; `returned` went back to the sponsor.
+pub fn emit_match_settled(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    matched: i128,
+    returned: i128,
+) {
+    let topics = (symbol_short!("matched"), project_id, token.clone());
+    let data = MatchSettled {
+        project_id,
+        token,
+        matched,
+        returned,
+    };
+    env.events().publish(topics, data);
+}
+
+/// A `deposit_with_nonce` call was ignored because `nonce` was not above
+/// the donor's last one.
+pub fn emit_nonce_replayed(
+    env: &Env,
+    project_id: u64,
+    donator: Address,
+    nonce: BytesN<32>,
+    stale: bool,
+) {
+    let topics = (symbol_short!("dup_nonce"), project_id);
+    let data = NonceReplayed {
+        project_id,
+        donator,
+        nonce,
+        stale,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_withdrawn(env: &Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+    let topics = (symbol_short!("withdraw"), project_id);
+    let data = FundsWithdrawn {
+        project_id,
+        donator,
+        token,
+        amount,
+    };
     env.events().publish(topics, data);
 }
 
+pub fn emit_ttl_bumped(env: &Env, project_id: u64, ledgers: u32) {
+    let topics = (Symbol::new(env, "ttl_bumped"), project_id);
+    let data = TtlBumped {
+        project_id,
+        ledgers,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_role_granted(env: &Env, account: Address, role: Role, by: Option<Address>) {
+    let topics = (symbol_short!("role_set"), account, role_symbol(&role));
+    env.events().publish(topics, by);
+}
+
+pub fn emit_role_revoked(env: &Env, account: Address, role: Role, by: Option<Address>) {
+    let topics = (symbol_short!("role_del"), account, role_symbol(&role));
+    env.events().publish(topics, by);
+}
+
+fn role_symbol(role: &Role) -> Symbol {
+    match role {
+        Role::SuperAdmin => symbol_short!("supadmin"),
+        Role::Admin => symbol_short!("admin"),
+        Role::Oracle => symbol_short!("oracle"),
+        Role::Auditor => symbol_short!("auditor"),
+        Role::ProjectManager => symbol_short!("proj_mgr"),
+    }
+}
+
 pub fn emit_protocol_paused(env: &Env, admin: Address) {
     env.events().publish((symbol_short!("paused"), admin), ());
 }
@@ -122,6 +594,118 @@ pub fn emit_protocol_unpaused(env: &Env, admin: Address) {
     env.events().publish((symbol_short!("unpaused"), admin), ());
 }
 
+pub fn emit_token_paused(env: &Env, admin: Address, token: Address) {
+    env.events()
+        .publish((symbol_short!("tok_pause"), admin), token);
+}
+
+pub fn emit_token_unpaused(env: &Env, admin: Address, token: Address) {
+    env.events()
+        .publish((symbol_short!("tok_unpse"), admin), token);
+}
+
+pub fn emit_token_allowed(env: &Env, admin: Address, token: Address) {
+    env.events()
+        .publish((symbol_short!("tok_allow"), admin), token);
+}
+
+pub fn emit_token_disallowed(env: &Env, admin: Address, token: Address) {
+    env.events()
+        .publish((symbol_short!("tok_deny"), admin), token);
+}
+
+pub fn emit_upgraded(env: &Env, new_wasm_hash: BytesN<32>, version: u32) {
+    let topics = (symbol_short!("upgraded"),);
+    let data = Upgraded {
+        new_wasm_hash,
+        version,
+    };
+    env.events().publish(topics, data);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleBondChanged {
+    pub token: Address,
+    /// Amount staked, unstaked or slashed.
+    pub amount: i128,
+    /// Bond the oracle holds in `token` afterwards.
+    pub stake: i128,
+}
+
+/// Topic `staked` or `unstaked`, followed by the oracle.
+pub fn emit_oracle_bond_changed(
+    env: &Env,
+    action: Symbol,
+    oracle: Address,
+    token: Address,
+    amount: i128,
+    stake: i128,
+) {
+    let topics = (action, oracle);
+    let data = OracleBondChanged {
+        token,
+        amount,
+        stake,
+    };
+    env.events().publish(topics, data);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleSlashed {
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: i128,
+    /// Bond the oracle holds in `token` afterwards.
+    pub stake: i128,
+}
+
+pub fn emit_oracle_slashed(
+    env: &Env,
+    oracle: Address,
+    token: Address,
+    treasury: Address,
+    amount: i128,
+    stake: i128,
+) {
+    let topics = (symbol_short!("slashed"), oracle);
+    let data = OracleSlashed {
+        token,
+        treasury,
+        amount,
+        stake,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_emergency_withdrawal(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    to: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("emergency"), admin);
+    let data = EmergencyWithdrawal { token, to, amount };
+    env.events().publish(topics, data);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub version: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawal {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GasMeasurementEvent {
@@ -130,10 +714,22 @@ pub struct GasMeasurementEvent {
     pub timestamp: u64,
 }
 
-pub fn emit_gas_measurement(env: &Env, operation: &str, gas_used: u64, timestamp: u64) {
-    let topics = (symbol_short!("gas_measure"),);
+/// Topics: `("gas", operation, category)`; `category` is void when the
+/// operation has none, so tooling can group measurements by it.
+pub fn emit_gas_measurement(
+    env: &Env,
+    operation: &str,
+    category: Option<Symbol>,
+    gas_used: u64,
+    timestamp: u64,
+) {
+    let topics = (
+        symbol_short!("gas"),
+        String::from_str(env, operation),
+        category,
+    );
     let data = GasMeasurementEvent {
-        operation: operation.to_string(),
+        operation: String::from_str(env, operation),
         gas_used,
         timestamp,
     };
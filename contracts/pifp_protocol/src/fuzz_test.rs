@@ -2,12 +2,14 @@ extern crate std;
 use std::vec::Vec;
 
 use proptest::prelude::*;
-use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, Vec as SorobanVec};
+use soroban_sdk::{
+    testutils::Address as _, token, Address, Bytes, BytesN, Env, String, Symbol, Vec as SorobanVec,
+};
 
 use crate::invariants::*;
 pub use crate::types::ProjectStatus;
 pub use crate::Role;
-use crate::{PifpProtocol, PifpProtocolClient};
+use crate::{PifpProtocol, PifpProtocolClient, ProjectMetadata};
 
 // ── Helpers ─────────────────────────────────────────────────────────
 
@@ -26,6 +28,14 @@ fn create_token<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
     token::Client::new(env, &addr.address())
 }
 
+fn dummy_metadata(env: &Env) -> ProjectMetadata {
+    ProjectMetadata {
+        name: Symbol::new(env, "project"),
+        metadata_uri: String::from_str(env, "ipfs://project"),
+        category: Symbol::new(env, "general"),
+    }
+}
+
 // ── 1. Registration Fuzz Tests ──────────────────────────────────────
 
 proptest! {
@@ -51,6 +61,8 @@ proptest! {
             &goal,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         assert_all_project_invariants(&project);
@@ -78,6 +90,8 @@ proptest! {
             &100,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         assert_all_project_invariants(&project);
@@ -104,6 +118,8 @@ proptest! {
             &1000,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         assert_all_project_invariants(&project);
@@ -136,6 +152,8 @@ proptest! {
             &100_000,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         let donator = Address::generate(&env);
@@ -174,6 +192,8 @@ proptest! {
             &1_000_000,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         let sac = token::StellarAssetClient::new(&env, &token_client.address);
@@ -218,7 +238,7 @@ proptest! {
 
         let token_admin = Address::generate(&env);
         let token = create_token(&env, &token_admin);
-        let proof_hash = BytesN::from_array(&env, &stored_bytes);
+        let proof_hash: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &stored_bytes)).into();
         let deadline = env.ledger().timestamp() + 86_400;
 
         let mut tokens = SorobanVec::new(&env);
@@ -230,13 +250,15 @@ proptest! {
             &500,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         let oracle = Address::generate(&env);
         client.set_oracle(&admin, &oracle);
 
-        let wrong_hash = BytesN::from_array(&env, &submitted_bytes);
-        let result = client.try_verify_and_release(&oracle, &project.id, &wrong_hash);
+        let wrong_preimage = Bytes::from_array(&env, &submitted_bytes);
+        let result = client.try_verify_and_release(&oracle, &project.id, &wrong_preimage);
         prop_assert!(result.is_err(), "verify_and_release should fail with wrong hash");
     }
 
@@ -250,7 +272,8 @@ proptest! {
 
         let token_admin = Address::generate(&env);
         let token = create_token(&env, &token_admin);
-        let proof_hash = BytesN::from_array(&env, &hash_bytes);
+        let preimage = Bytes::from_array(&env, &hash_bytes);
+        let proof_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
         let deadline = env.ledger().timestamp() + 86_400;
 
         let mut tokens = SorobanVec::new(&env);
@@ -262,12 +285,15 @@ proptest! {
             &500,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         let oracle = Address::generate(&env);
         client.set_oracle(&admin, &oracle);
+        client.set_allow_partial_release(&creator, &project.id, &true);
 
-        client.verify_and_release(&oracle, &project.id, &proof_hash);
+        client.verify_and_release(&oracle, &project.id, &preimage);
 
         let updated = client.get_project(&project.id);
         assert_valid_status_transition(&ProjectStatus::Funding, &updated.status);
@@ -302,6 +328,8 @@ proptest! {
                 &1000,
                 &proof_hash,
                 &deadline,
+                &dummy_metadata(&env),
+                &None,
             );
             projects.push(p);
         }
@@ -335,6 +363,8 @@ proptest! {
             &100_000,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         let donator = Address::generate(&env);
@@ -356,7 +386,8 @@ proptest! {
 
         let token_admin = Address::generate(&env);
         let token = create_token(&env, &token_admin);
-        let proof_hash = BytesN::from_array(&env, &hash_bytes);
+        let preimage = Bytes::from_array(&env, &hash_bytes);
+        let proof_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
         let deadline = env.ledger().timestamp() + 86_400;
 
         let mut tokens = SorobanVec::new(&env);
@@ -368,11 +399,14 @@ proptest! {
             &500,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
 
         let oracle = Address::generate(&env);
         client.set_oracle(&admin, &oracle);
-        client.verify_and_release(&oracle, &original.id, &proof_hash);
+        client.set_allow_partial_release(&creator, &original.id, &true);
+        client.verify_and_release(&oracle, &original.id, &preimage);
 
         let after = client.get_project(&original.id);
         assert_project_immutable_fields(&original, &after);
@@ -397,7 +431,8 @@ proptest! {
 
         let token_admin = Address::generate(&env);
         let token_client = create_token(&env, &token_admin);
-        let proof_hash = BytesN::from_array(&env, &hash_bytes);
+        let preimage = Bytes::from_array(&env, &hash_bytes);
+        let proof_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
         let deadline = env.ledger().timestamp() + deadline_offset;
 
         let mut tokens = SorobanVec::new(&env);
@@ -410,6 +445,8 @@ proptest! {
             &goal,
             &proof_hash,
             &deadline,
+            &dummy_metadata(&env),
+            &None,
         );
         assert_all_project_invariants(&project);
         assert_eq!(project.status, ProjectStatus::Funding);
@@ -438,10 +475,11 @@ proptest! {
         let final_balance = client.get_balance(&project.id, &token_client.address);
         assert_eq!(final_balance, total_deposited);
 
-        // Phase 3: Oracle verification.
+        // Phase 3: Oracle verification; deposits may fall short of the goal.
         let oracle = Address::generate(&env);
         client.set_oracle(&admin, &oracle);
-        client.verify_and_release(&oracle, &project.id, &proof_hash);
+        client.set_allow_partial_release(&creator, &project.id, &true);
+        client.verify_and_release(&oracle, &project.id, &preimage);
 
         let final_project = client.get_project(&project.id);
         assert_valid_status_transition(&ProjectStatus::Funding, &final_project.status);
@@ -459,7 +497,7 @@ proptest! {
         assert_eq!(creator_actual_balance, total_deposited);
 
         // Phase 5: Double-verify should fail.
-        let result = client.try_verify_and_release(&oracle, &project.id, &proof_hash);
+        let result = client.try_verify_and_release(&oracle, &project.id, &preimage);
         prop_assert!(result.is_err(), "double verification should fail");
     }
 }
@@ -6,7 +6,7 @@
 //!
 //! ## Usage
 //!
-//! ```rust
+//! ```ignore
 //! use crate::gas_profiling::{GasProfiler, GasMeasurement};
 //!
 //! // Measure a specific operation
@@ -14,18 +14,50 @@
 //! let measurement = profiler.measure("deposit_operation", || {
 //!     client.deposit(&project_id, &donator, &token, &amount)
 //! });
-//! println!("Gas used: {}", measurement.gas_used);
+//! println!("CPU instructions: {}", measurement.cpu_instructions);
 //! ```
+//!
+//! Measurements are read from the host's metering budget, so they reflect
+//! the actual CPU instructions and memory charged while the closure ran.
+//!
+//! For a multi-step flow, take a [`GasProfiler::checkpoint`] after each step
+//! and read the per-step costs from [`GasProfiler::diffs`]:
+//!
+//! ```ignore
+//! let profiler = GasProfiler::new(&env);
+//! client.register_project(/* ... */);
+//! profiler.checkpoint("register");
+//! client.deposit(/* ... */);
+//! profiler.checkpoint("deposit");
+//! for (from, to, cpu) in profiler.diffs() {
+//!     println!("{from} -> {to}: {cpu}");
+//! }
+//! ```
+//!
+//! Every [`GasProfiler::measure`] is also kept in the profiler's log, so a
+//! sequence of measurements can be reviewed with [`GasProfiler::log`] and
+//! summed with [`GasProfiler::total_gas`] at the end.
+
+extern crate std;
 
 use crate::events;
+use crate::storage::{self, DataKey};
+use core::cell::RefCell;
 use soroban_sdk::{Env, Symbol};
+use std::format;
+use std::string::{String, ToString};
+use std::vec::Vec;
 
 /// Represents a single gas measurement
 #[derive(Clone, Debug)]
 pub struct GasMeasurement {
     /// Operation name for identification
     pub operation: String,
-    /// Gas consumed during execution
+    /// CPU instructions charged during execution
+    pub cpu_instructions: u64,
+    /// Memory bytes charged during execution
+    pub memory_bytes: u64,
+    /// Alias of `cpu_instructions`, kept for existing callers
     pub gas_used: u64,
     /// Timestamp of measurement
     pub timestamp: u64,
@@ -34,7 +66,10 @@ pub struct GasMeasurement {
 /// Gas profiling utility for measuring contract operation costs
 pub struct GasProfiler {
     env: Env,
-    start_ledger: u32,
+    /// Labels with the CPU instructions charged since the previous checkpoint
+    checkpoints: RefCell<Vec<(String, u64)>>,
+    /// Every measurement taken, in order
+    measurements: RefCell<Vec<GasMeasurement>>,
 }
 
 impl GasProfiler {
@@ -42,51 +77,148 @@ impl GasProfiler {
     pub fn new(env: &Env) -> Self {
         Self {
             env: env.clone(),
-            start_ledger: env.ledger().sequence(),
+            checkpoints: RefCell::new(Vec::new()),
+            measurements: RefCell::new(Vec::new()),
         }
     }
 
+    /// Record a labelled checkpoint and restart the budget's counters
+    ///
+    /// The host restarts the budget at every top-level contract call, so a
+    /// checkpoint covers the work since the previous checkpoint or since the
+    /// start of the latest call, whichever is later. Take one after each
+    /// call of a flow. Do not take checkpoints inside [`GasProfiler::measure`].
+    pub fn checkpoint(&self, label: &str) {
+        let (cpu, _) = self.get_current_gas();
+        self.checkpoints.borrow_mut().push((label.to_string(), cpu));
+        self.env.cost_estimate().budget().reset_tracker();
+    }
+
+    /// CPU instructions charged between each pair of consecutive
+    /// checkpoints, as `(from_label, to_label, cpu_instructions)`
+    pub fn diffs(&self) -> Vec<(String, String, u64)> {
+        self.checkpoints
+            .borrow()
+            .windows(2)
+            .map(|pair| (pair[0].0.clone(), pair[1].0.clone(), pair[1].1))
+            .collect()
+    }
+
     /// Measure gas consumption of a closure execution
     pub fn measure<F, R>(&self, operation_name: &str, f: F) -> (R, GasMeasurement)
     where
         F: FnOnce() -> R,
     {
-        // Get initial gas state
-        let start_gas = self.get_current_gas();
+        // Get initial budget state
+        let (start_cpu, start_mem) = self.get_current_gas();
         let start_time = self.env.ledger().timestamp();
 
         // Execute the operation
         let result = f();
 
-        // Measure gas consumption
-        let end_gas = self.get_current_gas();
-        let gas_used = start_gas.saturating_sub(end_gas);
+        // The budget only grows while the closure runs
+        let (end_cpu, end_mem) = self.get_current_gas();
+        let cpu_instructions = end_cpu.saturating_sub(start_cpu);
+        let memory_bytes = end_mem.saturating_sub(start_mem);
 
         let measurement = GasMeasurement {
             operation: operation_name.to_string(),
-            gas_used,
+            cpu_instructions,
+            memory_bytes,
+            gas_used: cpu_instructions,
             timestamp: start_time,
         };
+        self.measurements.borrow_mut().push(measurement.clone());
 
         (result, measurement)
     }
 
-    /// Get current gas state from the environment
-    fn get_current_gas(&self) -> u64 {
-        // In a real implementation, this would interface with Soroban's
-        // gas metering system. For now, we simulate with ledger operations.
-        self.env.ledger().sequence() as u64
+    /// Every measurement taken by this profiler, oldest first
+    pub fn log(&self) -> Vec<GasMeasurement> {
+        self.measurements.borrow().clone()
+    }
+
+    /// Sum of `gas_used` over every logged measurement
+    pub fn total_gas(&self) -> u64 {
+        self.measurements
+            .borrow()
+            .iter()
+            .map(|m| m.gas_used)
+            .fold(0, u64::saturating_add)
     }
 
-    /// Emit gas measurement as an event for off-chain tracking
+    /// Measure several labelled closures and return their measurements,
+    /// cheapest first
+    ///
+    /// Closures of different shapes can be compared by boxing them as
+    /// `Box<dyn FnOnce() -> R>`.
+    pub fn measure_many<F, R>(&self, labels_and_closures: Vec<(&str, F)>) -> Vec<GasMeasurement>
+    where
+        F: FnOnce() -> R,
+    {
+        let mut measurements: Vec<GasMeasurement> = labels_and_closures
+            .into_iter()
+            .map(|(label, f)| self.measure(label, f).1)
+            .collect();
+        measurements.sort_by_key(|m| m.gas_used);
+        measurements
+    }
+
+    /// Render measurements as a fixed-width text table
+    pub fn format_table(measurements: &[GasMeasurement]) -> String {
+        let mut table = format!(
+            "{:<32} {:>16} {:>16}\n",
+            "operation", "cpu_instructions", "memory_bytes"
+        );
+        for m in measurements {
+            table.push_str(&format!(
+                "{:<32} {:>16} {:>16}\n",
+                m.operation, m.cpu_instructions, m.memory_bytes
+            ));
+        }
+        table
+    }
+
+    /// Print measurements as a table to stdout
+    pub fn print_table(measurements: &[GasMeasurement]) {
+        std::print!("{}", Self::format_table(measurements));
+    }
+
+    /// Get the CPU instructions and memory bytes consumed so far
+    fn get_current_gas(&self) -> (u64, u64) {
+        let budget = self.env.cost_estimate().budget();
+        (budget.cpu_instruction_cost(), budget.memory_bytes_cost())
+    }
+
+    /// Emit gas measurement as an event for off-chain tracking, tagged
+    /// with the category from [`GasProfiler::category_of`]
     pub fn emit_measurement(&self, measurement: &GasMeasurement) {
+        let category = Self::category_of(&measurement.operation).map(|c| Symbol::new(&self.env, c));
         events::emit_gas_measurement(
             &self.env,
             &measurement.operation,
+            category,
             measurement.gas_used,
             measurement.timestamp,
         );
     }
+
+    /// Group an operation name under a coarse category by keyword, e.g.
+    /// `"verify_and_release"` is a `"release"`; `None` if nothing matches
+    pub fn category_of(operation: &str) -> Option<&'static str> {
+        const CATEGORIES: [(&str, &str); 6] = [
+            ("deposit", "deposit"),
+            ("release", "release"),
+            ("verify", "release"),
+            ("refund", "refund"),
+            ("withdraw", "refund"),
+            ("register", "register"),
+        ];
+        CATEGORIES
+            .iter()
+            .find(|(keyword, _)| operation.contains(keyword))
+            .map(|(_, category)| *category)
+    }
 }
 
 /// Gas optimization utilities
@@ -95,15 +227,17 @@ pub struct GasOptimizer;
 impl GasOptimizer {
     /// Optimize token duplicate detection using single-pass algorithm
     ///
-    /// Replaces O(n²) nested loop with O(n) hash-based approach
+    /// Replaces O(n²) nested loop with O(n) hash-based approach.
+    /// Returns `Error::TooManyTokens` or `Error::DuplicateToken` rather than
+    /// panicking, so callers decide how to surface the failure.
     pub fn check_duplicate_tokens_optimized(
         env: &Env,
         tokens: &soroban_sdk::Vec<soroban_sdk::Address>,
     ) -> Result<(), crate::Error> {
         use soroban_sdk::Map;
 
-        if tokens.len() > 10 {
-            soroban_sdk::panic_with_error!(env, crate::Error::TooManyTokens);
+        if tokens.len() > crate::MAX_ACCEPTED_TOKENS {
+            return Err(crate::Error::TooManyTokens);
         }
 
         let mut seen_tokens: Map<soroban_sdk::Address, bool> = Map::new(env);
@@ -112,8 +246,8 @@ impl GasOptimizer {
             let token = tokens.get(i).unwrap();
 
             // Check if we've seen this token before
-            if seen_tokens.contains_key(token) {
-                soroban_sdk::panic_with_error!(env, crate::Error::DuplicateToken);
+            if seen_tokens.contains_key(token.clone()) {
+                return Err(crate::Error::DuplicateToken);
             }
 
             // Mark token as seen
@@ -124,24 +258,64 @@ impl GasOptimizer {
     }
 
     /// Batch storage operations to reduce TTL bumps
-    pub fn batch_storage_operations<F, R>(env: &Env, operations: F) -> R
+    ///
+    /// The closure records every persistent key it touches on the supplied
+    /// [`TtlBatch`] instead of bumping it directly. Once it returns, each
+    /// distinct key is bumped exactly once. Returns the closure's result and
+    /// the number of keys bumped.
+    pub fn batch_storage_operations<F, R>(env: &Env, operations: F) -> (R, u32)
     where
-        F: FnOnce() -> R,
+        F: FnOnce(&mut TtlBatch) -> R,
     {
-        // In a real implementation, this would:
-        // 1. Temporarily disable automatic TTL bumps
-        // 2. Execute all operations
-        // 3. Perform single bulk TTL bump
-        // 4. Re-enable automatic TTL management
+        let mut batch = TtlBatch::new(env);
+        let result = operations(&mut batch);
+        let bumped = batch.flush();
+        (result, bumped)
+    }
+}
+
+/// Persistent keys collected by [`GasOptimizer::batch_storage_operations`]
+pub struct TtlBatch {
+    env: Env,
+    keys: soroban_sdk::Vec<DataKey>,
+    touches: u32,
+}
 
-        operations()
+impl TtlBatch {
+    fn new(env: &Env) -> Self {
+        Self {
+            env: env.clone(),
+            keys: soroban_sdk::Vec::new(env),
+            touches: 0,
+        }
+    }
+
+    /// Record that `key` was touched and needs a TTL bump
+    pub fn touch(&mut self, key: DataKey) {
+        self.touches += 1;
+        if !self.keys.contains(&key) {
+            self.keys.push_back(key);
+        }
+    }
+
+    /// Number of bumps an unbatched run would have performed
+    pub fn touches(&self) -> u32 {
+        self.touches
+    }
+
+    /// Bump every distinct recorded key once and return how many were bumped
+    fn flush(self) -> u32 {
+        for key in self.keys.iter() {
+            storage::bump_persistent(&self.env, &key);
+        }
+        self.keys.len()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{vec, Address, Env};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env};
 
     #[test]
     fn test_gas_profiler_basic() {
@@ -154,10 +328,135 @@ mod test {
             GasOptimizer::check_duplicate_tokens_optimized(&env, &tokens).unwrap();
         });
 
-        assert!(measurement.gas_used > 0);
+        assert!(measurement.cpu_instructions > 0);
+        assert!(measurement.memory_bytes > 0);
+        assert_eq!(measurement.gas_used, measurement.cpu_instructions);
         assert_eq!(measurement.operation, "duplicate_check");
     }
 
+    #[test]
+    fn test_log_accumulates_measurements() {
+        let env = Env::default();
+        let profiler = GasProfiler::new(&env);
+        let tokens = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+        let (_, first) = profiler.measure("first_check", || {
+            GasOptimizer::check_duplicate_tokens_optimized(&env, &tokens).unwrap();
+        });
+        let (_, second) = profiler.measure("second_check", || {
+            GasOptimizer::check_duplicate_tokens_optimized(&env, &tokens).unwrap();
+        });
+
+        let log = profiler.log();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].operation, "first_check");
+        assert_eq!(log[1].operation, "second_check");
+        assert_eq!(profiler.total_gas(), first.gas_used + second.gas_used);
+    }
+
+    #[test]
+    fn test_measure_many_sorts_by_gas_used() {
+        let env = Env::default();
+        let profiler = GasProfiler::new(&env);
+
+        let few = vec![&env, Address::generate(&env)];
+        let many = vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+
+        type Bench<'a> = std::boxed::Box<dyn FnOnce() + 'a>;
+        let closures: std::vec::Vec<(&str, Bench)> = std::vec![
+            (
+                "many_tokens",
+                std::boxed::Box::new(|| GasOptimizer::check_duplicate_tokens_optimized(
+                    &env, &many
+                )
+                .unwrap()),
+            ),
+            (
+                "few_tokens",
+                std::boxed::Box::new(
+                    || GasOptimizer::check_duplicate_tokens_optimized(&env, &few).unwrap()
+                ),
+            ),
+        ];
+        let measurements = profiler.measure_many(closures);
+
+        assert_eq!(measurements.len(), 2);
+        assert_eq!(measurements[0].operation, "few_tokens");
+        assert_eq!(measurements[1].operation, "many_tokens");
+        assert!(measurements[0].gas_used <= measurements[1].gas_used);
+
+        let table = GasProfiler::format_table(&measurements);
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.contains("few_tokens"));
+    }
+
+    #[test]
+    fn test_emit_measurement_carries_name_and_category() {
+        use soroban_sdk::{testutils::Events, IntoVal, String as SorobanString};
+
+        let env = Env::default();
+        let contract_id = env.register(crate::PifpProtocol, ());
+        let profiler = GasProfiler::new(&env);
+        let measurement = GasMeasurement {
+            operation: "deposit_operation".to_string(),
+            cpu_instructions: 10,
+            memory_bytes: 5,
+            gas_used: 10,
+            timestamp: 0,
+        };
+
+        env.as_contract(&contract_id, || profiler.emit_measurement(&measurement));
+
+        let event = env.events().all().last().unwrap();
+        let expected = vec![
+            &env,
+            soroban_sdk::symbol_short!("gas").into_val(&env),
+            SorobanString::from_str(&env, "deposit_operation").into_val(&env),
+            Some(Symbol::new(&env, "deposit")).into_val(&env),
+        ];
+        assert_eq!(event.1, expected);
+
+        assert_eq!(
+            GasProfiler::category_of("verify_and_release"),
+            Some("release")
+        );
+        assert_eq!(GasProfiler::category_of("duplicate_check"), None);
+    }
+
+    #[test]
+    fn test_batch_storage_operations_bumps_each_key_once() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PifpProtocol, ());
+
+        env.as_contract(&contract_id, || {
+            let keys = [
+                DataKey::ProjState(0),
+                DataKey::ProjState(1),
+                DataKey::RefundCursor(0),
+            ];
+            for key in keys.iter() {
+                env.storage().persistent().set(key, &0u32);
+            }
+
+            let (_, bumped) = GasOptimizer::batch_storage_operations(&env, |batch| {
+                // Touch every key twice, as repeated reads in a flow would.
+                for key in keys.iter().chain(keys.iter()) {
+                    batch.touch(key.clone());
+                }
+                assert_eq!(batch.touches(), 6);
+            });
+
+            assert_eq!(bumped, 3);
+        });
+    }
+
     #[test]
     fn test_duplicate_detection_optimized() {
         let env = Env::default();
@@ -181,6 +480,14 @@ mod test {
         ];
 
         let result = GasOptimizer::check_duplicate_tokens_optimized(&env, &tokens_with_duplicate);
-        assert!(result.is_err());
+        assert_eq!(result, Err(crate::Error::DuplicateToken));
+
+        // Test case 3: Too many tokens
+        let mut too_many = vec![&env];
+        for _ in 0..11 {
+            too_many.push_back(Address::generate(&env));
+        }
+        let result = GasOptimizer::check_duplicate_tokens_optimized(&env, &too_many);
+        assert_eq!(result, Err(crate::Error::TooManyTokens));
     }
 }
@@ -67,18 +67,29 @@ pub fn assert_sequential_ids(projects: &[Project]) {
 }
 
 /// INV-7: Status transition validity. Only forward transitions are allowed:
-///   Funding -> Active | Completed | Expired
-///   Active  -> Completed | Expired
-///   Completed -> (none)
+///   Funding -> Active | Completed | Expired | Cancelled | PendingRelease
+///   Active  -> Completed | Expired | PendingRelease
+///   PendingRelease -> Completed, or back to Funding | Active via `cancel_release`
+///   Completed -> PartiallyReleased, when a safe release leaves transfers unpaid
+///   PartiallyReleased -> Completed via `retry_release`
 ///   Expired   -> (none)
+///   Cancelled -> (none)
 pub fn assert_valid_status_transition(from: &ProjectStatus, to: &ProjectStatus) {
     let valid = matches!(
         (from, to),
         (ProjectStatus::Funding, ProjectStatus::Active)
             | (ProjectStatus::Funding, ProjectStatus::Completed)
             | (ProjectStatus::Funding, ProjectStatus::Expired)
+            | (ProjectStatus::Funding, ProjectStatus::Cancelled)
             | (ProjectStatus::Active, ProjectStatus::Completed)
             | (ProjectStatus::Active, ProjectStatus::Expired)
+            | (ProjectStatus::Funding, ProjectStatus::PendingRelease)
+            | (ProjectStatus::Active, ProjectStatus::PendingRelease)
+            | (ProjectStatus::PendingRelease, ProjectStatus::Completed)
+            | (ProjectStatus::PendingRelease, ProjectStatus::Funding)
+            | (ProjectStatus::PendingRelease, ProjectStatus::Active)
+            | (ProjectStatus::Completed, ProjectStatus::PartiallyReleased)
+            | (ProjectStatus::PartiallyReleased, ProjectStatus::Completed)
     );
 
     assert!(
@@ -6,13 +6,23 @@
 //!
 //! | Phase        | Entry Point(s)                              |
 //! |--------------|---------------------------------------------|
-//! | Bootstrap    | [`PifpProtocol::init`]                      |
-//! | Role admin   | `grant_role`, `revoke_role`, `transfer_super_admin`, `set_oracle` |
-//! | Registration | [`PifpProtocol::register_project`]          |
-//! | Funding      | [`PifpProtocol::deposit`]                   |
-//! | Donor safety | [`PifpProtocol::refund`]                    |
-//! | Verification | [`PifpProtocol::verify_and_release`]        |
-//! | Queries      | `get_project`, `get_project_balances`, `role_of`, `has_role` |
+//! | Bootstrap    | [`PifpProtocol::init`], `upgrade`, `get_version` |
+//! | Role admin   | `grant_role`, `revoke_role`, `transfer_super_admin`, `transfer_admin`, `propose_admin`, `accept_admin`, `cancel_admin_proposal`, `set_oracle` |
+//! | Oracle bonds | `set_oracle_bond`, `get_oracle_bond`, `stake_oracle`, `unstake_oracle`, `slash_oracle`, `get_oracle_stake` |
+//! | Emergency    | `pause`, `unpause`, `pause_token`, `unpause_token`, `is_token_paused`, `reconcile`, `emergency_withdraw` |
+//! | Fees         | `set_fee_bps`, `set_treasury`, `set_fee_tiers`, `completed_projects_count`, `get_effective_fee_bps` |
+//! | Settings     | `set_min_funding_window`, `get_min_funding_window`, `set_unique_proofs`, `is_unique_proofs`, `set_safe_release`, `is_safe_release`, `allow_token`, `disallow_token`, `is_token_allowed`, `set_grace_period`, `get_grace_period`, `set_max_tokens`, `get_max_tokens`, `set_native_token`, `get_native_token`, `set_min_goal`, `get_min_goal`, `set_max_active_per_creator`, `get_max_active_per_creator`, `set_refund_window`, `get_refund_window` |
+//! | Pricing      | `set_token_price`, `get_token_price`        |
+//! | Registration | [`PifpProtocol::register_project`], `register_project_with_handle`, `compute_project_handle`, `set_metadata_uri`, `report_impact` |
+//! | Funding      | [`PifpProtocol::deposit`], `deposit_native`, `deposit_with_nonce`, `deposit_with_memo`, `batch_deposit`, `deposit_for`, `set_min_deposit`, `extend_deadline`, `update_goal`, `set_release_threshold_bps`, `add_accepted_token`, `cancel_project`, `enable_allowlist`, `add_allowed_donor`, `set_allow_partial_release`, `set_per_donor_cap`, `set_hard_cap` |
+//! | Recurring    | `schedule_recurring`, `execute_due_recurring`, `get_recurring` |
+//! | Expiry       | `expire_project`, `auto_expire`, `bump_project_ttl` |
+//! | Donor safety | `withdraw`, `refund`, `refund_deposit`, `refund_all`, `sweep_unrefunded` |
+//! | Matching     | `fund_match_pool`, `reclaim_match_pool`, `get_match_pool`, `set_match_ratio_bps`, `get_match_ratio_bps` |
+//! | Verification | `verify_and_release`, `approve_release`, `finalize_release`, `cancel_release`, `retry_release`, `get_unpaid_payouts`, `set_challenge_period`, `set_verification_threshold`, `set_verification_validity`, `set_beneficiaries`, `assign_project_oracle`, `remove_project_oracle`, `get_authorized_oracles` |
+//! | Milestones   | `register_project_with_milestones`, `register_staged_project`, `release_milestone` |
+//! | Vesting      | `register_project_with_vesting`, `claim_vested`, `get_claimable` |
+//! | Queries      | `get_project`, `get_project_by_hash`, `get_project_handle`, `find_project`, `get_project_summary`, `simulate_release`, `get_status_history`, `get_funding_progress`, `get_remaining_to_goal`, `get_accepted_tokens`, `get_effective_deadline`, `get_deadline_status`, `can_deposit`, `get_donor_count`, `is_donor_allowed`, `list_projects`, `list_projects_by_category`, `get_project_count`, `get_config`, `get_stats`, `get_total_locked`, `get_recent_actions`, `get_projects_by_creator`, `active_projects_count`, `get_projects_donated_by`, `get_donor_contribution`, `get_deposit_memo`, `get_top_donors`, `get_qf_match`, `get_project_balances`, `get_project_balances_paged`, `get_balances_for`, `role_of`, `has_role`, `get_role_members` |
 //!
 //! ## Architecture
 //!
@@ -22,16 +32,35 @@
 //!
 //! See [`ARCHITECTURE.md`](../../../../ARCHITECTURE.md) for the full system
 //! architecture and threat model.
+//!
+//! ## Error handling
+//!
+//! Entry points abort with [`Error`] via `panic_with_error!`, which rolls
+//! back the whole invocation on-chain. Callers that want to inspect the
+//! failure use the `try_*` methods of the generated [`PifpProtocolClient`]
+//! (`try_register_project`, `try_deposit`, `try_verify_and_release`, …).
+//! These return `Err(Ok(e))` for contract errors, where `e` converts from
+//! [`Error`], so tests can match the exact variant instead of relying on
+//! `#[should_panic]`:
+//!
+//! ```ignore
+//! assert_eq!(
+//!     client.try_deposit(&project_id, &donator, &token, &0),
+//!     Err(Ok(Error::InvalidAmount.into()))
+//! );
+//! ```
 
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, panic_with_error, token, Address, BytesN, Env, Vec,
+    contract, contracterror, contractimpl, panic_with_error, symbol_short, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 
 pub mod events;
 #[cfg(feature = "testutils")]
-mod gas_profiling;
+pub mod gas_profiling;
+pub mod qf;
 pub mod rbac;
 mod storage;
 mod types;
@@ -45,28 +74,92 @@ mod rbac_test;
 #[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_allowlist;
+#[cfg(test)]
+mod test_cancel;
+#[cfg(test)]
+mod test_categories;
+#[cfg(test)]
+mod test_challenge;
+#[cfg(test)]
+mod test_deadline;
+#[cfg(test)]
 mod test_donation_count;
 #[cfg(test)]
+mod test_donors;
+#[cfg(test)]
+mod test_emergency;
+#[cfg(test)]
+mod test_errors;
+#[cfg(test)]
 mod test_events;
 #[cfg(test)]
 mod test_expire;
 #[cfg(test)]
+mod test_fees;
+#[cfg(test)]
 mod test_gas_baseline;
 #[cfg(test)]
+mod test_goal;
+#[cfg(test)]
+mod test_hard_cap;
+#[cfg(test)]
+mod test_list_projects;
+#[cfg(test)]
+mod test_matching;
+#[cfg(test)]
+mod test_metadata;
+#[cfg(test)]
+mod test_milestones;
+#[cfg(test)]
+mod test_multi_oracle;
+#[cfg(test)]
+mod test_oracle_bond;
+#[cfg(test)]
 mod test_perf_regression;
 #[cfg(test)]
+mod test_pricing;
+#[cfg(test)]
+mod test_proofs;
+#[cfg(test)]
+mod test_qf;
+#[cfg(test)]
+mod test_recurring;
+#[cfg(test)]
+mod test_reentrancy;
+#[cfg(test)]
 mod test_refund;
 #[cfg(test)]
+mod test_safe_release;
+#[cfg(test)]
+mod test_stats;
+#[cfg(test)]
+mod test_status_history;
+#[cfg(test)]
+mod test_tokens;
+#[cfg(test)]
+mod test_ttl;
+#[cfg(test)]
+mod test_upgrade;
+#[cfg(test)]
 mod test_utils;
+#[cfg(test)]
+mod test_vesting;
+#[cfg(test)]
+mod test_withdraw;
 
-pub use events::emit_funds_released;
+pub use events::emit_release;
 pub use rbac::Role;
 use storage::{
     drain_token_balance, get_all_balances, get_and_increment_project_id, load_project,
     load_project_pair, maybe_load_project, save_project, save_project_state,
 };
-use types::ProjectConfig;
-pub use types::{Project, ProjectBalances, ProjectStatus};
+pub use types::{
+    DeadlineStatus, DonorBalance, GlobalConfig, MatchPool, NonceOutcome, PendingPayout, Project,
+    ProjectBalances, ProjectMetadata, ProjectStatus, ProjectSummary, ProtocolStats, RecentAction,
+    RecurringSchedule, TokenBalance, VestingGrant,
+};
+use types::{ProjectConfig, ProjectState};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -80,22 +173,176 @@ pub enum Error {
     NotAuthorized = 6,
     InvalidGoal = 7,
     AlreadyInitialized = 8,
-    RoleNotFound = 9,
+    TooManyOracles = 9,
     TooManyTokens = 10,
     InvalidAmount = 11,
     DuplicateToken = 12,
     InvalidDeadline = 13,
     ProjectExpired = 14,
     ProjectNotActive = 15,
-    VerificationFailed = 16,
+    HandleTaken = 16,
     EmptyAcceptedTokens = 17,
     Overflow = 18,
     ProtocolPaused = 19,
     GoalMismatch = 20,
     ProjectNotExpired = 21,
     InvalidTransition = 22,
+    NothingToRefund = 23,
+    InsufficientDonorBalance = 24,
+    RoleNotHeld = 25,
+    CannotRemoveLastAdmin = 26,
+    AlreadyApproved = 27,
+    DepositBelowMinimum = 28,
+    DeadlinePassed = 29,
+    PriceNotSet = 30,
+    GoalBelowRaised = 31,
+    HardCapExceeded = 32,
+    InvalidSchedule = 33,
+    ProtocolNotPaused = 34,
+    NoUnaccountedBalance = 35,
+    Reentrancy = 36,
+    DeadlineInPast = 37,
+    DuplicateProof = 38,
+    ProofMismatch = 39,
+    DonorNotAllowed = 40,
+    TokenPaused = 41,
+    GoalNotMet = 42,
+    NotInitialized = 43,
+    NotProjectOracle = 44,
+    GoalBelowMinimum = 45,
+    TooManyActiveProjects = 46,
+    DonorCapExceeded = 47,
+    SharesMustSumTo10000 = 48,
+    WindowNotElapsed = 49,
+    TokenNotAllowed = 50,
+}
+
+impl Error {
+    /// Short human-readable explanation of the error, for clients and logs.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Error::ProjectNotFound => "no project exists with this ID",
+            Error::MilestoneNotFound => "milestone index is out of range",
+            Error::MilestoneAlreadyReleased => "funds or milestone already released",
+            Error::InsufficientBalance => "balance is too low for this operation",
+            Error::InvalidMilestones => "milestone targets are malformed",
+            Error::NotAuthorized => "caller is not allowed to perform this action",
+            Error::InvalidGoal => "goal is outside the accepted range",
+            Error::AlreadyInitialized => "contract is already initialized",
+            Error::TooManyOracles => "project has the maximum number of oracles",
+            Error::TooManyTokens => "too many accepted tokens",
+            Error::InvalidAmount => "amount is zero, negative or out of range",
+            Error::DuplicateToken => "token is listed more than once",
+            Error::InvalidDeadline => "deadline is outside the accepted range",
+            Error::ProjectExpired => "project deadline has passed",
+            Error::ProjectNotActive => "project is not in a state that allows this action",
+            Error::HandleTaken => "project handle is already in use",
+            Error::EmptyAcceptedTokens => "project must accept at least one token",
+            Error::Overflow => "arithmetic overflow",
+            Error::ProtocolPaused => "protocol is paused",
+            Error::GoalMismatch => "goal is fixed by the project's milestones",
+            Error::ProjectNotExpired => "project has not expired",
+            Error::InvalidTransition => "status transition is not allowed",
+            Error::NothingToRefund => "no balance to refund",
+            Error::InsufficientDonorBalance => "donor balance is too low",
+            Error::RoleNotHeld => "account does not hold the role",
+            Error::CannotRemoveLastAdmin => "the last admin cannot be removed",
+            Error::AlreadyApproved => "oracle has already approved this release",
+            Error::DepositBelowMinimum => "deposit is below the project minimum",
+            Error::DeadlinePassed => "project deadline has passed",
+            Error::PriceNotSet => "no price set for this token",
+            Error::GoalBelowRaised => "goal cannot drop below the amount raised",
+            Error::HardCapExceeded => "deposit would exceed the hard cap",
+            Error::InvalidSchedule => "recurring or vesting schedule is malformed",
+            Error::ProtocolNotPaused => "protocol is not paused",
+            Error::NoUnaccountedBalance => "no unaccounted balance to sweep",
+            Error::Reentrancy => "reentrant call rejected",
+            Error::DeadlineInPast => "deadline is already in the past",
+            Error::DuplicateProof => "proof hash is already in use",
+            Error::ProofMismatch => "proof preimage does not match the stored hash",
+            Error::DonorNotAllowed => "donor is not on the project allowlist",
+            Error::TokenPaused => "token is paused",
+            Error::GoalNotMet => "funding goal has not been met",
+            Error::NotInitialized => "contract or a required setting is not initialized",
+            Error::NotProjectOracle => "oracle is not assigned to this project",
+            Error::GoalBelowMinimum => "goal is below the protocol minimum",
+            Error::TooManyActiveProjects => "creator has reached the limit of live projects",
+            Error::DonorCapExceeded => "deposit would exceed the per-donor cap",
+            Error::SharesMustSumTo10000 => "beneficiary shares must sum to 10000 bps",
+            Error::WindowNotElapsed => "a waiting period has not yet elapsed",
+            Error::TokenNotAllowed => "token is not on the protocol allowlist",
+        }
+    }
 }
 
+/// Refunds paid per `refund_all` call when the caller gives no limit.
+const DEFAULT_REFUND_BATCH: u32 = 25;
+
+/// Basis-point denominator: 10_000 bps = 100%.
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Upper bound for the platform fee: 1_000 bps = 10%.
+const MAX_FEE_BPS: u32 = 1_000;
+
+/// Maximum number of veteran fee tiers.
+const MAX_FEE_TIERS: u32 = 5;
+
+/// Upper bound for the matching ratio: 100_000 bps = 10 matched per unit raised.
+const MAX_MATCH_RATIO_BPS: u32 = 100_000;
+
+/// Most tokens the protocol-wide allowlist may hold.
+const MAX_ALLOWED_TOKENS: u32 = 50;
+
+/// Maximum number of milestones a project can be split into.
+const MAX_MILESTONES: u32 = 10;
+
+/// Maximum number of beneficiaries sharing a project's payout.
+const MAX_BENEFICIARIES: u32 = 10;
+
+/// Maximum number of oracles that can be assigned to one project.
+const MAX_PROJECT_ORACLES: u32 = 10;
+
+/// Shortest time an oracle's bond stays locked after its last approval:
+/// 7 days, or the challenge period if that is longer.
+const ORACLE_UNBONDING_SECS: u64 = 604_800;
+
+/// Default maximum number of tokens a project can accept; admins can change
+/// it with `set_max_tokens`.
+const MAX_ACCEPTED_TOKENS: u32 = 10;
+
+/// Maximum number of runs a single recurring schedule may have.
+const MAX_RECURRING_OCCURRENCES: u32 = 52;
+
+/// Maximum number of recurring schedules per project, bounding the work
+/// done by one `execute_due_recurring` call.
+const MAX_RECURRING_SCHEDULES: u32 = 20;
+
+/// Most deposits one `batch_deposit` call may make, keeping it well inside
+/// the per-transaction instruction budget.
+const MAX_BATCH_DEPOSITS: u32 = 10;
+
+/// Largest accepted funding goal (10^30).
+const MAX_GOAL: i128 = 1_000_000_000_000_000_000_000_000_000_000;
+
+/// Fixed-point scale for token prices: a price of `PRICE_SCALE` is par
+/// with the goal unit.
+const PRICE_SCALE: i128 = 10_000_000;
+
+/// How close a deadline must be for `get_deadline_status` to report it as
+/// `Imminent`: 24 hours.
+const IMMINENT_WINDOW_SECS: u64 = 86_400;
+
+/// Upper bound on the page size accepted by `list_projects`.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Donation index entries one `get_top_donors` call reads, each costing a
+/// balance lookup as well, keeping the query within read limits.
+const MAX_DONOR_SCAN: u32 = 25;
+
+/// Most project IDs kept in one account's creator or donor index. Later
+/// projects are not recorded once an index is full.
+const MAX_ACCOUNT_PROJECTS: u32 = 100;
+
 #[contract]
 pub struct PifpProtocol;
 
@@ -116,6 +363,30 @@ impl PifpProtocol {
         rbac::init_super_admin(&env, &super_admin);
     }
 
+    /// Replace the contract's Wasm with `new_wasm_hash` and bump the version.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - The Wasm must already be uploaded to the ledger.
+    /// - Storage is kept as-is; the new code must read the existing layout.
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let version = storage::get_version(&env)
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        storage::set_version(&env, version);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        events::emit_upgraded(&env, new_wasm_hash, version);
+    }
+
+    /// Return the code version: `1` as deployed, plus one per upgrade.
+    pub fn get_version(env: Env) -> u32 {
+        storage::get_version(&env)
+    }
+
     // ─────────────────────────────────────────────────────────
     // Role management
     // ─────────────────────────────────────────────────────────
@@ -128,12 +399,15 @@ impl PifpProtocol {
         rbac::grant_role(&env, &caller, &target, role);
     }
 
-    /// Revoke any role from `target`.
+    /// Revoke `role` from `target`.
     ///
-    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `caller` must authorize and hold `SuperAdmin` or `Admin`.
+    /// - `target` must currently hold `role` (`Error::RoleNotHeld`).
+    /// - The last `Admin` cannot be revoked (`Error::CannotRemoveLastAdmin`).
     /// - Cannot be used to remove the SuperAdmin; use `transfer_super_admin`.
-    pub fn revoke_role(env: Env, caller: Address, target: Address) {
-        rbac::revoke_role(&env, &caller, &target);
+    pub fn revoke_role(env: Env, caller: Address, target: Address, role: Role) {
+        caller.require_auth();
+        rbac::revoke_role(&env, &caller, &target, role);
     }
 
     /// Transfer SuperAdmin to `new_super_admin`.
@@ -144,6 +418,45 @@ impl PifpProtocol {
         rbac::transfer_super_admin(&env, &current_super_admin, &new_super_admin);
     }
 
+    /// Hand the `Admin` role from `caller` to `new_admin` atomically.
+    ///
+    /// - `caller` must authorize and hold `Admin`; it loses the role.
+    /// - `new_admin` is granted `Admin` in the same call, so there is no
+    ///   moment without an admin. Prefer `propose_admin` + `accept_admin`
+    ///   when the new address has not yet proven it can sign.
+    pub fn transfer_admin(env: Env, caller: Address, new_admin: Address) {
+        caller.require_auth();
+        rbac::transfer_admin(&env, &caller, &new_admin);
+    }
+
+    /// Offer `caller`'s `Admin` role to `new_admin`.
+    ///
+    /// Nothing changes until `new_admin` calls `accept_admin`.
+    /// - `caller` must authorize and hold `Admin`.
+    pub fn propose_admin(env: Env, caller: Address, new_admin: Address) {
+        caller.require_auth();
+        rbac::propose_admin(&env, &caller, &new_admin);
+    }
+
+    /// Withdraw `caller`'s pending `propose_admin` offer.
+    ///
+    /// - `caller` must authorize and hold `Admin`.
+    /// - Panics with `Error::NotAuthorized` if `caller` has nothing pending.
+    pub fn cancel_admin_proposal(env: Env, caller: Address) {
+        caller.require_auth();
+        rbac::cancel_admin_proposal(&env, &caller);
+    }
+
+    /// Take the `Admin` role proposed to `new_admin` via `propose_admin`.
+    ///
+    /// - `new_admin` must authorize.
+    /// - Panics with `Error::NotAuthorized` if no handoff is pending, or if
+    ///   the proposer is no longer an admin.
+    pub fn accept_admin(env: Env, new_admin: Address) {
+        new_admin.require_auth();
+        rbac::accept_admin(&env, &new_admin);
+    }
+
     /// Return the role held by `address`, or `None`.
     pub fn role_of(env: Env, address: Address) -> Option<Role> {
         rbac::role_of(&env, address)
@@ -154,6 +467,11 @@ impl PifpProtocol {
         rbac::has_role(&env, address, role)
     }
 
+    /// Return every address holding `role`; empty if the role is unassigned.
+    pub fn get_role_members(env: Env, role: Role) -> Vec<Address> {
+        rbac::role_members(&env, role)
+    }
+
     // ─────────────────────────────────────────────────────────
     // Emergency Control
     // ─────────────────────────────────────────────────────────
@@ -183,301 +501,3441 @@ impl PifpProtocol {
         storage::is_paused(&env)
     }
 
-    // ─────────────────────────────────────────────────────────
-    // Project lifecycle
-    // ─────────────────────────────────────────────────────────
+    /// Freeze a single token, e.g. one whose contract turned malicious.
+    ///
+    /// While paused, deposits, withdrawals, refunds and releases in `token`
+    /// panic with `Error::TokenPaused`; a release or `refund_all` batch
+    /// that would move it fails as a whole. Other tokens are unaffected.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn pause_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_token_paused(&env, &token, true);
+        events::emit_token_paused(&env, caller, token);
+    }
 
-    /// Register a new funding project.
+    /// Lift a token freeze set by `pause_token`.
     ///
-    /// `creator` must hold the `ProjectManager`, `Admin`, or `SuperAdmin` role.
-    pub fn register_project(
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn unpause_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_token_paused(&env, &token, false);
+        events::emit_token_unpaused(&env, caller, token);
+    }
+
+    /// Return true if `token` is frozen.
+    pub fn is_token_paused(env: Env, token: Address) -> bool {
+        storage::is_token_paused(&env, &token)
+    }
+
+    /// Return how much more `token` the contract holds than it accounts
+    /// for: its real balance minus every project balance, matching pool
+    /// and oracle bond in `token`.
+    ///
+    /// A positive result is untracked surplus, e.g. a direct transfer, that
+    /// an admin may recover with `emergency_withdraw`; a negative one means
+    /// the contract holds less than it owes. The tracked side comes from
+    /// running totals, so the cost is the same however many projects exist.
+    pub fn reconcile(env: Env, token: Address) -> i128 {
+        let held = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        held - Self::tracked_balance(&env, &token)
+    }
+
+    /// Sweep tokens that no project accounts for, e.g. a direct transfer
+    /// to the contract.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Only usable while the protocol is paused.
+    /// - Never touches the sum of tracked project balances of `token`.
+    ///
+    /// # Errors
+    /// - `Error::ProtocolNotPaused` if the protocol is running.
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    /// - `Error::NoUnaccountedBalance` if every unit held is tracked.
+    /// - `Error::InsufficientBalance` if `amount` exceeds the untracked surplus.
+    pub fn emergency_withdraw(
         env: Env,
-        creator: Address,
-        accepted_tokens: Vec<Address>,
-        goal: i128,
-        proof_hash: BytesN<32>,
-        deadline: u64,
-    ) -> Project {
-        Self::require_not_paused(&env);
-        creator.require_auth();
-        // RBAC gate: only authorised roles may create projects.
-        rbac::require_can_register(&env, &creator);
+        caller: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
 
-        if accepted_tokens.is_empty() {
-            panic_with_error!(&env, Error::EmptyAcceptedTokens);
+        if !storage::is_paused(&env) {
+            panic_with_error!(&env, Error::ProtocolNotPaused);
         }
-        if accepted_tokens.len() > 10 {
-            panic_with_error!(&env, Error::TooManyTokens);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
 
-        // Optimized duplicate token detection using hash-based lookup
-        // This replaces the O(n²) nested loop with O(n) complexity
-        Self::check_duplicate_tokens_optimized(&env, &accepted_tokens);
-
-        if goal <= 0 || goal > 1_000_000_000_000_000_000_000_000_000_000i128 {
-            // 10^30
-            panic_with_error!(&env, Error::InvalidGoal);
+        let token_client = token::Client::new(&env, &token);
+        let contract = env.current_contract_address();
+        let held = token_client.balance(&contract);
+        let tracked = Self::tracked_balance(&env, &token);
+        if held <= tracked {
+            panic_with_error!(&env, Error::NoUnaccountedBalance);
         }
-
-        let now = env.ledger().timestamp();
-        // Max 5 years deadline (5 * 365 * 24 * 60 * 60)
-        let max_deadline = now + 157_680_000;
-        if deadline <= now || deadline > max_deadline {
-            panic_with_error!(&env, Error::InvalidDeadline);
+        if amount > held - tracked {
+            panic_with_error!(&env, Error::InsufficientBalance);
         }
 
-        let id = get_and_increment_project_id(&env);
-        let project = Project {
-            id,
-            creator: creator.clone(),
-            accepted_tokens: accepted_tokens.clone(),
-            goal,
-            proof_hash,
-            deadline,
-            status: ProjectStatus::Funding,
-            donation_count: 0,
-        };
+        token_client.transfer(&contract, &to, &amount);
+        events::emit_emergency_withdrawal(&env, caller, token, to, amount);
+    }
 
-        save_project(&env, &project);
+    // ─────────────────────────────────────────────────────────
+    // Protocol fees
+    // ─────────────────────────────────────────────────────────
 
-        // Standardized event emission
-        if let Some(token) = accepted_tokens.get(0) {
-            events::emit_project_created(&env, id, creator, token, goal);
+    /// Set the platform fee taken from each token at release, in basis points.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Panics with `Error::InvalidAmount` if `bps` exceeds `MAX_FEE_BPS` (10%).
+    /// - The fee is only charged once a treasury is set via `set_treasury`.
+    pub fn set_fee_bps(env: Env, caller: Address, bps: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if bps > MAX_FEE_BPS {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
+        storage::set_fee_bps(&env, bps);
+    }
 
-        project
+    /// Set the address that receives platform fees.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn set_treasury(env: Env, caller: Address, treasury: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_treasury(&env, &treasury);
     }
 
-    pub fn get_project(env: Env, id: u64) -> Project {
-        load_project(&env, id)
+    /// Record the Stellar Asset Contract address of native XLM.
+    ///
+    /// The contract cannot look this up itself, so deployments set it once
+    /// (e.g. from `stellar contract id asset --asset native`) to enable
+    /// `deposit_native`. Projects still list it in `accepted_tokens` like any
+    /// other token; releases and refunds pay it out through the same SAC.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn set_native_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_native_token(&env, &token);
     }
 
-    /// Return the balance of `token` for `project_id`.
-    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
-        storage::get_token_balance(&env, project_id, &token)
+    /// Return the native XLM SAC address, or `None` if not configured.
+    pub fn get_native_token(env: Env) -> Option<Address> {
+        storage::get_native_token(&env)
     }
 
-    /// Return the current per-token balances for a project.
-    ///
-    /// Reconstructs the balance snapshot from persistent storage for every
-    /// token that was accepted at registration time.
-    ///
-    /// # Errors
-    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
-    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
-        let project = match maybe_load_project(&env, project_id) {
-            Some(p) => p,
-            None => panic_with_error!(&env, Error::ProjectNotFound),
-        };
-        get_all_balances(&env, &project)
+    /// Return the platform fee in basis points.
+    pub fn get_fee_bps(env: Env) -> u32 {
+        storage::get_fee_bps(&env)
     }
 
-    /// Deposit funds into a project.
-    ///
-    /// The `token` must be one of the project's accepted tokens.
-    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
-        Self::require_not_paused(&env);
-        donator.require_auth();
+    /// Return the fee treasury, or `None` if fees are not being collected.
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        storage::get_treasury(&env)
+    }
 
-        if amount <= 0 {
+    /// Reduce the platform fee for creators with a track record.
+    ///
+    /// Each tier is `(min_completed, fee_bps)`: a creator with at least
+    /// `min_completed` other completed projects pays `fee_bps` instead of
+    /// the base fee. The highest tier reached applies, and the base fee is
+    /// never exceeded. First-time creators always pay the base fee; an empty
+    /// list removes all rebates.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if there are more than `MAX_FEE_TIERS` tiers,
+    ///   a `min_completed` is zero or not strictly ascending, or a fee
+    ///   exceeds `MAX_FEE_BPS`.
+    pub fn set_fee_tiers(env: Env, caller: Address, tiers: Vec<(u32, u32)>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if tiers.len() > MAX_FEE_TIERS {
             panic_with_error!(&env, Error::InvalidAmount);
         }
-
-        // Read both config and state with a single helper that bumps TTLs
-        // atomically. This is the optimized retrieval pattern; it also returns
-        // the state needed for the subsequent checks.
-        let (config, mut state) = load_project_pair(&env, project_id);
-
-        // Check expiration
-        if env.ledger().timestamp() >= config.deadline {
-            if matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
-                state.status = ProjectStatus::Expired;
-                save_project_state(&env, project_id, &state);
+        let mut last_min = 0;
+        for (min_completed, bps) in tiers.iter() {
+            if min_completed <= last_min || bps > MAX_FEE_BPS {
+                panic_with_error!(&env, Error::InvalidAmount);
             }
-            panic_with_error!(&env, Error::ProjectExpired);
-        }
-
-        // Basic status check: must be Funding or Active.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
-            _ => panic_with_error!(&env, Error::ProjectNotActive),
-        }
-
-        // Optimized token verification using early termination
-        // This reduces average case complexity from O(n) to O(1) for first token
-        let is_accepted = Self::is_token_accepted(&config.accepted_tokens, &token);
-        if !is_accepted {
-            panic_with_error!(&env, Error::NotAuthorized);
+            last_min = min_completed;
         }
+        storage::set_fee_tiers(&env, &tiers);
+    }
 
-        // Check if this is a new unique (donator, token) pair.
-        let is_new_donor = !storage::has_donator_seen(&env, project_id, &donator, &token);
-        if is_new_donor {
-            // Increment donation count and mark as seen.
-            state.donation_count += 1;
-            storage::mark_donator_seen(&env, project_id, &donator, &token);
-            // Save the updated state.
-            save_project_state(&env, project_id, &state);
-        }
+    /// Return the veteran fee tiers as `(min_completed, fee_bps)` pairs.
+    pub fn get_fee_tiers(env: Env) -> Vec<(u32, u32)> {
+        storage::get_fee_tiers(&env)
+    }
 
-        // Transfer tokens from donator to contract.
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&donator, &env.current_contract_address(), &amount);
+    /// Return how many of `creator`'s projects have completed.
+    pub fn completed_projects_count(env: Env, creator: Address) -> u32 {
+        storage::get_completed_count(&env, &creator)
+    }
 
-        // Update the per-token balance.
-        let new_balance = storage::add_to_token_balance(&env, project_id, &token, amount);
-
-        // If this is the primary token and goal is reached, transition from Funding to Active.
-        if state.status == ProjectStatus::Funding {
-            if let Some(first_token) = config.accepted_tokens.get(0) {
-                if token == first_token && new_balance >= config.goal {
-                    state.status = ProjectStatus::Active;
-                    save_project_state(&env, project_id, &state);
-                    events::emit_project_active(&env, project_id);
-                }
-            }
-        }
+    /// Return the fee, in basis points, `creator` would pay on a new project.
+    pub fn get_effective_fee_bps(env: Env, creator: Address) -> u32 {
+        Self::tiered_fee_bps(&env, storage::get_completed_count(&env, &creator))
+    }
 
-        // Track per-donator refundable amount for this token.
-        storage::add_to_donator_balance(&env, project_id, &token, &donator, amount);
+    /// Require new projects to stay open for at least `secs` seconds.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Registrations with a deadline sooner than `now + secs` panic with
+    ///   `Error::InvalidDeadline`. Existing projects are unaffected.
+    pub fn set_min_funding_window(env: Env, caller: Address, secs: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_min_funding_window(&env, secs);
+    }
 
-        // Standardized event emission
-        events::emit_project_funded(&env, project_id, donator, amount);
+    /// Return the minimum funding window in seconds; `0` when unset.
+    pub fn get_min_funding_window(env: Env) -> u64 {
+        storage::get_min_funding_window(&env)
     }
 
-    /// Refund a donator from an expired project that was not verified.
-    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
-        donator.require_auth();
+    /// Set how many tokens a project may accept.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Checked by `register_project` and `add_accepted_token`; existing
+    ///   projects keep their tokens. Defaults to 10.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidAmount` if `max_tokens` is zero.
+    pub fn set_max_tokens(env: Env, caller: Address, max_tokens: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if max_tokens == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_max_tokens(&env, max_tokens);
+    }
 
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Return how many tokens a project may accept.
+    pub fn get_max_tokens(env: Env) -> u32 {
+        storage::get_max_tokens(&env)
+    }
 
-        if env.ledger().timestamp() >= config.deadline
+    /// Set the smallest goal a new project may register with.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Registrations below it panic with `Error::GoalBelowMinimum`.
+    ///   Existing projects are unaffected. Defaults to 0.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidAmount` if `min_goal` is negative.
+    pub fn set_min_goal(env: Env, caller: Address, min_goal: i128) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if min_goal < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_min_goal(&env, min_goal);
+    }
+
+    /// Return the smallest goal accepted at registration; `0` when unset.
+    pub fn get_min_goal(env: Env) -> i128 {
+        storage::get_min_goal(&env)
+    }
+
+    /// Limit how many projects one creator may have that are not yet
+    /// `Completed`, `Expired` or `Cancelled`; `None` lifts the limit.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Registrations past the limit panic with `Error::TooManyActiveProjects`.
+    ///   Existing projects are unaffected. Defaults to unlimited.
+    pub fn set_max_active_per_creator(env: Env, caller: Address, max: Option<u32>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_max_active_per_creator(&env, max);
+    }
+
+    /// Return the per-creator limit on live projects; `None` when unlimited.
+    pub fn get_max_active_per_creator(env: Env) -> Option<u32> {
+        storage::get_max_active_per_creator(&env)
+    }
+
+    /// Return how many of `creator`'s projects are not yet `Completed`,
+    /// `Expired` or `Cancelled`.
+    pub fn active_projects_count(env: Env, creator: Address) -> u32 {
+        storage::get_active_count(&env, &creator)
+    }
+
+    /// Keep projects live for `secs` seconds past their deadline.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Applies to every project, including existing ones: deposits are
+    ///   accepted and `expire_project` fails until `deadline + secs` has
+    ///   passed. Defaults to `0`.
+    pub fn set_grace_period(env: Env, caller: Address, secs: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_grace_period(&env, secs);
+    }
+
+    /// Return the grace period in seconds; `0` when unset.
+    pub fn get_grace_period(env: Env) -> u64 {
+        storage::get_grace_period(&env)
+    }
+
+    /// Give donors `secs` seconds after a project expires or is cancelled
+    /// to reclaim their deposits before `sweep_unrefunded` may run.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Applies to every project, including existing ones. `0`, the
+    ///   default, disables sweeping altogether.
+    pub fn set_refund_window(env: Env, caller: Address, secs: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_refund_window(&env, secs);
+    }
+
+    /// Return the refund window in seconds; `0` when sweeping is disabled.
+    pub fn get_refund_window(env: Env) -> u64 {
+        storage::get_refund_window(&env)
+    }
+
+    /// Require every project to commit to a distinct proof hash.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - While enabled, registering a hash already used by any project
+    ///   panics with `Error::DuplicateProof`. Off by default.
+    pub fn set_unique_proofs(env: Env, caller: Address, enabled: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_unique_proofs(&env, enabled);
+    }
+
+    /// Return true if proof hashes must be unique across projects.
+    pub fn is_unique_proofs(env: Env) -> bool {
+        storage::is_unique_proofs(&env)
+    }
+
+    /// Make releases robust against a failing token transfer.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - While enabled, `verify_and_release` and `finalize_release` attempt
+    ///   each transfer on its own. Transfers of a paused token, or that the
+    ///   token contract rejects, stay in the project's balance and the
+    ///   project becomes `PartiallyReleased` until `retry_release` sends
+    ///   them. Off by default, where any failing transfer aborts the release.
+    pub fn set_safe_release(env: Env, caller: Address, enabled: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_safe_release(&env, enabled);
+    }
+
+    /// Return true if releases survive failing token transfers.
+    pub fn is_safe_release(env: Env) -> bool {
+        storage::is_safe_release(&env)
+    }
+
+    /// Add `token` to the protocol-wide allowlist.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - While the list is empty any token may be used. Once it holds a
+    ///   token, `register_project` and `add_accepted_token` reject tokens
+    ///   not on it with `Error::TokenNotAllowed`. Projects that already
+    ///   accept a token keep it.
+    /// - Panics with `Error::TooManyTokens` beyond `MAX_ALLOWED_TOKENS`.
+    pub fn allow_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let mut allowed = storage::get_allowed_tokens(&env);
+        if allowed.contains(&token) {
+            return;
+        }
+        if allowed.len() >= MAX_ALLOWED_TOKENS {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+        allowed.push_back(token.clone());
+        storage::set_allowed_tokens(&env, &allowed);
+        events::emit_token_allowed(&env, caller, token);
+    }
+
+    /// Remove `token` from the protocol-wide allowlist.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Removing the last token reopens registration to every token.
+    pub fn disallow_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let mut allowed = storage::get_allowed_tokens(&env);
+        let Some(index) = allowed.first_index_of(&token) else {
+            return;
+        };
+        allowed.remove(index);
+        storage::set_allowed_tokens(&env, &allowed);
+        events::emit_token_disallowed(&env, caller, token);
+    }
+
+    /// Return true if new projects may accept `token`.
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        storage::is_token_allowed(&env, &token)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Project lifecycle
+    // ─────────────────────────────────────────────────────────
+
+    /// Register a new funding project.
+    ///
+    /// `creator` must hold the `ProjectManager`, `Admin`, or `SuperAdmin` role.
+    /// In `metadata`, `name` and `metadata_uri` are display-only; the URI can
+    /// be changed later with `set_metadata_uri`. `category` groups the project
+    /// for `list_projects_by_category`. A `hard_cap` (in goal units, at least
+    /// `goal`) stops deposits once reached; `None` leaves the project uncapped.
+    /// Every token must pass the protocol-wide allowlist (see `allow_token`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_project(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata: ProjectMetadata,
+        hard_cap: Option<i128>,
+    ) -> Project {
+        Self::create_project(
+            &env,
+            creator,
+            accepted_tokens,
+            goal,
+            proof_hash,
+            deadline,
+            metadata,
+            hard_cap,
+        )
+    }
+
+    /// Register a project whose funds are released in stages.
+    ///
+    /// `milestones` are cumulative funding targets, strictly increasing; the
+    /// last one is the project goal. Releasing milestone `i` pays out the
+    /// share `milestones[i] - milestones[i - 1]` of what remains to be paid.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidMilestones` if `milestones` is empty, longer
+    /// than `MAX_MILESTONES`, or not strictly increasing from a positive value.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_project_with_milestones(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        milestones: Vec<i128>,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata: ProjectMetadata,
+        hard_cap: Option<i128>,
+    ) -> Project {
+        let goal = Self::validate_milestones(&env, &milestones);
+
+        let project = Self::create_project(
+            &env,
+            creator,
+            accepted_tokens,
+            goal,
+            proof_hash,
+            deadline,
+            metadata,
+            hard_cap,
+        );
+        storage::set_milestones(&env, project.id, &milestones);
+        project
+    }
+
+    /// Register a milestone project with its own proof commitment per stage.
+    ///
+    /// Like `register_project_with_milestones`, but `proof_hashes[i]` is the
+    /// SHA-256 digest `release_milestone` checks for milestone `i`. The last
+    /// hash doubles as the project's `proof_hash`.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidMilestones` if `milestones` is invalid or
+    /// `proof_hashes` does not have one entry per milestone.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_staged_project(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        milestones: Vec<i128>,
+        proof_hashes: Vec<BytesN<32>>,
+        deadline: u64,
+        metadata: ProjectMetadata,
+        hard_cap: Option<i128>,
+    ) -> Project {
+        let goal = Self::validate_milestones(&env, &milestones);
+        if proof_hashes.len() != milestones.len() {
+            panic_with_error!(&env, Error::InvalidMilestones);
+        }
+
+        let project = Self::create_project(
+            &env,
+            creator,
+            accepted_tokens,
+            goal,
+            proof_hashes.last().unwrap(),
+            deadline,
+            metadata,
+            hard_cap,
+        );
+        storage::set_milestones(&env, project.id, &milestones);
+        storage::set_milestone_proofs(&env, project.id, &proof_hashes);
+        project
+    }
+
+    /// Register a project under a deterministic handle as well as its
+    /// numeric ID.
+    ///
+    /// Takes the same arguments as `register_project` less `hard_cap`, plus a
+    /// `nonce` chosen by the creator. `hard_cap` is left out to keep the
+    /// entrypoint at seven arguments, the most the generated client takes
+    /// without tripping `clippy::too_many_arguments`; creators who want a
+    /// cap call `set_hard_cap` right after registering. The
+    /// handle is `sha256(creator.to_xdr() || nonce)`
+    /// (see `compute_project_handle`), so clients can refer to the project
+    /// before the registration is confirmed and look it up afterwards with
+    /// `get_project_by_hash`.
+    ///
+    /// # Errors
+    /// Panics with `Error::HandleTaken` if `creator` already used `nonce`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_project_with_handle(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata: ProjectMetadata,
+        nonce: BytesN<32>,
+    ) -> Project {
+        let handle = Self::compute_project_handle(env.clone(), creator.clone(), nonce);
+        if storage::get_handle_owner(&env, &handle).is_some() {
+            panic_with_error!(&env, Error::HandleTaken);
+        }
+
+        let project = Self::create_project(
+            &env,
+            creator,
+            accepted_tokens,
+            goal,
+            proof_hash,
+            deadline,
+            metadata,
+            None,
+        );
+        storage::set_project_handle(&env, project.id, &handle);
+        project
+    }
+
+    /// Return the handle `register_project_with_handle` assigns to a project
+    /// registered by `creator` with `nonce`: `sha256(creator.to_xdr() || nonce)`.
+    pub fn compute_project_handle(env: Env, creator: Address, nonce: BytesN<32>) -> BytesN<32> {
+        let mut preimage = creator.to_xdr(&env);
+        preimage.append(&nonce.into());
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// Return the project registered under `handle`.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if no project has that handle.
+    pub fn get_project_by_hash(env: Env, handle: BytesN<32>) -> Project {
+        match storage::get_handle_owner(&env, &handle) {
+            Some(id) => load_project(&env, id),
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        }
+    }
+
+    /// Return the handle `project_id` was registered under, if any.
+    pub fn get_project_handle(env: Env, project_id: u64) -> Option<BytesN<32>> {
+        storage::get_project_handle(&env, project_id)
+    }
+
+    /// Register a project whose released funds vest to the creator linearly.
+    ///
+    /// Takes the same arguments as `register_project` less `hard_cap`, which
+    /// can be added with `set_hard_cap`. On verification the
+    /// balances stay in the contract and unlock over `vesting_duration_secs`
+    /// seconds; the creator collects them with `claim_vested`.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidSchedule` if `vesting_duration_secs` is zero.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_project_with_vesting(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata: ProjectMetadata,
+        vesting_duration_secs: u64,
+    ) -> Project {
+        if vesting_duration_secs == 0 {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+
+        let project = Self::create_project(
+            &env,
+            creator,
+            accepted_tokens,
+            goal,
+            proof_hash,
+            deadline,
+            metadata,
+            None,
+        );
+        storage::set_vesting_duration(&env, project.id, vesting_duration_secs);
+        project
+    }
+
+    /// Pay the creator the vested, not yet claimed part of `token`.
+    ///
+    /// After `t` seconds of a `d`-second vesting period, `total * t / d` has
+    /// vested, capped at `total` once the period ends. The protocol fee is
+    /// taken from each claim as with an immediate release. Returns the gross
+    /// amount claimed.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InsufficientBalance` if the project has not started vesting,
+    ///   holds no `token`, or everything vested so far was claimed.
+    pub fn claim_vested(env: Env, caller: Address, project_id: u64, token: Address) -> i128 {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        Self::enter_guard(&env);
+
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        let Some(mut grant) = storage::get_vesting_grant(&env, project_id, &token) else {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        };
+        let claimable = Self::vested_amount(&env, project_id, &grant) - grant.claimed;
+        if claimable <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        grant.claimed += claimable;
+        storage::set_vesting_grant(&env, project_id, &token, &grant);
+        storage::add_to_token_balance(&env, project_id, &token, -claimable);
+        Self::pay_creator(&env, project_id, &config.creator, &token, claimable);
+
+        Self::exit_guard(&env);
+        claimable
+    }
+
+    /// Return how much of `token` the creator could claim right now.
+    pub fn get_claimable(env: Env, project_id: u64, token: Address) -> i128 {
+        match storage::get_vesting_grant(&env, project_id, &token) {
+            Some(grant) => Self::vested_amount(&env, project_id, &grant) - grant.claimed,
+            None => 0,
+        }
+    }
+
+    /// Return a project's cumulative milestone targets; empty if it has none.
+    pub fn get_milestones(env: Env, project_id: u64) -> Vec<i128> {
+        storage::get_milestones(&env, project_id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Return how many milestones of a project have been released.
+    pub fn get_released_milestones(env: Env, project_id: u64) -> u32 {
+        storage::get_released_milestones(&env, project_id)
+    }
+
+    /// Return a project, panicking with `Error::ProjectNotFound` if `id`
+    /// does not exist. Use `find_project` to probe IDs without panicking.
+    pub fn get_project(env: Env, id: u64) -> Project {
+        load_project(&env, id)
+    }
+
+    /// Return the project with `id`, or `None` if there is none.
+    ///
+    /// The non-panicking counterpart of `get_project` (the generated client
+    /// already uses `try_get_project` for that call's `Result` form). Shares
+    /// its lookup with `list_projects`, which skips missing IDs the same way.
+    pub fn find_project(env: Env, id: u64) -> Option<Project> {
+        maybe_load_project(&env, id)
+    }
+
+    /// Return the number of project IDs issued so far.
+    ///
+    /// IDs are assigned sequentially from `0`, so every existing project has
+    /// an ID below this value.
+    pub fn get_project_count(env: Env) -> u64 {
+        storage::get_project_count(&env)
+    }
+
+    /// Return every admin-set protocol parameter in one read.
+    ///
+    /// Unset parameters report their defaults, exactly as the individual
+    /// getters (`get_fee_bps`, `get_grace_period`, …) do.
+    pub fn get_config(env: Env) -> GlobalConfig {
+        let bond = storage::get_oracle_bond(&env);
+        GlobalConfig {
+            version: storage::get_version(&env),
+            paused: storage::is_paused(&env),
+            fee_bps: storage::get_fee_bps(&env),
+            treasury: storage::get_treasury(&env),
+            fee_tiers: storage::get_fee_tiers(&env),
+            native_token: storage::get_native_token(&env),
+            min_funding_window: storage::get_min_funding_window(&env),
+            unique_proofs: storage::is_unique_proofs(&env),
+            safe_release: storage::is_safe_release(&env),
+            allowed_tokens: storage::get_allowed_tokens(&env),
+            grace_period: storage::get_grace_period(&env),
+            max_tokens: storage::get_max_tokens(&env),
+            min_goal: storage::get_min_goal(&env),
+            max_active_per_creator: storage::get_max_active_per_creator(&env),
+            refund_window: storage::get_refund_window(&env),
+            challenge_period: storage::get_challenge_period(&env),
+            match_ratio_bps: storage::get_match_ratio_bps(&env),
+            oracle_bond_token: bond.clone().map(|(token, _)| token),
+            min_oracle_stake: bond.map_or(0, |(_, min_stake)| min_stake),
+        }
+    }
+
+    /// Return protocol-wide metrics: project counts by status and the total
+    /// balance locked per token across all projects.
+    ///
+    /// Served from counters maintained on every status transition and
+    /// balance change, so the cost does not grow with the number of projects.
+    pub fn get_stats(env: Env) -> ProtocolStats {
+        let mut total_locked = Vec::new(&env);
+        for (token, balance) in storage::get_total_locked(&env).iter() {
+            total_locked.push_back(TokenBalance { token, balance });
+        }
+        ProtocolStats {
+            project_count: storage::get_project_count(&env),
+            funding: storage::get_status_count(&env, ProjectStatus::Funding),
+            active: storage::get_status_count(&env, ProjectStatus::Active),
+            completed: storage::get_status_count(&env, ProjectStatus::Completed),
+            expired: storage::get_status_count(&env, ProjectStatus::Expired),
+            cancelled: storage::get_status_count(&env, ProjectStatus::Cancelled),
+            pending_release: storage::get_status_count(&env, ProjectStatus::PendingRelease),
+            partially_released: storage::get_status_count(&env, ProjectStatus::PartiallyReleased),
+            total_locked,
+        }
+    }
+
+    /// Return the amount of `token` held across all project balances.
+    ///
+    /// Read from the same running counter as `get_stats`, so it can be
+    /// reconciled against the contract's own `token` balance. Balances still
+    /// owed to donors of expired or cancelled projects are included until
+    /// refunded or swept; match pools and oracle bonds are not.
+    pub fn get_total_locked(env: Env, token: Address) -> i128 {
+        storage::get_total_locked(&env).get(token).unwrap_or(0)
+    }
+
+    /// Return up to `limit` projects with IDs from `start_id` upwards.
+    ///
+    /// IDs with no stored project are skipped. `limit` is capped at
+    /// `MAX_PAGE_SIZE` to keep the call within instruction budgets.
+    pub fn list_projects(env: Env, start_id: u64, limit: u32) -> Vec<Project> {
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let count = storage::get_project_count(&env);
+
+        let mut projects = Vec::new(&env);
+        let mut id = start_id;
+        while id < count && projects.len() < limit {
+            if let Some(project) = maybe_load_project(&env, id) {
+                projects.push_back(project);
+            }
+            id += 1;
+        }
+        projects
+    }
+
+    /// Return the balance of `token` for `project_id`.
+    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_token_balance(&env, project_id, &token)
+    }
+
+    /// Return the balances of several tokens for `project_id`, in the order
+    /// given. Tokens the project does not accept report `0`.
+    ///
+    /// # Errors
+    /// - `Error::TooManyTokens` if more tokens are asked for than a project
+    ///   may accept (see `set_max_tokens`).
+    /// - `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_balances_for(env: Env, project_id: u64, tokens: Vec<Address>) -> Vec<i128> {
+        if tokens.len() > storage::get_max_tokens(&env) {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+        let config = storage::load_project_config(&env, project_id);
+
+        let mut balances = Vec::new(&env);
+        for token in tokens.iter() {
+            let balance = if Self::is_token_accepted(&config.accepted_tokens, &token) {
+                storage::get_token_balance(&env, project_id, &token)
+            } else {
+                0
+            };
+            balances.push_back(balance);
+        }
+        balances
+    }
+
+    /// Return the last second at which `project_id` is still live: its
+    /// deadline plus the current grace period.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_effective_deadline(env: Env, project_id: u64) -> u64 {
+        let config = storage::load_project_config(&env, project_id);
+        Self::effective_deadline(&env, &config)
+    }
+
+    /// Classify how close `project_id` is to its deadline.
+    ///
+    /// Uses the same grace-period arithmetic as `expire_project`, so
+    /// `Passed` means the project can be expired now. The status ignores the
+    /// lifecycle state: a completed project still reports its deadline.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_deadline_status(env: Env, project_id: u64) -> DeadlineStatus {
+        let config = storage::load_project_config(&env, project_id);
+        let now = env.ledger().timestamp();
+        if Self::is_past_grace(&env, &config) {
+            DeadlineStatus::Passed
+        } else if now > config.deadline {
+            DeadlineStatus::InGracePeriod
+        } else if config.deadline - now <= IMMINENT_WINDOW_SECS {
+            DeadlineStatus::Imminent
+        } else {
+            DeadlineStatus::Upcoming
+        }
+    }
+
+    /// Return the fields a project list view needs in a single call.
+    ///
+    /// `raised` matches `get_funding_progress`; `donor_count` matches
+    /// `get_donor_count`.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_summary(env: Env, project_id: u64) -> ProjectSummary {
+        let (config, state) = load_project_pair(&env, project_id);
+        ProjectSummary {
+            project_id,
+            status: state.status,
+            goal: config.goal,
+            raised: Self::raised_value(&env, &config),
+            token_count: config.accepted_tokens.len(),
+            donor_count: state.donor_count,
+            deadline: config.deadline,
+        }
+    }
+
+    /// Return the number of unique donors currently backing `project_id`.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_donor_count(env: Env, project_id: u64) -> u32 {
+        storage::load_project_state(&env, project_id).donor_count
+    }
+
+    /// Return `(raised, goal)` for a project in one call.
+    ///
+    /// `raised` is the price-normalized value of every token balance in goal
+    /// units. The first accepted token is priced at par, so a single-token
+    /// project reports its raw balance.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_funding_progress(env: Env, project_id: u64) -> (i128, i128) {
+        let config = match storage::maybe_load_project_config(&env, project_id) {
+            Some(c) => c,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        (Self::raised_value(&env, &config), config.goal)
+    }
+
+    /// Return how much is still needed to reach the goal, in goal units.
+    ///
+    /// Uses the same price-normalized total as `get_funding_progress` (raw
+    /// balance for a single-token project) and returns `0` once the goal is
+    /// met or exceeded.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_remaining_to_goal(env: Env, project_id: u64) -> i128 {
+        let config = storage::load_project_config(&env, project_id);
+        (config.goal - Self::raised_value(&env, &config)).max(0)
+    }
+
+    /// Return the tokens a project accepts, in registration order with any
+    /// tokens added later appended.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_accepted_tokens(env: Env, project_id: u64) -> Vec<Address> {
+        storage::load_project_config(&env, project_id).accepted_tokens
+    }
+
+    /// Return the current per-token balances for a project.
+    ///
+    /// Reconstructs the balance snapshot from persistent storage for every
+    /// accepted token. Entries always follow the project's `accepted_tokens`
+    /// order, regardless of the order deposits arrived in, so callers may
+    /// index into `balances` positionally.
+    ///
+    /// Convenience for [`PifpProtocol::get_project_balances_paged`] starting
+    /// at the first token; at most `MAX_PAGE_SIZE` entries are returned.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
+        Self::get_project_balances_paged(env, project_id, 0, MAX_PAGE_SIZE)
+    }
+
+    /// Return up to `limit` per-token balances of a project, starting at
+    /// index `start` of its `accepted_tokens`.
+    ///
+    /// `limit` is capped at `MAX_PAGE_SIZE`; a `start` past the last token
+    /// yields an empty page.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_balances_paged(
+        env: Env,
+        project_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> ProjectBalances {
+        let project = match maybe_load_project(&env, project_id) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        get_all_balances(&env, &project, start, limit.min(MAX_PAGE_SIZE))
+    }
+
+    /// Preview what a release of `project_id` would pay its creator right now.
+    ///
+    /// Returns `(token, net)` for every accepted token that would be
+    /// transferred, in `accepted_tokens` order, using the same match and fee
+    /// arithmetic as `verify_and_release`. Nothing is written and no tokens move. For
+    /// vesting projects the amounts are what vests in total, not what is
+    /// claimable immediately.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn simulate_release(env: Env, project_id: u64) -> Vec<(Address, i128)> {
+        let config = storage::load_project_config(&env, project_id);
+
+        let mut payouts = Vec::new(&env);
+        for token in config.accepted_tokens.iter() {
+            let raised = storage::get_token_balance(&env, project_id, &token);
+            let balance = raised + Self::match_for(&env, project_id, &token, raised);
+            let net = balance - Self::protocol_fee(&env, project_id, &config.creator, balance);
+            if net > 0 {
+                payouts.push_back((token, net));
+            }
+        }
+        payouts
+    }
+
+    /// Return up to `limit` projects tagged `category` with IDs from
+    /// `start_id` upwards.
+    ///
+    /// Reads the category's ID index, so unrelated projects are never
+    /// loaded. `limit` is capped at `MAX_PAGE_SIZE`; an unknown category
+    /// yields an empty vector.
+    pub fn list_projects_by_category(
+        env: Env,
+        category: Symbol,
+        start_id: u64,
+        limit: u32,
+    ) -> Vec<Project> {
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let mut projects = Vec::new(&env);
+        for id in storage::get_category_index(&env, &category).iter() {
+            if projects.len() >= limit {
+                break;
+            }
+            if id >= start_id {
+                projects.push_back(load_project(&env, id));
+            }
+        }
+        projects
+    }
+
+    /// Return the IDs of projects registered by `creator`, oldest first.
+    ///
+    /// Holds at most `MAX_ACCOUNT_PROJECTS` (100) entries; projects
+    /// registered after that are not listed.
+    pub fn get_projects_by_creator(env: Env, creator: Address) -> Vec<u64> {
+        storage::get_creator_projects(&env, &creator)
+    }
+
+    /// Return the IDs of projects `donor` has deposited to, in order of
+    /// first deposit.
+    ///
+    /// Holds at most `MAX_ACCOUNT_PROJECTS` (100) entries; projects
+    /// funded after that are not listed.
+    pub fn get_projects_donated_by(env: Env, donor: Address) -> Vec<u64> {
+        storage::get_donor_projects(&env, &donor)
+    }
+
+    /// Return `donator`'s current balance of each token in `project_id`.
+    ///
+    /// Only tokens with a non-zero balance are listed, so a donator who never
+    /// contributed (or has been fully refunded) gets an empty vector. Each
+    /// balance is exactly what `refund` would pay out.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_donor_contribution(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+    ) -> Vec<DonorBalance> {
+        let config = match storage::maybe_load_project_config(&env, project_id) {
+            Some(c) => c,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+
+        let mut contributions = Vec::new(&env);
+        for token in config.accepted_tokens.iter() {
+            let balance = storage::get_donator_balance(&env, project_id, &token, &donator);
+            if balance > 0 {
+                contributions.push_back(DonorBalance { token, balance });
+            }
+        }
+        contributions
+    }
+
+    /// Return up to `limit` donators of `token` to `project_id`, largest
+    /// current balance first, with ties ordered by address, among the
+    /// `MAX_DONOR_SCAN` donation index entries from `start`.
+    ///
+    /// Computed on read; `limit` is capped at `MAX_PAGE_SIZE` and donators
+    /// with a zero balance are omitted. Each donator appears once per token
+    /// in the index, so clients rank a larger project by calling with
+    /// `start` stepping by `MAX_DONOR_SCAN` up to the project's
+    /// `donation_count` and merging the pages.
+    pub fn get_top_donors(
+        env: Env,
+        project_id: u64,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(Address, i128)> {
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let state = storage::load_project_state(&env, project_id);
+
+        let mut top: Vec<(Address, i128)> = Vec::new(&env);
+        if limit == 0 {
+            return top;
+        }
+        let end = start
+            .saturating_add(MAX_DONOR_SCAN)
+            .min(state.donation_count);
+        for index in start..end {
+            let Some((donator, entry_token)) = storage::get_donation_entry(&env, project_id, index)
+            else {
+                continue;
+            };
+            if entry_token != token {
+                continue;
+            }
+            let amount = storage::get_donator_balance(&env, project_id, &token, &donator);
+            if amount <= 0 {
+                continue;
+            }
+
+            let mut pos = top.len();
+            for (i, (other, other_amount)) in top.iter().enumerate() {
+                if amount > other_amount || (amount == other_amount && donator < other) {
+                    pos = i as u32;
+                    break;
+                }
+            }
+            if pos < limit {
+                top.insert(pos, (donator, amount));
+                if top.len() > limit {
+                    top.pop_back();
+                }
+            }
+        }
+        top
+    }
+
+    /// Replace the metadata URI of `project_id`.
+    ///
+    /// Only the project creator may call this; financial fields are untouched.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    pub fn set_metadata_uri(env: Env, caller: Address, project_id: u64, uri: String) {
+        caller.require_auth();
+
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        let mut metadata = storage::get_project_metadata(&env, project_id);
+        metadata.metadata_uri = uri;
+        storage::set_project_metadata(&env, project_id, &metadata);
+    }
+
+    /// Record the impact metric of a completed project, e.g. beneficiaries
+    /// reached. A later report replaces the earlier one; `get_project`
+    /// returns the latest as `reported_impact`.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` / `Error::NotProjectOracle` if `caller` is
+    ///   neither the project creator nor one of its oracles.
+    /// - `Error::ProjectNotActive` unless the project is `Completed` or
+    ///   `PartiallyReleased`.
+    /// - `Error::InvalidAmount` if `value` is negative.
+    pub fn report_impact(env: Env, caller: Address, project_id: u64, value: i128) {
+        caller.require_auth();
+
+        let (config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            Self::require_project_oracle(&env, project_id, &caller);
+        }
+        if !matches!(
+            state.status,
+            ProjectStatus::Completed | ProjectStatus::PartiallyReleased
+        ) {
+            panic_with_error!(&env, Error::ProjectNotActive);
+        }
+        if value < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        storage::set_reported_impact(&env, project_id, value);
+        events::emit_impact_reported(&env, project_id, caller, value);
+    }
+
+    /// Set the smallest deposit `project_id` will accept.
+    ///
+    /// Only the project creator may call this. An `amount` of `0` removes
+    /// the floor.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidAmount` if `amount` is negative.
+    pub fn set_min_deposit(env: Env, caller: Address, project_id: u64, amount: i128) {
+        caller.require_auth();
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if amount < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        state.min_deposit = amount;
+        save_project_state(&env, project_id, &state);
+    }
+
+    /// Push back the deadline of a project that is still open.
+    ///
+    /// Only the project creator may call this.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::ProjectExpired` if the deadline has already passed.
+    /// - `Error::ProjectNotActive` if the project is `Completed`.
+    /// - `Error::InvalidDeadline` if `new_deadline` is not later than the
+    ///   current deadline or exceeds the 5-year horizon.
+    pub fn extend_deadline(env: Env, caller: Address, project_id: u64, new_deadline: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+            ProjectStatus::Completed
+            | ProjectStatus::Cancelled
+            | ProjectStatus::PendingRelease
+            | ProjectStatus::PartiallyReleased => {
+                panic_with_error!(&env, Error::ProjectNotActive)
+            }
+        }
+        if now > config.deadline {
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+
+        // Same 5-year horizon as `register_project`.
+        if new_deadline <= config.deadline || new_deadline > now + 157_680_000 {
+            panic_with_error!(&env, Error::InvalidDeadline);
+        }
+
+        let old_deadline = config.deadline;
+        config.deadline = new_deadline;
+        storage::save_project_config(&env, &config);
+
+        events::emit_deadline_extended(&env, project_id, old_deadline, new_deadline);
+    }
+
+    /// Set the price of `token` for `project_id`, in goal units per
+    /// `PRICE_SCALE` units of the token.
+    ///
+    /// The first accepted token is the project's unit of account and is
+    /// priced at par (`PRICE_SCALE`) unless set explicitly; every other
+    /// token needs a price before it can be deposited.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `token` is not accepted by the project.
+    /// - `Error::InvalidAmount` if `price` is not positive.
+    pub fn set_token_price(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        token: Address,
+        price: i128,
+    ) {
+        caller.require_auth();
+        rbac::require_oracle(&env, &caller);
+
+        let config = storage::load_project_config(&env, project_id);
+        if !Self::is_token_accepted(&config.accepted_tokens, &token) {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if price <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        storage::set_token_price(&env, project_id, &token, price);
+    }
+
+    /// Return the price used for `token` in `project_id`, if any.
+    pub fn get_token_price(env: Env, project_id: u64, token: Address) -> Option<i128> {
+        let config = storage::load_project_config(&env, project_id);
+        storage::get_token_price(&env, project_id, &token)
+            .or_else(|| (config.accepted_tokens.get(0) == Some(token)).then_some(PRICE_SCALE))
+    }
+
+    /// Let a `Funding` project turn `Active` once it raises `bps` basis
+    /// points of its goal instead of the whole goal.
+    ///
+    /// Only the project creator may call this. Projects default to 10 000
+    /// (100%). If the value already raised meets the new target, the project
+    /// moves straight to `Active`.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidTransition` if the project is not `Funding`.
+    /// - `Error::InvalidAmount` if `bps` is zero or above 10 000.
+    pub fn set_release_threshold_bps(env: Env, caller: Address, project_id: u64, bps: u32) {
+        caller.require_auth();
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if bps == 0 || bps as i128 > BPS_DENOMINATOR {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_release_threshold_bps(&env, project_id, bps);
+
+        if Self::raised_value(&env, &config) >= Self::activation_target(&env, &config) {
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Active);
+            save_project_state(&env, project_id, &state);
+            events::emit_project_active(&env, project_id);
+        }
+    }
+
+    /// Return the share of the goal, in basis points, at which a project
+    /// turns `Active`; 10 000 unless lowered by the creator.
+    pub fn get_release_threshold_bps(env: Env, project_id: u64) -> u32 {
+        storage::get_release_threshold_bps(&env, project_id)
+    }
+
+    /// Split the released funds of `project_id` among `beneficiaries`.
+    ///
+    /// Each entry pairs a recipient with its share in basis points. Every
+    /// payout — full release, milestone or vesting claim — is divided by
+    /// these shares after the protocol fee. Without a split the creator
+    /// receives everything.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidTransition` once the project has left `Funding` and
+    ///   `Active`.
+    /// - `Error::SharesMustSumTo10000` if the list is empty, longer than
+    ///   `MAX_BENEFICIARIES`, or its shares do not add up to 10 000.
+    pub fn set_beneficiaries(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        beneficiaries: Vec<(Address, u32)>,
+    ) {
+        caller.require_auth();
+        let (config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        if beneficiaries.is_empty() || beneficiaries.len() > MAX_BENEFICIARIES {
+            panic_with_error!(&env, Error::SharesMustSumTo10000);
+        }
+        let mut total: u32 = 0;
+        for (_, share_bps) in beneficiaries.iter() {
+            total = total.saturating_add(share_bps);
+        }
+        if total != BPS_DENOMINATOR as u32 {
+            panic_with_error!(&env, Error::SharesMustSumTo10000);
+        }
+
+        storage::set_beneficiaries(&env, project_id, &beneficiaries);
+    }
+
+    /// Return who receives `project_id`'s released funds and their shares
+    /// in basis points; the creator alone with 10 000 unless split.
+    pub fn get_beneficiaries(env: Env, project_id: u64) -> Vec<(Address, u32)> {
+        let config = storage::load_project_config(&env, project_id);
+        storage::get_beneficiaries(&env, project_id)
+            .unwrap_or_else(|| Vec::from_array(&env, [(config.creator, BPS_DENOMINATOR as u32)]))
+    }
+
+    /// Change the goal of a project that is still `Funding`.
+    ///
+    /// Only the project creator may call this. If the value already raised
+    /// meets the activation target for `new_goal`, the project moves
+    /// straight to `Active`.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidTransition` if the project is not `Funding`.
+    /// - `Error::InvalidGoal` if `new_goal` is out of range or above the hard cap.
+    /// - `Error::GoalMismatch` if the project was registered with milestones.
+    /// - `Error::GoalBelowRaised` if `new_goal` is below the value raised so far.
+    pub fn update_goal(env: Env, caller: Address, project_id: u64, new_goal: i128) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (mut config, mut state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if new_goal <= 0
+            || new_goal > MAX_GOAL
+            || matches!(config.hard_cap, Some(cap) if new_goal > cap)
+        {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        // The goal of a milestone project is pinned to its last milestone.
+        if storage::get_milestones(&env, project_id).is_some() {
+            panic_with_error!(&env, Error::GoalMismatch);
+        }
+
+        let raised = Self::raised_value(&env, &config);
+        if new_goal < raised {
+            panic_with_error!(&env, Error::GoalBelowRaised);
+        }
+
+        let old_goal = config.goal;
+        config.goal = new_goal;
+        storage::save_project_config(&env, &config);
+        events::emit_goal_updated(&env, project_id, old_goal, new_goal);
+
+        if raised >= Self::activation_target(&env, &config) {
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Active);
+            save_project_state(&env, project_id, &state);
+            events::emit_project_active(&env, project_id);
+        }
+    }
+
+    /// Set or lift (`None`) the hard cap of a project still taking deposits.
+    ///
+    /// Only the project creator may call this. The cap is in goal units.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidTransition` if the project is not `Funding` or `Active`.
+    /// - `Error::InvalidGoal` if `hard_cap` is below the goal.
+    /// - `Error::HardCapExceeded` if `hard_cap` is below the value raised so far.
+    pub fn set_hard_cap(env: Env, caller: Address, project_id: u64, hard_cap: Option<i128>) {
+        caller.require_auth();
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        if let Some(cap) = hard_cap {
+            if cap < config.goal {
+                panic_with_error!(&env, Error::InvalidGoal);
+            }
+            if cap < Self::raised_value(&env, &config) {
+                panic_with_error!(&env, Error::HardCapExceeded);
+            }
+        }
+        config.hard_cap = hard_cap;
+        storage::save_project_config(&env, &config);
+    }
+
+    /// Accept an additional token for a `Funding` or `Active` project.
+    ///
+    /// Only the project creator may call this. Tokens other than the first
+    /// still need a price from `set_token_price` before they can be deposited.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::ProjectExpired` / `Error::ProjectNotActive` if the project
+    ///   is no longer open.
+    /// - `Error::DuplicateToken` if `token` is already accepted.
+    /// - `Error::TokenNotAllowed` if `token` is not on the protocol-wide
+    ///   allowlist.
+    /// - `Error::TooManyTokens` if the project already accepts
+    ///   the maximum set by `set_max_tokens` (10 by default).
+    pub fn add_accepted_token(env: Env, caller: Address, project_id: u64, token: Address) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+            ProjectStatus::Completed
+            | ProjectStatus::Cancelled
+            | ProjectStatus::PendingRelease
+            | ProjectStatus::PartiallyReleased => {
+                panic_with_error!(&env, Error::ProjectNotActive)
+            }
+        }
+
+        config.accepted_tokens.push_back(token.clone());
+        if config.accepted_tokens.len() > storage::get_max_tokens(&env) {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+        Self::check_duplicate_tokens_optimized(&env, &config.accepted_tokens);
+        Self::require_token_allowed(&env, &token);
+
+        storage::save_project_config(&env, &config);
+        storage::set_token_balance(&env, project_id, &token, 0);
+
+        events::emit_token_added(&env, project_id, token);
+    }
+
+    /// Let verification release a project that has not reached its goal.
+    ///
+    /// By default only `Active` projects (goal reached) can be released and
+    /// `verify_and_release` on a `Funding` project panics with
+    /// `Error::GoalNotMet`. With this flag set, whatever was raised is paid
+    /// out instead.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    pub fn set_allow_partial_release(env: Env, caller: Address, project_id: u64, allowed: bool) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        storage::set_partial_release_allowed(&env, project_id, allowed);
+    }
+
+    /// Cap how much one donor may contribute in each token, or lift the cap
+    /// with `None`.
+    ///
+    /// A donor's contribution is their refundable balance, so withdrawals
+    /// free up room under the cap. Relayed deposits count against the
+    /// beneficiary. Projects start uncapped.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidAmount` if `cap` is zero or negative.
+    pub fn set_per_donor_cap(env: Env, caller: Address, project_id: u64, cap: Option<i128>) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if matches!(cap, Some(c) if c <= 0) {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_donor_cap(&env, project_id, cap);
+    }
+
+    /// Return the per-donor, per-token contribution cap, if any.
+    pub fn get_per_donor_cap(env: Env, project_id: u64) -> Option<i128> {
+        storage::get_donor_cap(&env, project_id)
+    }
+
+    /// Restrict deposits to donors the creator has listed.
+    ///
+    /// Projects start open; once enabled, `deposit` and recurring deposits
+    /// from unlisted donors fail with `Error::DonorNotAllowed`. Listed donors
+    /// are added with `add_allowed_donor`, before or after enabling.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    pub fn enable_allowlist(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        storage::set_allowlist_on(&env, project_id);
+    }
+
+    /// Admit `donor` to the project's allowlist.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    pub fn add_allowed_donor(env: Env, caller: Address, project_id: u64, donor: Address) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        storage::add_listed_donor(&env, project_id, &donor);
+    }
+
+    /// Return true if `donor` may deposit: the project is open, or
+    /// `donor` is on its allowlist.
+    pub fn is_donor_allowed(env: Env, project_id: u64, donor: Address) -> bool {
+        Self::donor_allowed(&env, project_id, &donor)
+    }
+
+    /// Cancel a project that is still `Funding`.
+    ///
+    /// Only the project creator may call this. Donors recover their
+    /// deposits through `refund`, `refund_deposit` or `refund_all`; the
+    /// project can no longer be funded or verified.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is not the project creator.
+    /// - `Error::InvalidTransition` if the project is not `Funding`.
+    pub fn cancel_project(env: Env, caller: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Cancelled);
+        save_project_state(&env, project_id, &state);
+
+        events::emit_project_cancelled(&env, project_id, caller);
+    }
+
+    /// Deposit funds into a project.
+    ///
+    /// The `token` must be one of the project's accepted tokens.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if `amount` is zero or negative.
+    /// - `Error::DeadlinePassed` once the deadline is behind the ledger.
+    /// - `Error::DepositBelowMinimum`, `Error::PriceNotSet` and
+    ///   `Error::HardCapExceeded` from the project's funding rules.
+    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        Self::deposit_authorized(&env, project_id, &donator, &token, amount);
+    }
+
+    /// Body of `deposit` once the protocol pause and `donator`'s
+    /// authorization have been checked.
+    fn deposit_authorized(
+        env: &Env,
+        project_id: u64,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+    ) {
+        Self::enter_guard(env);
+
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+
+        // Read both config and state with a single helper that bumps TTLs
+        // atomically. This is the optimized retrieval pattern; it also returns
+        // the state needed for the subsequent checks.
+        let (config, state) = load_project_pair(env, project_id);
+        if let Err(err) = Self::check_deposit(env, &config, &state, donator, token, amount) {
+            panic_with_error!(env, err);
+        }
+
+        Self::record_deposit(env, &config, state, donator, donator, token, amount, false);
+        Self::exit_guard(env);
+    }
+
+    /// Whether `deposit(project_id, donator, token, amount)` would succeed
+    /// right now, short of the donator's own token balance and authorization.
+    ///
+    /// Runs the same guards as `deposit` — protocol and token pause, status,
+    /// deadline, allowlist, minimum deposit, accepted token, per-donor cap,
+    /// pricing and hard cap — without writing anything. Unknown projects
+    /// yield `false`.
+    pub fn can_deposit(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+    ) -> bool {
+        if storage::is_paused(&env) || amount <= 0 {
+            return false;
+        }
+        let (Some(config), Some(state)) = (
+            storage::maybe_load_project_config(&env, project_id),
+            storage::maybe_load_project_state(&env, project_id),
+        ) else {
+            return false;
+        };
+        Self::check_deposit(&env, &config, &state, &donator, &token, amount).is_ok()
+    }
+
+    /// Deposit native XLM into a project.
+    ///
+    /// Same as `deposit` with the configured native SAC as the token.
+    ///
+    /// # Errors
+    /// - `Error::NotInitialized` if `set_native_token` has not been called.
+    /// - Everything `deposit` can return, including `Error::NotAuthorized`
+    ///   if the project does not accept native XLM.
+    pub fn deposit_native(env: Env, project_id: u64, donator: Address, amount: i128) {
+        let native = match storage::get_native_token(&env) {
+            Some(token) => token,
+            None => panic_with_error!(&env, Error::NotInitialized),
+        };
+        Self::deposit(env, project_id, donator, native, amount);
+    }
+
+    /// Deposit exactly once per client-supplied `nonce`.
+    ///
+    /// Same as `deposit`, but a relayer retrying the same submission cannot
+    /// deposit twice. Each donor's nonces must strictly increase (compared
+    /// as big-endian bytes, e.g. a counter or timestamp): the contract keeps
+    /// only the highest one used, so deposits submitted concurrently must be
+    /// sent in nonce order. A nonce at or below the highest is ignored,
+    /// nothing moves and a `dup_nonce` event is emitted. A retry is thus
+    /// safe for as long as the donor has not moved on to a newer nonce;
+    /// there is no time limit.
+    ///
+    /// Returns `NonceOutcome::Deposited` if the deposit was made,
+    /// `NonceOutcome::Replayed` if `nonce` equals the highest one used (the
+    /// last deposit was already made), or `NonceOutcome::Stale` if it is
+    /// lower, in which case that deposit may never have been made and must
+    /// be resubmitted under a new nonce.
+    ///
+    /// # Errors
+    /// Everything `deposit` can return; a failed deposit does not consume
+    /// the nonce.
+    pub fn deposit_with_nonce(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        nonce: BytesN<32>,
+    ) -> NonceOutcome {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        if let Some(last) = storage::get_last_nonce(&env, &donator) {
+            if nonce <= last {
+                let stale = nonce < last;
+                events::emit_nonce_replayed(&env, project_id, donator, nonce, stale);
+                return if stale {
+                    NonceOutcome::Stale
+                } else {
+                    NonceOutcome::Replayed
+                };
+            }
+        }
+        storage::set_last_nonce(&env, &donator, &nonce);
+        Self::deposit_authorized(&env, project_id, &donator, &token, amount);
+        NonceOutcome::Deposited
+    }
+
+    /// Deposit with a short note or campaign tag attached.
+    ///
+    /// Same as `deposit`, then records `memo` as `donator`'s latest memo for
+    /// the project and emits a `memo` event after the `deposit` event, so
+    /// off-chain tooling can reconcile the donation.
+    ///
+    /// # Errors
+    /// The same as [`PifpProtocol::deposit`].
+    pub fn deposit_with_memo(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        memo: Symbol,
+    ) {
+        Self::deposit(
+            env.clone(),
+            project_id,
+            donator.clone(),
+            token.clone(),
+            amount,
+        );
+        storage::set_deposit_memo(&env, project_id, &donator, &memo);
+        events::emit_deposit_memo(&env, project_id, donator, token, amount, memo);
+    }
+
+    /// Return the memo of `donator`'s latest `deposit_with_memo` to
+    /// `project_id`, if any.
+    pub fn get_deposit_memo(env: Env, project_id: u64, donator: Address) -> Option<Symbol> {
+        storage::get_deposit_memo(&env, project_id, &donator)
+    }
+
+    /// Make several deposits under one authorization from `donator`.
+    ///
+    /// Each `(project_id, token, amount)` entry is deposited in order with
+    /// the same checks and events as `deposit`. If any entry fails, the
+    /// whole call reverts and no deposit is kept.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if `deposits` is empty or holds more than
+    ///   `MAX_BATCH_DEPOSITS` entries.
+    /// - Any error `deposit` would raise for one of the entries.
+    pub fn batch_deposit(env: Env, donator: Address, deposits: Vec<(u64, Address, i128)>) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        if deposits.is_empty() || deposits.len() > MAX_BATCH_DEPOSITS {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        Self::enter_guard(&env);
+
+        for (project_id, token, amount) in deposits.iter() {
+            if amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+            let (config, state) = load_project_pair(&env, project_id);
+            if let Err(err) = Self::check_deposit(&env, &config, &state, &donator, &token, amount) {
+                panic_with_error!(&env, err);
+            }
+            Self::record_deposit(
+                &env, &config, state, &donator, &donator, &token, amount, false,
+            );
+        }
+        Self::exit_guard(&env);
+    }
+
+    /// Deposit on behalf of `beneficiary`, paid for by `relayer`.
+    ///
+    /// `relayer` authorizes and funds the transfer, but the contribution is
+    /// credited to `beneficiary`: it counts toward their balance, appears in
+    /// donor queries, and any refund or withdrawal goes to them. Allowlists
+    /// and deposit rules apply to `beneficiary` exactly as in `deposit`.
+    ///
+    /// # Errors
+    /// The same as [`PifpProtocol::deposit`].
+    pub fn deposit_for(
+        env: Env,
+        relayer: Address,
+        project_id: u64,
+        beneficiary: Address,
+        token: Address,
+        amount: i128,
+    ) {
+        Self::require_not_paused(&env);
+        relayer.require_auth();
+        Self::enter_guard(&env);
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, state) = load_project_pair(&env, project_id);
+        if let Err(err) = Self::check_deposit(&env, &config, &state, &beneficiary, &token, amount) {
+            panic_with_error!(&env, err);
+        }
+
+        Self::record_deposit(
+            &env,
+            &config,
+            state,
+            &relayer,
+            &beneficiary,
+            &token,
+            amount,
+            false,
+        );
+        events::emit_relayed_deposit(&env, project_id, relayer, beneficiary, token, amount);
+        Self::exit_guard(&env);
+    }
+
+    /// Schedule `occurrences` deposits of `amount` every `interval_secs`.
+    ///
+    /// The first deposit is due immediately. The donator's authorization
+    /// also grants this contract an allowance of `amount * occurrences` on
+    /// `token`, which `execute_due_recurring` draws from. The allowance
+    /// lasts as long as the ledger's maximum entry TTL allows, so very long schedules
+    /// may need it renewed through the token contract.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    /// - `Error::InvalidSchedule` if `interval_secs` is zero, `occurrences`
+    ///   is zero or above `MAX_RECURRING_OCCURRENCES`, or the project
+    ///   already has `MAX_RECURRING_SCHEDULES` schedules.
+    /// - Any error a `deposit` of `amount` would raise right now.
+    pub fn schedule_recurring(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+        interval_secs: u64,
+        occurrences: u32,
+    ) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if interval_secs == 0 || occurrences == 0 || occurrences > MAX_RECURRING_OCCURRENCES {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+
+        let (config, state) = load_project_pair(&env, project_id);
+        if let Err(err) = Self::check_deposit(&env, &config, &state, &donator, &token, amount) {
+            panic_with_error!(&env, err);
+        }
+
+        let mut schedules = storage::get_recurring(&env, project_id);
+        if schedules.len() >= MAX_RECURRING_SCHEDULES {
+            panic_with_error!(&env, Error::InvalidSchedule);
+        }
+
+        let total = amount
+            .checked_mul(occurrences as i128)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        let token_client = token::Client::new(&env, &token);
+        let contract = env.current_contract_address();
+        let allowance = token_client
+            .allowance(&donator, &contract)
+            .checked_add(total)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        // The token contract keeps an allowance alive one ledger past its
+        // expiration, so this is the furthest expiration it will accept.
+        let expiration = env.ledger().max_live_until_ledger() - 1;
+        token_client.approve(&donator, &contract, &allowance, &expiration);
+
+        schedules.push_back(RecurringSchedule {
+            donator: donator.clone(),
+            token: token.clone(),
+            amount,
+            interval_secs,
+            next_run: env.ledger().timestamp(),
+            remaining: occurrences,
+        });
+        storage::set_recurring(&env, project_id, &schedules);
+
+        events::emit_recurring_scheduled(
+            &env,
+            project_id,
+            donator,
+            token,
+            amount,
+            interval_secs,
+            occurrences,
+        );
+    }
+
+    /// Run every recurring deposit of a project that is due.
+    ///
+    /// Callable by anyone, typically a keeper. Each due schedule makes one
+    /// deposit and moves its next run forward by its interval. A run whose
+    /// deposit cannot go through (the token is paused, the donator lacks
+    /// funds or allowance, the hard cap is reached) is skipped but still
+    /// counts as one of the schedule's runs. Schedules are removed once
+    /// they have no runs left, or when the project stops taking deposits
+    /// for good. Returns the number of deposits made.
+    pub fn execute_due_recurring(env: Env, project_id: u64) -> u32 {
+        Self::require_not_paused(&env);
+
+        let schedules = storage::get_recurring(&env, project_id);
+        if schedules.is_empty() {
+            return 0;
+        }
+        Self::enter_guard(&env);
+
+        let now = env.ledger().timestamp();
+        let contract = env.current_contract_address();
+        let mut kept = Vec::new(&env);
+        let mut executed: u32 = 0;
+
+        for mut schedule in schedules.iter() {
+            if schedule.next_run > now {
+                kept.push_back(schedule);
+                continue;
+            }
+
+            // Reload on every run: earlier deposits change the state.
+            let (config, state) = load_project_pair(&env, project_id);
+            let closed = !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+                || Self::is_past_grace(&env, &config);
+            if closed {
+                continue;
+            }
+            let token_client = token::Client::new(&env, &schedule.token);
+            let funded = token_client.balance(&schedule.donator) >= schedule.amount
+                && token_client.allowance(&schedule.donator, &contract) >= schedule.amount;
+            let deposit_ok = funded
+                && Self::check_deposit(
+                    &env,
+                    &config,
+                    &state,
+                    &schedule.donator,
+                    &schedule.token,
+                    schedule.amount,
+                )
+                .is_ok();
+            if deposit_ok {
+                Self::record_deposit(
+                    &env,
+                    &config,
+                    state,
+                    &schedule.donator,
+                    &schedule.donator,
+                    &schedule.token,
+                    schedule.amount,
+                    true,
+                );
+                executed += 1;
+            }
+
+            schedule.remaining -= 1;
+            if schedule.remaining > 0 {
+                schedule.next_run = now.saturating_add(schedule.interval_secs);
+                kept.push_back(schedule);
+            }
+        }
+
+        storage::set_recurring(&env, project_id, &kept);
+        Self::exit_guard(&env);
+        executed
+    }
+
+    /// Quadratic-funding match for the donations a project holds in `token`.
+    ///
+    /// Computed with [`qf::compute_qf_match`] over each donator's current
+    /// refundable balance, so withdrawals and refunds lower the match.
+    pub fn get_qf_match(env: Env, project_id: u64, token: Address) -> i128 {
+        let state = storage::load_project_state(&env, project_id);
+
+        let mut contributions = Vec::new(&env);
+        for index in 0..state.donation_count {
+            let Some((donator, entry_token)) = storage::get_donation_entry(&env, project_id, index)
+            else {
+                continue;
+            };
+            if entry_token != token {
+                continue;
+            }
+            contributions.push_back(storage::get_donator_balance(
+                &env, project_id, &token, &donator,
+            ));
+        }
+        qf::compute_qf_match(contributions)
+    }
+
+    /// The last 32 deposits, releases, expiries and refunds across all
+    /// projects, oldest first.
+    ///
+    /// Meant for light clients without an event indexer; older actions are
+    /// dropped as new ones arrive.
+    pub fn get_recent_actions(env: Env) -> Vec<RecentAction> {
+        storage::get_recent_actions(&env)
+    }
+
+    /// Every status a project has entered, with the ledger timestamp of
+    /// each transition, oldest first.
+    pub fn get_status_history(env: Env, project_id: u64) -> Vec<(ProjectStatus, u64)> {
+        storage::get_status_history(&env, project_id)
+    }
+
+    /// Recurring deposit schedules still pending for a project.
+    pub fn get_recurring(env: Env, project_id: u64) -> Vec<RecurringSchedule> {
+        storage::get_recurring(&env, project_id)
+    }
+
+    /// Withdraw part of a donation while the project is still `Funding`.
+    ///
+    /// Once the goal is reached the project is `Active` and its funds are
+    /// committed; they can then only leave through verification or, after
+    /// expiry, a refund.
+    ///
+    /// # Errors
+    /// - `Error::ProtocolPaused` if the protocol is paused.
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    /// - `Error::ProjectExpired` if the deadline plus grace period has passed.
+    /// - `Error::ProjectNotActive` if the project is not in `Funding`, or has
+    ///   already released a milestone.
+    /// - `Error::InsufficientDonorBalance` if `amount` exceeds what the
+    ///   donator deposited in `token`.
+    pub fn withdraw(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        Self::enter_guard(&env);
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        // Same cut-off as `deposit`: open through the effective deadline.
+        if Self::is_past_grace(&env, &config) {
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+        match state.status {
+            ProjectStatus::Funding => {}
+            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+            _ => panic_with_error!(&env, Error::ProjectNotActive),
+        }
+        // A partially released project no longer holds every donation.
+        if storage::get_released_milestones(&env, project_id) > 0 {
+            panic_with_error!(&env, Error::ProjectNotActive);
+        }
+
+        Self::require_token_not_paused(&env, &token);
+        let balance = storage::get_donator_balance(&env, project_id, &token, &donator);
+        if amount > balance {
+            panic_with_error!(&env, Error::InsufficientDonorBalance);
+        }
+
+        // Update accounting before the external transfer.
+        storage::set_donator_balance(&env, project_id, &token, &donator, balance - amount);
+        storage::add_to_token_balance(&env, project_id, &token, -amount);
+        if !Self::holds_any_balance(&env, &config, &donator) {
+            state.donor_count = state.donor_count.saturating_sub(1);
+            save_project_state(&env, project_id, &state);
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &donator, &amount);
+
+        events::emit_withdrawn(&env, project_id, donator, token, amount);
+        Self::exit_guard(&env);
+    }
+
+    /// Refund a donator from an expired or cancelled project that was not verified.
+    ///
+    /// After a milestone payout the donator receives their pro-rata share of
+    /// what is left; see [`PifpProtocol::refund_deposit`].
+    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
+        donator.require_auth();
+        Self::require_refundable(&env, project_id);
+
+        let refund_amount = storage::get_donator_balance(&env, project_id, &token, &donator);
+        if refund_amount <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        Self::pay_refund(&env, project_id, &token, &donator, &donator, refund_amount);
+    }
+
+    /// Return a donator's full recorded balance of `token` from an expired
+    /// or cancelled project.
+    ///
+    /// - The project must be `Expired` or `Cancelled`; a project whose
+    ///   deadline has passed is transitioned to `Expired` first.
+    /// - The stored balance is zeroed before the transfer, so a second call
+    ///   panics with `Error::NothingToRefund` instead of paying out again.
+    /// - Once a milestone has been paid out the project no longer holds
+    ///   every donation. Each donator then receives the share of the
+    ///   remaining `token` balance that their recorded balance is of all
+    ///   unrefunded donations in `token`, less transfers still owed by
+    ///   `retry_release`.
+    ///
+    /// - `refund_to` sends the refund to another address, e.g. a treasury
+    ///   the donator deposited for; it defaults to `donator`, whose
+    ///   authorization covers the choice either way.
+    ///
+    /// # Errors
+    /// - `Error::ProjectNotExpired` if the project is neither expired nor cancelled.
+    /// - `Error::NothingToRefund` if the donator has no balance for `token`.
+    pub fn refund_deposit(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        refund_to: Option<Address>,
+    ) {
+        donator.require_auth();
+        Self::enter_guard(&env);
+        Self::require_refundable(&env, project_id);
+
+        let refund_amount = storage::get_donator_balance(&env, project_id, &token, &donator);
+        if refund_amount <= 0 {
+            panic_with_error!(&env, Error::NothingToRefund);
+        }
+
+        let to = refund_to.unwrap_or_else(|| donator.clone());
+        Self::pay_refund(&env, project_id, &token, &donator, &to, refund_amount);
+        Self::exit_guard(&env);
+    }
+
+    /// Refund every donator of an expired or cancelled project, in batches.
+    ///
+    /// Walks the project's donation index from where the previous call
+    /// stopped, paying out each non-zero donator balance, pro-rata once a
+    /// milestone has been paid. Balances already refunded individually are
+    /// skipped, so the sweep is idempotent.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `max_entries` caps the donation entries visited in this call,
+    ///   paid or skipped (default `DEFAULT_REFUND_BATCH`), to stay within
+    ///   instruction limits.
+    ///
+    /// Returns the number of entries visited; `0` means the sweep is finished.
+    pub fn refund_all(env: Env, caller: Address, project_id: u64, max_entries: Option<u32>) -> u32 {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        let (_, state) = Self::require_refundable(&env, project_id);
+
+        let limit = max_entries.unwrap_or(DEFAULT_REFUND_BATCH);
+        let mut cursor = storage::get_refund_cursor(&env, project_id);
+        let mut processed: u32 = 0;
+
+        // Skipped entries still cost reads, so they count against the limit.
+        while cursor < state.donation_count && processed < limit {
+            if let Some((donator, token)) = storage::get_donation_entry(&env, project_id, cursor) {
+                let amount = storage::get_donator_balance(&env, project_id, &token, &donator);
+                if amount > 0 {
+                    Self::pay_refund(&env, project_id, &token, &donator, &donator, amount);
+                }
+            }
+            processed += 1;
+            cursor += 1;
+        }
+
+        storage::set_refund_cursor(&env, project_id, cursor);
+        processed
+    }
+
+    /// Send the balances donors never reclaimed from an expired or
+    /// cancelled project to the treasury.
+    ///
+    /// This is a last resort against funds sitting in storage forever, not
+    /// a routine payout: donors are meant to reclaim their balances via
+    /// `refund`, `refund_deposit` or `refund_all`, which also share out what
+    /// a milestone project has left. The sweep only runs once the refund
+    /// window has elapsed since the project closed, and afterwards every
+    /// refund of the project panics with `Error::NothingToRefund`.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Emits one `swept` event per token with a balance.
+    ///
+    /// # Errors
+    /// - `Error::ProjectNotExpired` if the project is neither expired nor cancelled.
+    /// - `Error::NothingToRefund` if the project was already swept.
+    /// - `Error::WindowNotElapsed` if sweeping is disabled or the window
+    ///   has not yet elapsed.
+    /// - `Error::NotInitialized` if no treasury is configured.
+    pub fn sweep_unrefunded(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        Self::enter_guard(&env);
+        let (config, _) = Self::require_refundable(&env, project_id);
+
+        let window = storage::get_refund_window(&env);
+        let closed_at = Self::closed_at(&env, &config);
+        if window == 0 || env.ledger().timestamp() < closed_at.saturating_add(window) {
+            panic_with_error!(&env, Error::WindowNotElapsed);
+        }
+        let treasury = storage::get_treasury(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+
+        storage::set_swept(&env, project_id);
+        let contract_address = env.current_contract_address();
+        for token in config.accepted_tokens.iter() {
+            let amount = drain_token_balance(&env, project_id, &token);
+            if amount > 0 {
+                token::Client::new(&env, &token).transfer(&contract_address, &treasury, &amount);
+                events::emit_swept(&env, project_id, token, treasury.clone(), amount);
+            }
+        }
+        Self::exit_guard(&env);
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Matching pools
+    // ─────────────────────────────────────────────────────────
+
+    /// Add `amount` of `token` to the pool matching donations to `project_id`.
+    ///
+    /// At release the project receives, on top of its donations, a match of
+    /// `raised * get_match_ratio_bps() / 10_000` capped at the pool; the rest
+    /// of the pool goes back to the sponsor. The pool is held apart from the
+    /// project's balance, so refunds never draw on it, and the sponsor takes
+    /// it back with `reclaim_match_pool` if the project expires or is
+    /// cancelled.
+    ///
+    /// - `sponsor` must authorize; each token's pool has a single sponsor,
+    ///   who may top it up.
+    /// - Emits a `match_add` event.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    /// - `Error::DeadlinePassed` if the project's effective deadline has passed.
+    /// - `Error::ProjectExpired` / `Error::ProjectNotActive` unless the
+    ///   project is `Funding` or `Active`.
+    /// - `Error::NotAuthorized` if the project does not accept `token`, or
+    ///   another sponsor already funds its pool.
+    pub fn fund_match_pool(
+        env: Env,
+        sponsor: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+    ) {
+        Self::require_not_paused(&env);
+        sponsor.require_auth();
+        Self::require_token_not_paused(&env, &token);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, state) = load_project_pair(&env, project_id);
+        if Self::is_past_grace(&env, &config) {
+            panic_with_error!(&env, Error::DeadlinePassed);
+        }
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+            _ => panic_with_error!(&env, Error::ProjectNotActive),
+        }
+        if !Self::is_token_accepted(&config.accepted_tokens, &token) {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        let mut pool = storage::get_match_pool(&env, project_id, &token).unwrap_or(MatchPool {
+            sponsor: sponsor.clone(),
+            balance: 0,
+        });
+        if pool.sponsor != sponsor {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        pool.balance = pool
+            .balance
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+
+        token::Client::new(&env, &token).transfer(
+            &sponsor,
+            &env.current_contract_address(),
+            &amount,
+        );
+        storage::set_match_pool(&env, project_id, &token, &pool);
+        events::emit_match_funded(&env, project_id, sponsor, token, amount);
+    }
+
+    /// Return the whole matching pool of `token` to its sponsor once
+    /// `project_id` has expired or been cancelled.
+    ///
+    /// A project whose deadline has passed is transitioned to `Expired`
+    /// first. Emits a `matched` event with nothing matched.
+    ///
+    /// # Errors
+    /// - `Error::ProjectNotExpired` if the project is neither expired nor cancelled.
+    /// - `Error::NothingToRefund` if no pool is held for `token`.
+    /// - `Error::NotAuthorized` if `sponsor` did not fund the pool.
+    pub fn reclaim_match_pool(env: Env, sponsor: Address, project_id: u64, token: Address) {
+        sponsor.require_auth();
+        Self::enter_guard(&env);
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if Self::is_past_grace(&env, &config)
+            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+        {
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Expired);
+            save_project_state(&env, project_id, &state);
+        }
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+
+        let pool = storage::get_match_pool(&env, project_id, &token)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NothingToRefund));
+        if pool.sponsor != sponsor {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        Self::require_token_not_paused(&env, &token);
+
+        storage::remove_match_pool(&env, project_id, &token);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &sponsor,
+            &pool.balance,
+        );
+        events::emit_match_settled(&env, project_id, token, 0, pool.balance);
+        Self::exit_guard(&env);
+    }
+
+    /// Return the matching pool held for `token` in `project_id`, if any.
+    pub fn get_match_pool(env: Env, project_id: u64, token: Address) -> Option<MatchPool> {
+        storage::get_match_pool(&env, project_id, &token)
+    }
+
+    /// Set the match paid per unit raised, in basis points.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Applies to every pool settled afterwards. Defaults to `10_000`
+    ///   (1:1); `0` disables matching and returns every pool in full.
+    /// - Panics with `Error::InvalidAmount` if `bps` exceeds
+    ///   `MAX_MATCH_RATIO_BPS` (10:1).
+    pub fn set_match_ratio_bps(env: Env, caller: Address, bps: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if bps > MAX_MATCH_RATIO_BPS {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_match_ratio_bps(&env, bps);
+    }
+
+    /// Return the match paid per unit raised, in basis points.
+    pub fn get_match_ratio_bps(env: Env) -> u32 {
+        storage::get_match_ratio_bps(&env)
+    }
+
+    /// Grant the Oracle role to `oracle`.
+    ///
+    /// Replaces the original `set_oracle(admin, oracle)`.
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn set_oracle(env: Env, caller: Address, oracle: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        rbac::grant_role(&env, &caller, &oracle, Role::Oracle);
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Oracle bonds
+    // ─────────────────────────────────────────────────────────
+
+    /// Require oracles to hold at least `min_stake` of `token` staked with
+    /// `stake_oracle` before they may verify or release milestones.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `0` leaves the bond token in place but lifts the requirement.
+    /// - Panics with `Error::InvalidAmount` if `min_stake` is negative.
+    pub fn set_oracle_bond(env: Env, caller: Address, token: Address, min_stake: i128) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if min_stake < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_oracle_bond(&env, &token, min_stake);
+    }
+
+    /// Return the bond token and minimum stake, if a bond was configured.
+    pub fn get_oracle_bond(env: Env) -> Option<(Address, i128)> {
+        storage::get_oracle_bond(&env)
+    }
+
+    /// Stake `amount` of `token` as `oracle`'s bond.
+    ///
+    /// Bonds in the token set with `set_oracle_bond` count towards the
+    /// minimum stake and are what `slash_oracle` draws on. Emits a `staked`
+    /// event.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    pub fn stake_oracle(env: Env, oracle: Address, token: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+        Self::require_token_not_paused(&env, &token);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let stake = storage::get_oracle_stake(&env, &oracle, &token)
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        token::Client::new(&env, &token).transfer(
+            &oracle,
+            &env.current_contract_address(),
+            &amount,
+        );
+        storage::set_oracle_stake(&env, &oracle, &token, stake);
+        events::emit_oracle_bond_changed(
+            &env,
+            symbol_short!("staked"),
+            oracle,
+            token,
+            amount,
+            stake,
+        );
+    }
+
+    /// Withdraw `amount` of `oracle`'s bond in `token`.
+    ///
+    /// Only possible once `oracle` no longer holds `Role::Oracle` and its
+    /// last approval is at least `ORACLE_UNBONDING_SECS`, or the challenge
+    /// period if longer, in the past, so a bond cannot be pulled out from
+    /// under a pending slash. Emits an `unstaked` event.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` while `oracle` holds `Role::Oracle`.
+    /// - `Error::WindowNotElapsed` while the bond is still unbonding.
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    /// - `Error::InsufficientBalance` if `amount` exceeds the bond.
+    pub fn unstake_oracle(env: Env, oracle: Address, token: Address, amount: i128) {
+        oracle.require_auth();
+        Self::enter_guard(&env);
+        Self::require_token_not_paused(&env, &token);
+        if rbac::has_role(&env, oracle.clone(), Role::Oracle) {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if let Some(approved_at) = storage::get_oracle_approved_at(&env, &oracle) {
+            let delay = storage::get_challenge_period(&env).max(ORACLE_UNBONDING_SECS);
+            if env.ledger().timestamp() < approved_at.saturating_add(delay) {
+                panic_with_error!(&env, Error::WindowNotElapsed);
+            }
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let stake = storage::get_oracle_stake(&env, &oracle, &token);
+        if amount > stake {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        storage::set_oracle_stake(&env, &oracle, &token, stake - amount);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &oracle,
+            &amount,
+        );
+        events::emit_oracle_bond_changed(
+            &env,
+            symbol_short!("unstaked"),
+            oracle,
+            token,
+            amount,
+            stake - amount,
+        );
+        Self::exit_guard(&env);
+    }
+
+    /// Slash `amount` of `oracle`'s bond for provable misbehavior, sending it
+    /// to the treasury.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Draws on the bond held in the token set with `set_oracle_bond`.
+    /// - Emits a `slashed` event.
+    ///
+    /// # Errors
+    /// - `Error::InvalidAmount` if `amount` is not positive.
+    /// - `Error::InsufficientBalance` if no bond token is configured or
+    ///   `amount` exceeds the oracle's bond.
+    /// - `Error::NotInitialized` if no treasury is configured.
+    pub fn slash_oracle(env: Env, caller: Address, oracle: Address, amount: i128) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        Self::enter_guard(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let Some((token, _)) = storage::get_oracle_bond(&env) else {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        };
+        let stake = storage::get_oracle_stake(&env, &oracle, &token);
+        if amount > stake {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        let treasury = storage::get_treasury(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotInitialized));
+        Self::require_token_not_paused(&env, &token);
+
+        storage::set_oracle_stake(&env, &oracle, &token, stake - amount);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &amount,
+        );
+        events::emit_oracle_slashed(&env, oracle, token, treasury, amount, stake - amount);
+        Self::exit_guard(&env);
+    }
+
+    /// Return the bond `oracle` has staked in `token`.
+    pub fn get_oracle_stake(env: Env, oracle: Address, token: Address) -> i128 {
+        storage::get_oracle_stake(&env, &oracle, &token)
+    }
+
+    /// Require `threshold` distinct oracle approvals before a project's
+    /// funds are released. Projects default to a threshold of 1.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - The project must still be `Funding` or `Active`.
+    /// - Panics with `Error::InvalidAmount` if `threshold` is zero.
+    pub fn set_verification_threshold(env: Env, caller: Address, project_id: u64, threshold: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        if threshold == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (_, state) = load_project_pair(&env, project_id);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        storage::set_verification_threshold(&env, project_id, threshold);
+    }
+
+    /// Return the number of distinct oracle approvals required for release.
+    pub fn get_verification_threshold(env: Env, project_id: u64) -> u32 {
+        storage::get_verification_threshold(&env, project_id)
+    }
+
+    /// Expire oracle approvals of `project_id` after `secs` seconds.
+    ///
+    /// An approval older than the window no longer counts towards the
+    /// verification threshold, and its oracle must approve again. `0`, the
+    /// default, keeps approvals valid indefinitely.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - The project must still be `Funding` or `Active`.
+    pub fn set_verification_validity(env: Env, caller: Address, project_id: u64, secs: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let (_, state) = load_project_pair(&env, project_id);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        storage::set_verification_validity(&env, project_id, secs);
+    }
+
+    /// Return how many seconds an approval stays valid; `0` means no expiry.
+    pub fn get_verification_validity(env: Env, project_id: u64) -> u64 {
+        storage::get_verification_validity(&env, project_id)
+    }
+
+    /// Restrict verification of `project_id` to assigned oracles.
+    ///
+    /// Once a project has at least one assigned oracle, only those addresses
+    /// may call `verify_and_release`, `approve_release` or
+    /// `release_milestone` for it, and only while they still hold the
+    /// global Oracle role. Projects without assignments keep accepting any
+    /// `Role::Oracle` holder.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is neither the project creator
+    ///   nor an admin, if `oracle` does not hold `Role::Oracle`, or if
+    ///   `oracle` is the project creator.
+    /// - `Error::TooManyOracles` if the project already has
+    ///   `MAX_PROJECT_ORACLES` oracles.
+    pub fn assign_project_oracle(env: Env, caller: Address, project_id: u64, oracle: Address) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        rbac::require_oracle(&env, &oracle);
+        if oracle == config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        let assigned = storage::get_project_oracles(&env, project_id);
+        if assigned.contains(&oracle) {
+            return;
+        }
+        if assigned.len() >= MAX_PROJECT_ORACLES {
+            panic_with_error!(&env, Error::TooManyOracles);
+        }
+        storage::add_project_oracle(&env, project_id, &oracle);
+    }
+
+    /// Remove `oracle` from the oracles assigned to `project_id`.
+    ///
+    /// Removing the last assigned oracle returns the project to accepting
+    /// any `Role::Oracle` holder. Approvals the oracle already recorded stop
+    /// counting towards the threshold.
+    ///
+    /// # Errors
+    /// - `Error::NotAuthorized` if `caller` is neither the project creator
+    ///   nor an admin.
+    /// - `Error::NotProjectOracle` if `oracle` is not assigned to the project.
+    pub fn remove_project_oracle(env: Env, caller: Address, project_id: u64, oracle: Address) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if !storage::remove_project_oracle(&env, project_id, &oracle) {
+            panic_with_error!(&env, Error::NotProjectOracle);
+        }
+    }
+
+    /// Return the addresses that may currently verify `project_id`.
+    ///
+    /// These are the project's assigned oracles if it has any, otherwise
+    /// every holder of `Role::Oracle`; the list is empty when neither is
+    /// configured. Panics with `Error::ProjectNotFound` if the project does
+    /// not exist.
+    pub fn get_authorized_oracles(env: Env, project_id: u64) -> Vec<Address> {
+        storage::load_project_config(&env, project_id);
+        let assigned = storage::get_project_oracles(&env, project_id);
+        if assigned.is_empty() {
+            rbac::role_members(&env, Role::Oracle)
+        } else {
+            assigned
+        }
+    }
+
+    /// Assert that `oracle` may verify `project_id`: a holder of
+    /// `Role::Oracle` that is one of the project's assigned oracles if it
+    /// has any, and bonded with at least the configured minimum stake.
+    fn require_project_oracle(env: &Env, project_id: u64, oracle: &Address) {
+        let assigned = storage::get_project_oracles(env, project_id);
+        if !assigned.is_empty() && !assigned.contains(oracle) {
+            panic_with_error!(env, Error::NotProjectOracle);
+        }
+        rbac::require_oracle(env, oracle);
+
+        if !Self::meets_oracle_bond(env, oracle) {
+            panic_with_error!(env, Error::NotAuthorized);
+        }
+    }
+
+    /// Non-panicking form of `require_project_oracle`, used to drop
+    /// approvals from oracles that have since lost their authorization.
+    fn is_project_oracle(env: &Env, project_id: u64, oracle: &Address) -> bool {
+        let assigned = storage::get_project_oracles(env, project_id);
+        let listed = assigned.is_empty() || assigned.contains(oracle);
+        listed
+            && rbac::has_role(env, oracle.clone(), Role::Oracle)
+            && Self::meets_oracle_bond(env, oracle)
+    }
+
+    /// True unless an oracle bond is configured and `oracle` stakes less.
+    fn meets_oracle_bond(env: &Env, oracle: &Address) -> bool {
+        match storage::get_oracle_bond(env) {
+            Some((token, min_stake)) => storage::get_oracle_stake(env, oracle, &token) >= min_stake,
+            None => true,
+        }
+    }
+
+    /// Return the oracles that have approved a pending release.
+    ///
+    /// Approvals older than the project's verification validity, or from
+    /// oracles no longer authorized for the project, are omitted.
+    pub fn get_approvals(env: Env, project_id: u64) -> Vec<Address> {
+        Self::fresh_approvals(&env, project_id)
+    }
+
+    /// Approvals of `project_id` still inside its verification validity
+    /// whose oracles may still verify it.
+    fn fresh_approvals(env: &Env, project_id: u64) -> Vec<Address> {
+        let validity = storage::get_verification_validity(env, project_id);
+        let now = env.ledger().timestamp();
+        let mut fresh = Vec::new(env);
+        for oracle in storage::get_approvals(env, project_id).iter() {
+            if !Self::is_project_oracle(env, project_id, &oracle) {
+                continue;
+            }
+            // Approvals recorded before timestamps existed count as fresh.
+            let approved_at = storage::get_approved_at(env, project_id, &oracle).unwrap_or(now);
+            if validity == 0 || now.saturating_sub(approved_at) <= validity {
+                fresh.push_back(oracle);
+            }
+        }
+        fresh
+    }
+
+    /// Verify proof of impact and release funds to the creator.
+    ///
+    /// The registered oracle submits the proof preimage, which is hashed
+    /// on-chain with SHA-256. If the digest matches the project's stored
+    /// `proof_hash`, the project status transitions to `Completed`.
+    ///
+    /// With a verification threshold above 1 this records a single approval;
+    /// see [`PifpProtocol::approve_release`].
+    ///
+    /// # Errors
+    /// - `Error::ProofMismatch` if `sha256(preimage)` differs from `proof_hash`.
+    pub fn verify_and_release(env: Env, oracle: Address, project_id: u64, preimage: Bytes) {
+        let proof_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+        Self::record_approval(env, oracle, project_id, proof_hash, None);
+    }
+
+    /// Approve a project's proof of impact as one of its oracles.
+    ///
+    /// Like `verify_and_release`, the oracle submits the proof preimage and
+    /// it is hashed on-chain; the public `proof_hash` alone is not enough.
+    /// Each distinct oracle may approve once (`Error::AlreadyApproved`).
+    /// If the project has a verification validity, approvals older than it
+    /// are dropped and their oracles may approve again. When the number of
+    /// approvals reaches the project's verification threshold, the project
+    /// transitions to `Completed` and funds are released to the creator.
+    ///
+    /// NOTE: This is a mocked verification (hash equality).
+    /// The structure is prepared for future ZK-STARK verification.
+    ///
+    /// # Errors
+    /// - `Error::ProofMismatch` if `sha256(preimage)` differs from `proof_hash`.
+    pub fn approve_release(env: Env, oracle: Address, project_id: u64, preimage: Bytes) {
+        let proof_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+        Self::record_approval(env, oracle, project_id, proof_hash, None);
+    }
+
+    /// Shared body of `verify_and_release`, `approve_release` and
+    /// `release_milestone`; a preimage digest that differs from the expected
+    /// proof hash panics with `Error::ProofMismatch`. With `milestone` set, the approval
+    /// is for that milestone and reaching the threshold releases only it.
+    fn record_approval(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        submitted_proof_hash: BytesN<32>,
+        milestone: Option<u32>,
+    ) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+        // RBAC gate: caller must be a project or global oracle.
+        Self::require_project_oracle(&env, project_id, &oracle);
+        Self::enter_guard(&env);
+
+        // Optimised dual-read helper
+        let (config, mut state) = load_project_pair(&env, project_id);
+
+        if Self::is_past_grace(&env, &config)
             && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
         {
             state.status = ProjectStatus::Expired;
             save_project_state(&env, project_id, &state);
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+
+        // Ensure the project is in a verifiable state.
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Completed | ProjectStatus::PartiallyReleased => {
+                panic_with_error!(&env, Error::MilestoneAlreadyReleased)
+            }
+            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+            ProjectStatus::Cancelled | ProjectStatus::PendingRelease => {
+                panic_with_error!(&env, Error::ProjectNotActive)
+            }
+        }
+
+        // Mocked ZK verification: compare submitted hash to stored hash.
+        let expected = match milestone {
+            Some(index) => Self::milestone_proof(&env, &config, index),
+            None => config.proof_hash.clone(),
+        };
+        if submitted_proof_hash != expected {
+            panic_with_error!(&env, Error::ProofMismatch);
+        }
+
+        // An underfunded project releases only if its creator opted in.
+        if state.status == ProjectStatus::Funding
+            && !storage::is_partial_release_allowed(&env, project_id)
+        {
+            panic_with_error!(&env, Error::GoalNotMet);
+        }
+
+        // Count this oracle's approval towards the release threshold,
+        // discarding any that have gone stale.
+        let mut approvals = Self::fresh_approvals(&env, project_id);
+        if approvals.contains(&oracle) {
+            panic_with_error!(&env, Error::AlreadyApproved);
+        }
+        approvals.push_back(oracle.clone());
+        storage::set_approved_at(&env, project_id, &oracle, env.ledger().timestamp());
+        storage::set_oracle_approved_at(&env, &oracle, env.ledger().timestamp());
+        let threshold = storage::get_verification_threshold(&env, project_id);
+        events::emit_release_approved(&env, project_id, oracle.clone(), approvals.len(), threshold);
+        if approvals.len() < threshold {
+            storage::set_approvals(&env, project_id, &approvals);
+            Self::exit_guard(&env);
+            return;
+        }
+
+        // With a challenge period the funds wait for `finalize_release`.
+        let challenge_period = storage::get_challenge_period(&env);
+        if challenge_period > 0 {
+            let unlock_at = env.ledger().timestamp().saturating_add(challenge_period);
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::PendingRelease);
+            save_project_state(&env, project_id, &state);
+            storage::set_release_unlock_at(&env, project_id, Some(unlock_at));
+            // Kept so `finalize_release` can credit the deciding oracle.
+            storage::set_approvals(&env, project_id, &approvals);
+            events::emit_project_verified(&env, project_id, oracle, submitted_proof_hash);
+            events::emit_release_pending(&env, project_id, unlock_at);
+            Self::exit_guard(&env);
+            return;
+        }
+
+        if milestone.is_some() {
+            Self::release_next_milestone(&env, &config, &mut state, oracle);
+            Self::exit_guard(&env);
+            return;
+        }
+
+        let paid = Self::complete_release(&env, &config, &mut state);
+
+        // Standardized event emission
+        events::emit_project_verified(&env, project_id, oracle, submitted_proof_hash);
+        if paid {
+            events::emit_project_completed(&env, project_id);
+        }
+        Self::exit_guard(&env);
+    }
+
+    /// Pay out a release whose challenge period has ended.
+    ///
+    /// Permissionless: anyone may finalize once `get_release_unlock_at` has
+    /// passed, so a creator never depends on the oracle to collect.
+    ///
+    /// # Errors
+    /// - `Error::InvalidTransition` if the project has no pending release.
+    /// - `Error::WindowNotElapsed` if the challenge period is still running.
+    pub fn finalize_release(env: Env, project_id: u64) {
+        Self::require_not_paused(&env);
+        Self::enter_guard(&env);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if state.status != ProjectStatus::PendingRelease {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+        let unlock_at = storage::get_release_unlock_at(&env, project_id).unwrap_or(0);
+        if env.ledger().timestamp() < unlock_at {
+            panic_with_error!(&env, Error::WindowNotElapsed);
+        }
+
+        storage::set_release_unlock_at(&env, project_id, None);
+        if storage::get_milestones(&env, project_id).is_some() {
+            let oracle = storage::get_approvals(&env, project_id)
+                .last()
+                .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidTransition));
+            Self::release_next_milestone(&env, &config, &mut state, oracle);
+        } else if Self::complete_release(&env, &config, &mut state) {
+            events::emit_project_completed(&env, project_id);
+        }
+        Self::exit_guard(&env);
+    }
+
+    /// Send the transfers a safe release of `project_id` could not make.
+    ///
+    /// Permissionless: every payout goes to the recipient recorded at
+    /// release. Transfers that fail again are kept for another retry; once
+    /// all have gone through a `PartiallyReleased` project becomes
+    /// `Completed`. A milestone project may also owe transfers from an
+    /// earlier milestone while it is still live.
+    ///
+    /// Returns the number of payouts still unpaid.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidTransition` unless the project is
+    /// `PartiallyReleased` or owes milestone transfers.
+    pub fn retry_release(env: Env, project_id: u64) -> u32 {
+        Self::require_not_paused(&env);
+        Self::enter_guard(&env);
+        let mut state = storage::load_project_state(&env, project_id);
+        let pending = storage::get_unpaid_payouts(&env, project_id);
+        let partial = state.status == ProjectStatus::PartiallyReleased;
+        if !partial && pending.is_empty() {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        let unpaid = Self::attempt_payouts(&env, project_id, pending);
+        storage::set_unpaid_payouts(&env, project_id, &unpaid);
+        if partial && unpaid.is_empty() {
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Completed);
+            save_project_state(&env, project_id, &state);
+            events::emit_project_completed(&env, project_id);
+        }
+        Self::exit_guard(&env);
+        unpaid.len()
+    }
+
+    /// Return the transfers a safe release of `project_id` still owes.
+    pub fn get_unpaid_payouts(env: Env, project_id: u64) -> Vec<PendingPayout> {
+        storage::get_unpaid_payouts(&env, project_id)
+    }
+
+    /// Stop a pending release during its challenge period.
+    ///
+    /// The project returns to `Active`, or to `Funding` if it had not reached
+    /// its activation target, and every oracle approval is discarded; the
+    /// proof must be verified again before funds can move.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    ///
+    /// # Errors
+    /// - `Error::InvalidTransition` if the project has no pending release or
+    ///   its challenge period is over.
+    pub fn cancel_release(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        let unlock_at = storage::get_release_unlock_at(&env, project_id).unwrap_or(0);
+        if state.status != ProjectStatus::PendingRelease || env.ledger().timestamp() >= unlock_at {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        let restored = Self::unverified_status(&env, &config);
+        Self::set_status(&env, project_id, &mut state, restored);
+        save_project_state(&env, project_id, &state);
+        storage::set_release_unlock_at(&env, project_id, None);
+        storage::set_approvals(&env, project_id, &Vec::new(&env));
+        events::emit_release_cancelled(&env, project_id, caller);
+    }
+
+    /// Set how long a verified release waits before `finalize_release` may
+    /// pay it out, giving admins time to `cancel_release`.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Applies to releases verified from now on. `0`, the default, pays
+    ///   out immediately on verification.
+    pub fn set_challenge_period(env: Env, caller: Address, secs: u64) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_challenge_period(&env, secs);
+    }
+
+    /// Return the challenge period in seconds; `0` for instant releases.
+    pub fn get_challenge_period(env: Env) -> u64 {
+        storage::get_challenge_period(&env)
+    }
+
+    /// Return when the pending release of `project_id` may be finalized, or
+    /// `None` if no release is pending.
+    pub fn get_release_unlock_at(env: Env, project_id: u64) -> Option<u64> {
+        storage::get_release_unlock_at(&env, project_id)
+    }
+
+    /// Release one milestone of a milestone-based project to its creator.
+    ///
+    /// Milestones are released in order. Milestone `i` pays out, for every
+    /// accepted token, the fraction `(m[i] - m[i-1]) / (goal - m[i-1])` of the
+    /// balance still held; the final milestone pays out everything left and
+    /// marks the project `Completed`.
+    ///
+    /// Each milestone is verified like a full release: it needs the
+    /// project's verification threshold of distinct oracle approvals, waits
+    /// out any challenge period as `PendingRelease` (see `finalize_release`),
+    /// and honours safe release. Approvals are cleared after each milestone.
+    ///
+    /// # Errors
+    /// - `Error::MilestoneNotFound` if the project has no milestone `milestone_index`.
+    /// - `Error::MilestoneAlreadyReleased` if that milestone was already paid.
+    /// - `Error::InvalidMilestones` if an earlier milestone is still unreleased.
+    /// - `Error::ProofMismatch` if `sha256(preimage)` differs from the proof
+    ///   hash of that milestone, or from the project's `proof_hash` when it
+    ///   was registered without per-milestone proofs.
+    /// - `Error::GoalNotMet` if the project is still `Funding` and has not
+    ///   opted in to partial release.
+    pub fn release_milestone(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        milestone_index: u32,
+        preimage: Bytes,
+    ) {
+        let proof_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+        Self::record_approval(env, oracle, project_id, proof_hash, Some(milestone_index));
+    }
+
+    /// Mark a project as expired once its effective deadline has passed.
+    ///
+    /// Permissionless: anyone can trigger expiration once the deadline plus
+    /// the grace period is behind the ledger.
+    /// - Panics if project is not in Funding status.
+    /// - Panics if the effective deadline has not passed.
+    pub fn expire_project(env: Env, project_id: u64) {
+        let (config, mut state) = load_project_pair(&env, project_id);
+
+        // State transition check: only Funding or Active projects can expire.
+        // Completed projects cannot be expired.
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            _ => panic_with_error!(&env, Error::InvalidTransition),
+        }
+
+        // Deadline check, honoring the grace period.
+        if !Self::is_past_grace(&env, &config) {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+
+        // Update status and save.
+        Self::set_status(&env, project_id, &mut state, ProjectStatus::Expired);
+        save_project_state(&env, project_id, &state);
+
+        // Standardized event emission.
+        events::emit_project_expired(&env, project_id, config.deadline);
+    }
+
+    /// Expire every overdue project with an ID in `start_id..start_id + limit`.
+    ///
+    /// Permissionless, for keepers sweeping the ledger. `Funding` and
+    /// `Active` projects past their effective deadline become `Expired`,
+    /// exactly as with `expire_project`; every other project is skipped.
+    /// `limit` is capped at `MAX_PAGE_SIZE`. Returns how many were expired.
+    pub fn auto_expire(env: Env, start_id: u64, limit: u32) -> u32 {
+        let end = start_id
+            .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+            .min(storage::get_project_count(&env));
+
+        let mut expired = 0;
+        for project_id in start_id..end {
+            let (config, mut state) = load_project_pair(&env, project_id);
+            if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+                || !Self::is_past_grace(&env, &config)
+            {
+                continue;
+            }
+            Self::set_status(&env, project_id, &mut state, ProjectStatus::Expired);
+            save_project_state(&env, project_id, &state);
+            events::emit_project_expired(&env, project_id, config.deadline);
+            expired += 1;
+        }
+        expired
+    }
+
+    /// Extend the TTL of `project_id`'s storage so it outlives long campaigns.
+    ///
+    /// Permissionless, so keepers can keep a project alive. Every existing
+    /// per-project persistent entry (config, state, metadata, per-token
+    /// balances and prices, oracle and milestone data) is extended to live
+    /// at least `ledgers` more ledgers, as is the contract instance. `ledgers`
+    /// is capped at the network's maximum entry TTL. Per-donor entries keep
+    /// their own TTLs and are bumped whenever they are touched.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn bump_project_ttl(env: Env, project_id: u64, ledgers: u32) {
+        let config = storage::load_project_config(&env, project_id);
+        let ledgers = ledgers.min(env.storage().max_ttl());
+
+        storage::extend_project_ttl(&env, &config, ledgers);
+        events::emit_ttl_bumped(&env, project_id, ledgers);
+    }
+
+    //─────────────────────────────────────────────────────────
+    // Internal Helpers
+    //─────────────────────────────────────────────────────────
+
+    /// Validate registration parameters and persist a new `Funding` project.
+    #[allow(clippy::too_many_arguments)]
+    fn create_project(
+        env: &Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata: ProjectMetadata,
+        hard_cap: Option<i128>,
+    ) -> Project {
+        Self::require_not_paused(env);
+        creator.require_auth();
+        // RBAC gate: only authorised roles may create projects.
+        rbac::require_can_register(env, &creator);
+
+        let active = storage::get_active_count(env, &creator);
+        if matches!(storage::get_max_active_per_creator(env), Some(max) if active >= max) {
+            panic_with_error!(env, Error::TooManyActiveProjects);
+        }
+
+        if accepted_tokens.is_empty() {
+            panic_with_error!(env, Error::EmptyAcceptedTokens);
+        }
+        if accepted_tokens.len() > storage::get_max_tokens(env) {
+            panic_with_error!(env, Error::TooManyTokens);
+        }
+
+        // Optimized duplicate token detection using hash-based lookup
+        // This replaces the O(n²) nested loop with O(n) complexity
+        Self::check_duplicate_tokens_optimized(env, &accepted_tokens);
+        for token in accepted_tokens.iter() {
+            Self::require_token_allowed(env, &token);
+        }
+
+        if goal <= 0 || goal > MAX_GOAL {
+            panic_with_error!(env, Error::InvalidGoal);
+        }
+        if goal < storage::get_min_goal(env) {
+            panic_with_error!(env, Error::GoalBelowMinimum);
+        }
+        if matches!(hard_cap, Some(cap) if cap < goal) {
+            panic_with_error!(env, Error::InvalidGoal);
+        }
+
+        let now = env.ledger().timestamp();
+        if deadline <= now {
+            panic_with_error!(env, Error::DeadlineInPast);
+        }
+        // Max 5 years deadline (5 * 365 * 24 * 60 * 60)
+        let max_deadline = now + 157_680_000;
+        let min_deadline = now.saturating_add(storage::get_min_funding_window(env));
+        if deadline < min_deadline || deadline > max_deadline {
+            panic_with_error!(env, Error::InvalidDeadline);
+        }
+
+        // Every hash is indexed so uniqueness can be switched on later.
+        let proof_owner = storage::get_proof_owner(env, &proof_hash);
+        if proof_owner.is_some() && storage::is_unique_proofs(env) {
+            panic_with_error!(env, Error::DuplicateProof);
+        }
+
+        let id = get_and_increment_project_id(env);
+        if proof_owner.is_none() {
+            storage::set_proof_owner(env, &proof_hash, id);
         }
+        let project = Project {
+            id,
+            creator: creator.clone(),
+            accepted_tokens: accepted_tokens.clone(),
+            goal,
+            proof_hash,
+            deadline,
+            hard_cap,
+            status: ProjectStatus::Funding,
+            donation_count: 0,
+            donor_count: 0,
+            min_deposit: 0,
+            reported_impact: 0,
+            name: metadata.name,
+            metadata_uri: metadata.metadata_uri,
+            category: metadata.category,
+        };
+
+        save_project(env, &project);
+        storage::add_to_category_index(env, &project.category, id);
+        storage::add_creator_project(env, &creator, id);
+        storage::set_active_count(env, &creator, active + 1);
+        storage::record_status(env, id, ProjectStatus::Funding);
+        storage::shift_status_count(env, None, ProjectStatus::Funding);
 
-        if state.status != ProjectStatus::Expired {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+        // Standardized event emission
+        if let Some(token) = accepted_tokens.get(0) {
+            events::emit_project_created(env, id, creator, token, goal);
         }
 
-        let refund_amount = storage::get_donator_balance(&env, project_id, &token, &donator);
-        if refund_amount <= 0 {
-            panic_with_error!(&env, Error::InsufficientBalance);
-        }
+        project
+    }
 
-        // Zero-out first to prevent double-refund/reentrancy patterns.
-        storage::set_donator_balance(&env, project_id, &token, &donator, 0);
-        storage::add_to_token_balance(&env, project_id, &token, -refund_amount);
+    /// Move a project to `new_status`, logging it in the status history and
+    /// emitting a `status` event. The caller still saves `state`.
+    fn set_status(env: &Env, project_id: u64, state: &mut ProjectState, new_status: ProjectStatus) {
+        let old_status = state.status;
+        state.status = new_status;
+        storage::record_status(env, project_id, new_status);
+        storage::shift_status_count(env, Some(old_status), new_status);
+        // A partially released project already left the creator's active
+        // count and counted as completed on its way through `Completed`.
+        let terminal = |status| {
+            matches!(
+                status,
+                ProjectStatus::Completed
+                    | ProjectStatus::PartiallyReleased
+                    | ProjectStatus::Expired
+                    | ProjectStatus::Cancelled
+            )
+        };
+        if terminal(new_status) && !terminal(old_status) {
+            let creator = storage::load_project_config(env, project_id).creator;
+            let active = storage::get_active_count(env, &creator);
+            storage::set_active_count(env, &creator, active.saturating_sub(1));
+            if new_status == ProjectStatus::Completed {
+                storage::increment_completed_count(env, &creator);
+            }
+        }
+        if new_status == ProjectStatus::Expired {
+            storage::push_recent_action(env, symbol_short!("expire"), project_id);
+        }
+        events::emit_status_change(env, project_id, old_status, new_status);
+    }
 
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&contract_address, &donator, &refund_amount);
+    /// Take the reentrancy lock around a flow that calls a token contract.
+    ///
+    /// A panic anywhere in the flow rolls the lock back with every other
+    /// write, so only a nested call can observe it held.
+    fn enter_guard(env: &Env) {
+        if storage::is_locked(env) {
+            panic_with_error!(env, Error::Reentrancy);
+        }
+        storage::set_locked(env, true);
+    }
 
-        events::emit_refunded(&env, project_id, donator, refund_amount);
+    /// Release the reentrancy lock taken by `enter_guard`.
+    fn exit_guard(env: &Env) {
+        storage::set_locked(env, false);
     }
 
-    /// Grant the Oracle role to `oracle`.
+    /// Validate a deposit of `amount` of `token` against the project's
+    /// current configuration and state.
     ///
-    /// Replaces the original `set_oracle(admin, oracle)`.
-    /// - `caller` must hold `SuperAdmin` or `Admin`.
-    pub fn set_oracle(env: Env, caller: Address, oracle: Address) {
-        caller.require_auth();
-        rbac::require_admin_or_above(&env, &caller);
-        rbac::grant_role(&env, &caller, &oracle, Role::Oracle);
+    /// Returns the error instead of panicking so that `execute_due_recurring`
+    /// can drop a failing schedule without aborting the whole batch.
+    fn check_deposit(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &ProjectState,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        // The effective deadline is the last second a deposit is accepted.
+        if Self::is_past_grace(env, config) {
+            return Err(Error::DeadlinePassed);
+        }
+
+        if !Self::donor_allowed(env, config.id, donator) {
+            return Err(Error::DonorNotAllowed);
+        }
+        if storage::is_token_paused(env, token) {
+            return Err(Error::TokenPaused);
+        }
+
+        // Basic status check: must be Funding or Active.
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Expired => return Err(Error::ProjectExpired),
+            _ => return Err(Error::ProjectNotActive),
+        }
+
+        if amount < state.min_deposit {
+            return Err(Error::DepositBelowMinimum);
+        }
+
+        // Optimized token verification using early termination
+        // This reduces average case complexity from O(n) to O(1) for first token
+        if !Self::is_token_accepted(&config.accepted_tokens, token) {
+            return Err(Error::NotAuthorized);
+        }
+
+        // A donor's balance in each token may not exceed the project's cap.
+        if let Some(cap) = storage::get_donor_cap(env, config.id) {
+            let held = storage::get_donator_balance(env, config.id, token, donator);
+            if amount > cap - held {
+                return Err(Error::DonorCapExceeded);
+            }
+        }
+
+        // Every token must be priceable so it can count toward the goal.
+        let price = Self::find_token_price(env, config, token).ok_or(Error::PriceNotSet)?;
+
+        // Reject deposits that would carry the raised value past the cap.
+        if let Some(cap) = config.hard_cap {
+            let value = amount.checked_mul(price).ok_or(Error::Overflow)? / PRICE_SCALE;
+            let raised = Self::raised_value(env, config);
+            if raised >= cap || value > cap - raised {
+                return Err(Error::HardCapExceeded);
+            }
+        }
+
+        Ok(())
     }
 
-    /// Verify proof of impact and release funds to the creator.
-    ///
-    /// The registered oracle submits a proof hash. If it matches the project's
-    /// stored `proof_hash`, the project status transitions to `Completed`.
+    /// Move a validated deposit into the contract and credit it to `donator`.
     ///
-    /// NOTE: This is a mocked verification (hash equality).
-    /// The structure is prepared for future ZK-STARK verification.
-    ///
-    /// Reads the immutable config (for proof_hash) and mutable state (for status),
-    /// then writes back only the small state entry.
-    pub fn verify_and_release(
-        env: Env,
-        oracle: Address,
-        project_id: u64,
-        submitted_proof_hash: BytesN<32>,
+    /// The tokens come from `payer`, which is `donator` except for relayed
+    /// deposits. With `from_allowance` set, they are pulled with
+    /// `transfer_from` against an allowance `payer` granted this contract;
+    /// otherwise `payer`'s own authorization covers a direct `transfer`.
+    #[allow(clippy::too_many_arguments)]
+    fn record_deposit(
+        env: &Env,
+        config: &ProjectConfig,
+        mut state: ProjectState,
+        payer: &Address,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+        from_allowance: bool,
     ) {
-        Self::require_not_paused(&env);
-        oracle.require_auth();
-        // RBAC gate: caller must hold the Oracle role.
-        rbac::require_oracle(&env, &oracle);
-
-        // Optimised dual-read helper
-        let (config, mut state) = load_project_pair(&env, project_id);
+        let project_id = config.id;
 
-        if env.ledger().timestamp() >= config.deadline
-            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
-        {
-            state.status = ProjectStatus::Expired;
-            save_project_state(&env, project_id, &state);
-            panic_with_error!(&env, Error::ProjectExpired);
+        // A donator holding nothing in any token becomes a new donor.
+        if !Self::holds_any_balance(env, config, donator) {
+            state.donor_count += 1;
+            save_project_state(env, project_id, &state);
+            storage::add_donor_project(env, donator, project_id);
         }
 
-        // Ensure the project is in a verifiable state.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Completed => panic_with_error!(&env, Error::MilestoneAlreadyReleased),
-            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+        // Check if this is a new unique (donator, token) pair.
+        let is_new_donor = !storage::has_donator_seen(env, project_id, donator, token);
+        if is_new_donor {
+            // Index the new pair so batched refunds can find it, then
+            // increment donation count and mark as seen.
+            storage::set_donation_entry(env, project_id, state.donation_count, donator, token);
+            state.donation_count += 1;
+            storage::mark_donator_seen(env, project_id, donator, token);
+            // Save the updated state.
+            save_project_state(env, project_id, &state);
         }
 
-        // Mocked ZK verification: compare submitted hash to stored hash.
-        if submitted_proof_hash != config.proof_hash {
-            panic_with_error!(&env, Error::VerificationFailed);
+        // Transfer tokens from the payer to contract.
+        let token_client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        if from_allowance {
+            token_client.transfer_from(&contract, payer, &contract, &amount);
+        } else {
+            token_client.transfer(payer, &contract, &amount);
         }
 
-        // Transition to Completed — only write the state entry.
-        state.status = ProjectStatus::Completed;
+        // Update the per-token balance.
+        let new_balance = storage::add_to_token_balance(env, project_id, token, amount);
 
-        // Optimized fund transfer with batch processing
-        // Reduces redundant operations and improves gas efficiency
-        Self::transfer_all_funds_optimized(&env, project_id, &config);
+        // Once the priced value of all balances reaches the activation target,
+        // transition from Funding to Active.
+        if state.status == ProjectStatus::Funding
+            && Self::raised_value(env, config) >= Self::activation_target(env, config)
+        {
+            Self::set_status(env, project_id, &mut state, ProjectStatus::Active);
+            save_project_state(env, project_id, &state);
+            events::emit_project_active(env, project_id);
+        }
 
-        // Save the updated state (now marked as Completed).
-        save_project_state(&env, project_id, &state);
+        // Track per-donator refundable amount for this token.
+        storage::add_to_donator_balance(env, project_id, token, donator, amount);
+        storage::push_recent_action(env, symbol_short!("deposit"), project_id);
 
         // Standardized event emission
-        events::emit_project_verified(&env, project_id, oracle.clone(), submitted_proof_hash);
+        events::emit_project_funded(env, project_id, donator.clone(), amount);
+        events::emit_deposit(
+            env,
+            project_id,
+            donator.clone(),
+            token.clone(),
+            amount,
+            new_balance,
+        );
     }
 
-    /// Mark a project as expired if its deadline has passed.
-    ///
-    /// Permissionless: anyone can trigger expiration once the deadline is met.
-    /// - Panics if project is not in Funding status.
-    /// - Panics if deadline has not passed.
-    pub fn expire_project(env: Env, project_id: u64) {
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Sum of `token` balances tracked across every project, matching pools
+    /// included, plus oracle bonds. Reads running totals, so the cost does
+    /// not grow with the number of projects.
+    fn tracked_balance(env: &Env, token: &Address) -> i128 {
+        storage::get_total_locked(env)
+            .get(token.clone())
+            .unwrap_or(0)
+            .checked_add(storage::get_total_pooled(env, token))
+            .and_then(|t| t.checked_add(storage::get_total_staked(env, token)))
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+    }
 
-        // State transition check: only Funding or Active projects can expire.
-        // Completed projects cannot be expired.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            _ => panic_with_error!(&env, Error::InvalidTransition),
+    /// Price of `token` in goal units per `PRICE_SCALE`, if one is known.
+    ///
+    /// The first accepted token is priced at par unless an oracle says otherwise.
+    fn find_token_price(env: &Env, config: &ProjectConfig, token: &Address) -> Option<i128> {
+        match storage::get_token_price(env, config.id, token) {
+            Some(price) => Some(price),
+            None if config.accepted_tokens.get(0).as_ref() == Some(token) => Some(PRICE_SCALE),
+            None => None,
         }
+    }
 
-        // Deadline check.
-        if env.ledger().timestamp() < config.deadline {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+    /// Price of `token` in goal units per `PRICE_SCALE`, panicking with
+    /// `Error::PriceNotSet` when none is known.
+    fn token_price(env: &Env, config: &ProjectConfig, token: &Address) -> i128 {
+        Self::find_token_price(env, config, token)
+            .unwrap_or_else(|| panic_with_error!(env, Error::PriceNotSet))
+    }
+
+    /// Total value of all stored token balances, in goal units.
+    fn raised_value(env: &Env, config: &ProjectConfig) -> i128 {
+        let mut total: i128 = 0;
+        for token in config.accepted_tokens.iter() {
+            let amount = storage::get_token_balance(env, config.id, &token);
+            if amount == 0 {
+                continue;
+            }
+            let value = amount
+                .checked_mul(Self::token_price(env, config, &token))
+                .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+                / PRICE_SCALE;
+            total = total
+                .checked_add(value)
+                .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
         }
+        total
+    }
 
-        // Update status and save.
-        state.status = ProjectStatus::Expired;
-        save_project_state(&env, project_id, &state);
+    /// Check that `milestones` are strictly increasing positive targets, at
+    /// most `MAX_MILESTONES` of them, and return the last one as the goal.
+    fn validate_milestones(env: &Env, milestones: &Vec<i128>) -> i128 {
+        if milestones.is_empty() || milestones.len() > MAX_MILESTONES {
+            panic_with_error!(env, Error::InvalidMilestones);
+        }
+        let mut previous = 0i128;
+        for target in milestones.iter() {
+            if target <= previous {
+                panic_with_error!(env, Error::InvalidMilestones);
+            }
+            previous = target;
+        }
+        previous
+    }
 
-        // Standardized event emission.
-        events::emit_project_expired(&env, project_id, config.deadline);
+    /// Value a project must raise to turn `Active`: its goal scaled by the
+    /// release threshold, rounded down.
+    fn activation_target(env: &Env, config: &ProjectConfig) -> i128 {
+        let bps = storage::get_release_threshold_bps(env, config.id);
+        if bps as i128 == BPS_DENOMINATOR {
+            return config.goal;
+        }
+        config
+            .goal
+            .checked_mul(bps as i128)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / BPS_DENOMINATOR
     }
 
-    //─────────────────────────────────────────────────────────
-    // Internal Helpers
-    //─────────────────────────────────────────────────────────
+    /// Panic with `Error::TokenNotAllowed` unless `token` passes the
+    /// protocol-wide allowlist.
+    fn require_token_allowed(env: &Env, token: &Address) {
+        if !storage::is_token_allowed(env, token) {
+            panic_with_error!(env, Error::TokenNotAllowed);
+        }
+    }
 
     /// Optimized duplicate token detection using hash-based lookup
     ///
@@ -492,7 +3950,7 @@ impl PifpProtocol {
             let token = tokens.get(i).unwrap();
 
             // Check if we've seen this token before using hash lookup
-            if seen_tokens.contains_key(token) {
+            if seen_tokens.contains_key(token.clone()) {
                 panic_with_error!(env, Error::DuplicateToken);
             }
 
@@ -525,12 +3983,236 @@ impl PifpProtocol {
         false
     }
 
+    /// The proof hash milestone `index` of `config`'s project must match,
+    /// after checking that `index` is the next milestone to release.
+    fn milestone_proof(env: &Env, config: &ProjectConfig, index: u32) -> BytesN<32> {
+        match storage::get_milestones(env, config.id) {
+            Some(m) if index < m.len() => {}
+            _ => panic_with_error!(env, Error::MilestoneNotFound),
+        }
+        let released = storage::get_released_milestones(env, config.id);
+        if index < released {
+            panic_with_error!(env, Error::MilestoneAlreadyReleased);
+        }
+        if index > released {
+            panic_with_error!(env, Error::InvalidMilestones);
+        }
+        match storage::get_milestone_proofs(env, config.id) {
+            Some(proofs) => proofs.get(index).unwrap(),
+            None => config.proof_hash.clone(),
+        }
+    }
+
+    /// Pay out the next milestone of `config`'s project, credited to
+    /// `oracle`, completing the project after the last one.
+    ///
+    /// In safe-release mode failed transfers join the project's unpaid
+    /// payouts and are held back from later milestones; otherwise a failure
+    /// aborts the call.
+    fn release_next_milestone(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        oracle: Address,
+    ) {
+        let project_id = config.id;
+        let milestones = storage::get_milestones(env, project_id)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MilestoneNotFound));
+        let index = storage::get_released_milestones(env, project_id);
+        let is_final = index + 1 == milestones.len();
+        let previous = match index {
+            0 => 0,
+            i => milestones.get(i - 1).unwrap(),
+        };
+        let share = milestones.get(index).unwrap() - previous;
+        let outstanding = config.goal - previous;
+
+        // Each milestone needs its own round of approvals.
+        storage::set_approvals(env, project_id, &Vec::new(env));
+        storage::set_released_milestones(env, project_id, index + 1);
+        if is_final {
+            Self::set_status(env, project_id, state, ProjectStatus::Completed);
+        } else if state.status == ProjectStatus::PendingRelease {
+            let restored = Self::unverified_status(env, config);
+            Self::set_status(env, project_id, state, restored);
+        }
+        save_project_state(env, project_id, state);
+
+        // Until now the balance is exactly the donor balances, which later
+        // refunds share the remainder by. The match then joins the balance
+        // and is paid out milestone by milestone like the donations.
+        if index == 0 {
+            for token in config.accepted_tokens.iter() {
+                let raised = storage::get_token_balance(env, project_id, &token);
+                storage::set_refund_claims(env, project_id, &token, raised);
+            }
+            Self::settle_match_pools(env, config);
+        }
+
+        let safe = storage::is_safe_release(env);
+        let mut unpaid = storage::get_unpaid_payouts(env, project_id);
+        let mut payouts = Vec::new(env);
+        for token in config.accepted_tokens.iter() {
+            let balance = storage::get_token_balance(env, project_id, &token);
+            // Transfers still owed from an earlier milestone are spoken for.
+            let owed: i128 = unpaid
+                .iter()
+                .filter(|payout| payout.token == token)
+                .map(|payout| payout.amount)
+                .sum();
+            let available = balance - owed;
+            let amount = if is_final {
+                available
+            } else {
+                available
+                    .checked_mul(share)
+                    .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+                    / outstanding
+            };
+            if amount <= 0 {
+                continue;
+            }
+            if safe {
+                payouts.append(&Self::plan_payouts(
+                    env,
+                    project_id,
+                    &config.creator,
+                    &token,
+                    amount,
+                ));
+            } else {
+                storage::set_token_balance(env, project_id, &token, balance - amount);
+                Self::pay_creator(env, project_id, &config.creator, &token, amount);
+            }
+        }
+        if safe {
+            unpaid.append(&Self::attempt_payouts(env, project_id, payouts));
+            storage::set_unpaid_payouts(env, project_id, &unpaid);
+        }
+
+        storage::push_recent_action(env, symbol_short!("release"), project_id);
+        events::emit_milestone_released(env, project_id, index, oracle);
+        if !is_final {
+            return;
+        }
+        if unpaid.is_empty() {
+            events::emit_project_completed(env, project_id);
+        } else {
+            Self::set_status(env, project_id, state, ProjectStatus::PartiallyReleased);
+            save_project_state(env, project_id, state);
+        }
+    }
+
+    /// Status a project returns to when a pending release is undone or a
+    /// milestone leaves it live: `Active` once its activation target is
+    /// met, `Funding` before.
+    fn unverified_status(env: &Env, config: &ProjectConfig) -> ProjectStatus {
+        if Self::raised_value(env, config) >= Self::activation_target(env, config) {
+            ProjectStatus::Active
+        } else {
+            ProjectStatus::Funding
+        }
+    }
+
+    /// Mark a verified project `Completed` and pay it out, or start its
+    /// vesting.
+    ///
+    /// The state is saved before any payout so fee lookups see the project
+    /// as completed. If a safe release leaves transfers unpaid, the project
+    /// moves on to `PartiallyReleased` and `false` is returned.
+    fn complete_release(env: &Env, config: &ProjectConfig, state: &mut ProjectState) -> bool {
+        Self::set_status(env, config.id, state, ProjectStatus::Completed);
+        save_project_state(env, config.id, state);
+        Self::settle_match_pools(env, config);
+
+        // Optimized fund transfer with batch processing
+        // Reduces redundant operations and improves gas efficiency
+        let mut unpaid = Vec::new(env);
+        if storage::get_vesting_duration(env, config.id).is_some() {
+            Self::start_vesting(env, config);
+        } else {
+            unpaid = Self::transfer_all_funds_optimized(env, config.id, config);
+        }
+        storage::push_recent_action(env, symbol_short!("release"), config.id);
+
+        if unpaid.is_empty() {
+            return true;
+        }
+        storage::set_unpaid_payouts(env, config.id, &unpaid);
+        Self::set_status(env, config.id, state, ProjectStatus::PartiallyReleased);
+        save_project_state(env, config.id, state);
+        false
+    }
+
+    /// Move each token's match into the project's balance and return the
+    /// rest of its pool to the sponsor.
+    fn settle_match_pools(env: &Env, config: &ProjectConfig) {
+        for token in config.accepted_tokens.iter() {
+            let Some(pool) = storage::get_match_pool(env, config.id, &token) else {
+                continue;
+            };
+            let raised = storage::get_token_balance(env, config.id, &token);
+            let matched = Self::match_for(env, config.id, &token, raised);
+            let returned = pool.balance - matched;
+
+            storage::remove_match_pool(env, config.id, &token);
+            if matched > 0 {
+                storage::add_to_token_balance(env, config.id, &token, matched);
+            }
+            if returned > 0 {
+                Self::require_token_not_paused(env, &token);
+                token::Client::new(env, &token).transfer(
+                    &env.current_contract_address(),
+                    &pool.sponsor,
+                    &returned,
+                );
+            }
+            events::emit_match_settled(env, config.id, token, matched, returned);
+        }
+    }
+
+    /// Match owed on `raised` units of `token` in `project_id`: the raised
+    /// amount scaled by the match ratio, capped at the pool.
+    fn match_for(env: &Env, project_id: u64, token: &Address, raised: i128) -> i128 {
+        let Some(pool) = storage::get_match_pool(env, project_id, token) else {
+            return 0;
+        };
+        let owed = raised
+            .checked_mul(storage::get_match_ratio_bps(env) as i128)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / BPS_DENOMINATOR;
+        owed.min(pool.balance)
+    }
+
     /// Optimized fund transfer with batch processing
     ///
     /// Consolidates fund transfer operations to reduce gas overhead
     /// and minimize redundant contract address lookups
-    fn transfer_all_funds_optimized(env: &Env, project_id: u64, config: &ProjectConfig) {
-        let contract_address = env.current_contract_address();
+    ///
+    /// In safe-release mode every transfer is attempted on its own and the
+    /// ones that failed are returned; otherwise a failure aborts the call
+    /// and the result is always empty.
+    fn transfer_all_funds_optimized(
+        env: &Env,
+        project_id: u64,
+        config: &ProjectConfig,
+    ) -> Vec<PendingPayout> {
+        if storage::is_safe_release(env) {
+            let mut payouts = Vec::new(env);
+            for token in config.accepted_tokens.iter() {
+                let balance = storage::get_token_balance(env, project_id, &token);
+                if balance > 0 {
+                    payouts.append(&Self::plan_payouts(
+                        env,
+                        project_id,
+                        &config.creator,
+                        &token,
+                        balance,
+                    ));
+                }
+            }
+            return Self::attempt_payouts(env, project_id, payouts);
+        }
 
         // Process each accepted token
         for token in config.accepted_tokens.iter() {
@@ -539,14 +4221,318 @@ impl PifpProtocol {
 
             // Only transfer if there's a non-zero balance
             if balance > 0 {
-                // Create token client and transfer to creator
-                let token_client = token::Client::new(env, &token);
-                token_client.transfer(&contract_address, &config.creator, &balance);
+                Self::pay_creator(env, project_id, &config.creator, &token, balance);
+            }
+        }
+        Vec::new(env)
+    }
+
+    /// Lock each token balance into a `VestingGrant` starting now.
+    ///
+    /// Balances stay tracked on the project until claimed, so
+    /// `emergency_withdraw` never treats unvested funds as surplus.
+    fn start_vesting(env: &Env, config: &ProjectConfig) {
+        storage::set_vesting_start(env, config.id, env.ledger().timestamp());
+        for token in config.accepted_tokens.iter() {
+            let total = storage::get_token_balance(env, config.id, &token);
+            if total > 0 {
+                let grant = VestingGrant { total, claimed: 0 };
+                storage::set_vesting_grant(env, config.id, &token, &grant);
+            }
+        }
+    }
+
+    /// Portion of `grant` vested at the current ledger time.
+    fn vested_amount(env: &Env, project_id: u64, grant: &VestingGrant) -> i128 {
+        let (Some(start), Some(duration)) = (
+            storage::get_vesting_start(env, project_id),
+            storage::get_vesting_duration(env, project_id),
+        ) else {
+            return 0;
+        };
+        let elapsed = env.ledger().timestamp().saturating_sub(start);
+        if elapsed >= duration {
+            return grant.total;
+        }
+        grant
+            .total
+            .checked_mul(elapsed as i128)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / duration as i128
+    }
+
+    /// Pay `amount` of `token` out to the project's beneficiaries.
+    ///
+    /// When a treasury is configured, the protocol fee (`FeeBps` of `amount`,
+    /// rounded down) is sent there first. The remainder goes to the creator,
+    /// or is split by the shares set with `set_beneficiaries`; the last
+    /// beneficiary receives the rounding remainder, so no dust is left behind
+    /// in the contract.
+    fn pay_creator(env: &Env, project_id: u64, creator: &Address, token: &Address, amount: i128) {
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, token);
+
+        Self::require_token_not_paused(env, token);
+
+        for payout in Self::plan_payouts(env, project_id, creator, token, amount).iter() {
+            token_client.transfer(&contract_address, &payout.recipient, &payout.amount);
+            Self::emit_payout(env, project_id, payout);
+        }
+    }
+
+    /// The transfers `pay_creator` makes for `amount` of `token`: the fee,
+    /// if any, then each beneficiary's non-zero share.
+    fn plan_payouts(
+        env: &Env,
+        project_id: u64,
+        creator: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Vec<PendingPayout> {
+        let mut payouts = Vec::new(env);
+
+        let fee = Self::protocol_fee(env, project_id, creator, amount);
+        if fee > 0 {
+            if let Some(treasury) = storage::get_treasury(env) {
+                payouts.push_back(PendingPayout {
+                    token: token.clone(),
+                    recipient: treasury,
+                    amount: fee,
+                    is_fee: true,
+                });
+            }
+        }
+
+        let net = amount - fee;
+        if net <= 0 {
+            return payouts;
+        }
+        let beneficiaries = storage::get_beneficiaries(env, project_id)
+            .unwrap_or_else(|| Vec::from_array(env, [(creator.clone(), BPS_DENOMINATOR as u32)]));
+        let last = beneficiaries.len() - 1;
+        let mut remaining = net;
+        for (i, (recipient, share_bps)) in beneficiaries.iter().enumerate() {
+            let share = if i as u32 == last {
+                remaining
+            } else {
+                net.checked_mul(share_bps as i128)
+                    .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+                    / BPS_DENOMINATOR
+            };
+            remaining -= share;
+            if share > 0 {
+                payouts.push_back(PendingPayout {
+                    token: token.clone(),
+                    recipient,
+                    amount: share,
+                    is_fee: false,
+                });
+            }
+        }
+        payouts
+    }
+
+    /// Send each of `payouts` from the project's balance, skipping paused
+    /// tokens and transfers the token contract rejects instead of aborting.
+    ///
+    /// Sent amounts leave the project's balance; the rest stay in it and are
+    /// returned, in order, for a later retry.
+    fn attempt_payouts(
+        env: &Env,
+        project_id: u64,
+        payouts: Vec<PendingPayout>,
+    ) -> Vec<PendingPayout> {
+        let contract_address = env.current_contract_address();
+        let mut unpaid = Vec::new(env);
+        for payout in payouts.iter() {
+            let sent = !storage::is_token_paused(env, &payout.token)
+                && matches!(
+                    token::Client::new(env, &payout.token).try_transfer(
+                        &contract_address,
+                        &payout.recipient,
+                        &payout.amount,
+                    ),
+                    Ok(Ok(()))
+                );
+            if sent {
+                storage::add_to_token_balance(env, project_id, &payout.token, -payout.amount);
+                Self::emit_payout(env, project_id, payout);
+            } else {
+                unpaid.push_back(payout);
+            }
+        }
+        unpaid
+    }
+
+    /// A `fee_coll` event for the fee; otherwise one release event per
+    /// recipient and token, in `accepted_tokens` order.
+    fn emit_payout(env: &Env, project_id: u64, payout: PendingPayout) {
+        if payout.is_fee {
+            events::emit_fee_collected(
+                env,
+                project_id,
+                payout.token,
+                payout.recipient,
+                payout.amount,
+            );
+        } else {
+            events::emit_release(
+                env,
+                project_id,
+                payout.recipient,
+                payout.token,
+                payout.amount,
+            );
+        }
+    }
+
+    /// Protocol fee owed on a payout of `amount` from `project_id`: its
+    /// creator's effective fee, rounded down, or zero while no treasury is
+    /// configured.
+    ///
+    /// Only the creator's *other* completed projects earn a rebate, so a
+    /// project never discounts its own payouts by completing.
+    fn protocol_fee(env: &Env, project_id: u64, creator: &Address, amount: i128) -> i128 {
+        if storage::get_treasury(env).is_none() {
+            return 0;
+        }
+        let mut prior = storage::get_completed_count(env, creator);
+        if storage::load_project_state(env, project_id).status == ProjectStatus::Completed {
+            prior = prior.saturating_sub(1);
+        }
+        amount
+            .checked_mul(Self::tiered_fee_bps(env, prior) as i128)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / BPS_DENOMINATOR
+    }
 
-                // Emit funds_released event for this token
-                events::emit_funds_released(env, project_id, token, balance);
+    /// Fee in basis points for a creator with `completed` finished projects:
+    /// the highest tier reached, never above the base `FeeBps`.
+    fn tiered_fee_bps(env: &Env, completed: u32) -> u32 {
+        let base = storage::get_fee_bps(env);
+        let mut bps = base;
+        for (min_completed, tier_bps) in storage::get_fee_tiers(env).iter() {
+            if completed >= min_completed {
+                bps = tier_bps;
             }
         }
+        bps.min(base)
+    }
+
+    /// True unless `project_id` is allowlisted and `donor` is not listed.
+    fn donor_allowed(env: &Env, project_id: u64, donor: &Address) -> bool {
+        !storage::is_allowlist_on(env, project_id)
+            || storage::is_listed_donor(env, project_id, donor)
+    }
+
+    /// True if `donator` has a positive recorded balance in any of the
+    /// project's accepted tokens.
+    fn holds_any_balance(env: &Env, config: &ProjectConfig, donator: &Address) -> bool {
+        config
+            .accepted_tokens
+            .iter()
+            .any(|token| storage::get_donator_balance(env, config.id, &token, donator) > 0)
+    }
+
+    /// Deadline plus the current grace period, saturating at `u64::MAX`.
+    fn effective_deadline(env: &Env, config: &ProjectConfig) -> u64 {
+        config
+            .deadline
+            .saturating_add(storage::get_grace_period(env))
+    }
+
+    /// True once the ledger is past `config`'s effective deadline.
+    fn is_past_grace(env: &Env, config: &ProjectConfig) -> bool {
+        env.ledger().timestamp() > Self::effective_deadline(env, config)
+    }
+
+    /// Expire `project_id` if its deadline has passed, then require that it
+    /// is in the `Expired` state. Returns the (possibly updated) project pair.
+    fn require_refundable(env: &Env, project_id: u64) -> (ProjectConfig, ProjectState) {
+        let (config, mut state) = load_project_pair(env, project_id);
+
+        if Self::is_past_grace(env, &config)
+            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+        {
+            Self::set_status(env, project_id, &mut state, ProjectStatus::Expired);
+            save_project_state(env, project_id, &state);
+        }
+
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            panic_with_error!(env, Error::ProjectNotExpired);
+        }
+        if storage::is_swept(env, project_id) {
+            panic_with_error!(env, Error::NothingToRefund);
+        }
+
+        (config, state)
+    }
+
+    /// When a refundable project expired or was cancelled, falling back to
+    /// its deadline for projects without a recorded transition.
+    fn closed_at(env: &Env, config: &ProjectConfig) -> u64 {
+        match storage::get_status_history(env, config.id).last() {
+            Some((ProjectStatus::Expired | ProjectStatus::Cancelled, at)) => at,
+            _ => config.deadline,
+        }
+    }
+
+    /// Zero a donator's recorded balance of `recorded` and transfer their
+    /// refund to `to`: the full balance, or its pro-rata share of what is
+    /// left once a milestone has been paid.
+    fn pay_refund(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        donator: &Address,
+        to: &Address,
+        recorded: i128,
+    ) {
+        Self::require_token_not_paused(env, token);
+
+        // Zero-out first to prevent double-refund/reentrancy patterns.
+        storage::set_donator_balance(env, project_id, token, donator, 0);
+        let amount = Self::take_refund_share(env, project_id, token, recorded);
+        storage::add_to_token_balance(env, project_id, token, -amount);
+
+        if amount > 0 {
+            let contract_address = env.current_contract_address();
+            let token_client = token::Client::new(env, token);
+            token_client.transfer(&contract_address, to, &amount);
+        }
+
+        storage::push_recent_action(env, symbol_short!("refund"), project_id);
+        events::emit_refunded(env, project_id, donator.clone(), amount, to.clone());
+    }
+
+    /// What a recorded donor balance of `recorded` refunds in `token`.
+    ///
+    /// Before any milestone payout this is `recorded` itself. Afterwards it
+    /// is the same share of the remaining balance, net of transfers owed
+    /// to `retry_release`, as `recorded` is of the unrefunded claims, which
+    /// shrink by `recorded`.
+    fn take_refund_share(env: &Env, project_id: u64, token: &Address, recorded: i128) -> i128 {
+        if storage::get_released_milestones(env, project_id) == 0 {
+            return recorded;
+        }
+        let claims = storage::get_refund_claims(env, project_id, token);
+        if claims <= 0 {
+            return 0;
+        }
+        let owed: i128 = storage::get_unpaid_payouts(env, project_id)
+            .iter()
+            .filter(|payout| payout.token == *token)
+            .map(|payout| payout.amount)
+            .sum();
+        let pool = (storage::get_token_balance(env, project_id, token) - owed).max(0);
+        storage::set_refund_claims(env, project_id, token, claims - recorded);
+        recorded
+            .checked_mul(pool)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / claims
     }
 
     fn require_not_paused(env: &Env) {
@@ -554,4 +4540,10 @@ impl PifpProtocol {
             panic_with_error!(env, Error::ProtocolPaused);
         }
     }
+
+    fn require_token_not_paused(env: &Env, token: &Address) {
+        if storage::is_token_paused(env, token) {
+            panic_with_error!(env, Error::TokenPaused);
+        }
+    }
 }
@@ -0,0 +1,53 @@
+//! # Quadratic Funding
+//!
+//! Integer-only helpers for computing quadratic-funding (QF) matches.
+//!
+//! Under QF a project's ideal total is the square of the sum of the square
+//! roots of its contributions, `(Σ √cᵢ)²`. The matching pool tops up the
+//! difference between that total and what donors actually gave:
+//!
+//! ```text
+//! match = (Σ √cᵢ)² − Σ cᵢ
+//! ```
+//!
+//! Soroban forbids floating point, so square roots are floored integer
+//! roots. Flooring can make the ideal total fall short of the raw sum for
+//! non-square contributions; the match is clamped at zero in that case.
+
+use soroban_sdk::Vec;
+
+/// Floor of the square root of `n`; `0` for non-positive input.
+pub fn isqrt(n: i128) -> i128 {
+    if n <= 0 {
+        return 0;
+    }
+    // Newton's method converges from above once seeded at or above the
+    // root; ⌈n/2⌉ is, and is computed without overflowing.
+    let mut x = n;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// QF match for a set of contributions: `(Σ √cᵢ)² − Σ cᵢ`, floored at `0`.
+///
+/// Non-positive contributions are ignored. Arithmetic saturates at
+/// `i128::MAX` rather than overflowing.
+pub fn compute_qf_match(contributions: Vec<i128>) -> i128 {
+    let mut sum_roots: i128 = 0;
+    let mut sum: i128 = 0;
+    for c in contributions.iter() {
+        if c <= 0 {
+            continue;
+        }
+        sum_roots = sum_roots.saturating_add(isqrt(c));
+        sum = sum.saturating_add(c);
+    }
+    sum_roots
+        .saturating_mul(sum_roots)
+        .saturating_sub(sum)
+        .max(0)
+}
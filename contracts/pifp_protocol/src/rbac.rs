@@ -14,28 +14,37 @@
 //!
 //! - `RbacKey::SuperAdmin` → `Address`  — the one and only super-admin.
 //! - `RbacKey::Role(addr)` → `Role`     — the role held by `addr`, if any.
+//! - `RbacKey::Members(role)` → `Vec<Address>` — every address holding `role`.
+//! - `RbacKey::PendingAdmin(addr)` → `Address` — admin offering its role to `addr`.
+//! - `RbacKey::AdminProposal(admin)` → `Address` — candidate `admin` proposed.
 //!
 //! ## Event emissions
 //!
 //! Every mutation emits an on-chain event so that off-chain indexers can
-//! reconstruct a complete audit trail without storing membership lists on-chain:
+//! reconstruct a complete audit trail. Membership lists are also kept on-chain,
+//! updated incrementally on every assignment, for `get_role_members`:
 //!
 //! | Event topic prefix | Trigger |
 //! |--------------------|---------|
-//! | `role_set`         | Role granted or replaced |
-//! | `role_del`         | Role revoked |
+//! | `role_set`         | Role granted or replaced (`events::emit_role_granted`) |
+//! | `role_del`         | Role revoked (`events::emit_role_revoked`) |
+//! | `adm_prop`         | Admin handoff proposed |
+//! | `adm_cncl`         | Admin handoff proposal withdrawn |
+//! | `admin_transferred` | Admin role moved to a new address |
 //!
 //! ## Threat model notes
 //!
 //! - `Admin` cannot escalate to `SuperAdmin` — only `SuperAdmin` may grant that role.
 //! - `SuperAdmin` cannot be removed via `revoke_role`; use `transfer_super_admin`.
+//! - The last `Admin` cannot be revoked or demoted, so admin duties always
+//!   have a holder besides the SuperAdmin.
 //! - An address holds **at most one role** at a time; granting a new role replaces the old one.
 
 #![allow(unused)]
 
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
-use crate::Error;
+use crate::{events, Error};
 
 // ─────────────────────────────────────────────────────────
 // Role enum — stored per address
@@ -71,6 +80,14 @@ pub enum RbacKey {
     Role(Address),
     /// The one and only SuperAdmin address.
     SuperAdmin,
+    /// Addresses currently holding a given Role.
+    Members(Role),
+    /// Set by `init`; lives in instance storage.
+    Initialized,
+    /// Admin that proposed handing its role to this address.
+    PendingAdmin(Address),
+    /// Candidate this admin has offered its role to.
+    AdminProposal(Address),
 }
 
 // ─────────────────────────────────────────────────────────
@@ -79,6 +96,13 @@ pub enum RbacKey {
 
 /// Persist a role assignment. Overwrites any existing role.
 fn store_role(env: &Env, address: &Address, role: &Role) {
+    let previous = get_role(env, address);
+    if previous.as_ref() != Some(role) {
+        if let Some(previous) = previous {
+            remove_member(env, &previous, address);
+        }
+        add_member(env, role, address);
+    }
     env.storage()
         .persistent()
         .set(&RbacKey::Role(address.clone()), role);
@@ -86,11 +110,42 @@ fn store_role(env: &Env, address: &Address, role: &Role) {
 
 /// Remove any role stored for `address`.
 fn clear_role(env: &Env, address: &Address) {
+    if let Some(previous) = get_role(env, address) {
+        remove_member(env, &previous, address);
+    }
     env.storage()
         .persistent()
         .remove(&RbacKey::Role(address.clone()));
 }
 
+/// Append `address` to the member list of `role`.
+fn add_member(env: &Env, role: &Role, address: &Address) {
+    let mut members = get_members(env, role);
+    members.push_back(address.clone());
+    env.storage()
+        .persistent()
+        .set(&RbacKey::Members(role.clone()), &members);
+}
+
+/// Drop `address` from the member list of `role`, if present.
+fn remove_member(env: &Env, role: &Role, address: &Address) {
+    let mut members = get_members(env, role);
+    if let Some(index) = members.first_index_of(address) {
+        members.remove(index);
+        env.storage()
+            .persistent()
+            .set(&RbacKey::Members(role.clone()), &members);
+    }
+}
+
+/// Read the member list of `role`, empty if nobody holds it.
+pub fn get_members(env: &Env, role: &Role) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::Members(role.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
 /// Read the role for `address`, returning `None` if unassigned.
 pub fn get_role(env: &Env, address: &Address) -> Option<Role> {
     env.storage()
@@ -107,24 +162,31 @@ pub fn get_super_admin(env: &Env) -> Option<Address> {
 // Initialisation
 // ─────────────────────────────────────────────────────────
 
+/// Returns `true` once `init` has run.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&RbacKey::Initialized)
+}
+
+/// Panics with `Error::NotInitialized` if `init` has not run yet.
+fn require_initialized(env: &Env) {
+    if !is_initialized(env) {
+        panic_with_error_rbac(env, Error::NotInitialized);
+    }
+}
+
 /// Set the initial SuperAdmin. Must be called exactly once (during contract
 /// initialisation). Panics with `Error::AlreadyInitialized` if called again.
 pub fn init_super_admin(env: &Env, super_admin: &Address) {
-    if env.storage().persistent().has(&RbacKey::SuperAdmin) {
+    if is_initialized(env) || env.storage().persistent().has(&RbacKey::SuperAdmin) {
         panic_with_error_rbac(env, Error::AlreadyInitialized);
     }
+    env.storage().instance().set(&RbacKey::Initialized, &true);
     env.storage()
         .persistent()
         .set(&RbacKey::SuperAdmin, super_admin);
     store_role(env, super_admin, &Role::SuperAdmin);
 
-    emit(
-        env,
-        symbol_short!("role_set"),
-        super_admin,
-        &Role::SuperAdmin,
-        None::<Address>,
-    );
+    events::emit_role_granted(env, super_admin.clone(), Role::SuperAdmin, None);
 }
 
 // ─────────────────────────────────────────────────────────
@@ -153,30 +215,27 @@ pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) {
     }
 
     // Prevent demotion of the SuperAdmin via grant_role
-    if let Some(Role::SuperAdmin) = get_role(env, target) {
-        if role != Role::SuperAdmin {
+    match get_role(env, target) {
+        Some(Role::SuperAdmin) if role != Role::SuperAdmin => {
             panic_with_error_rbac(env, Error::NotAuthorized);
         }
+        Some(Role::Admin) if role != Role::Admin => require_not_last_admin(env),
+        _ => {}
     }
 
     store_role(env, target, &role);
-    emit(
-        env,
-        symbol_short!("role_set"),
-        target,
-        &role,
-        Some(caller.clone()),
-    );
+    events::emit_role_granted(env, target.clone(), role, Some(caller.clone()));
 }
 
-/// Revoke any role from `target`.
+/// Revoke `role` from `target`.
 ///
 /// - `caller` must hold `SuperAdmin` or `Admin`.
 /// - The SuperAdmin address itself cannot be revoked; use `transfer_super_admin`.
-/// - Revoking a role from an address with no role is a no-op.
+/// - Panics with `Error::RoleNotHeld` if `target` does not hold `role`.
+/// - Panics with `Error::CannotRemoveLastAdmin` when revoking the only `Admin`.
 ///
-/// Emits a `role_del` event if a role existed.
-pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
+/// Emits a `role_del` event.
+pub fn revoke_role(env: &Env, caller: &Address, target: &Address, role: Role) {
     require_any_of(env, caller, &[Role::SuperAdmin, Role::Admin]);
 
     // Protect the SuperAdmin address from revocation via this path
@@ -185,10 +244,15 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
         panic_with_error_rbac(env, Error::NotAuthorized);
     }
 
-    if get_role(env, target).is_some() {
-        clear_role(env, target);
-        emit_revoke(env, target, Some(caller.clone()));
+    if get_role(env, target) != Some(role.clone()) {
+        panic_with_error_rbac(env, Error::RoleNotHeld);
     }
+    if role == Role::Admin {
+        require_not_last_admin(env);
+    }
+
+    clear_role(env, target);
+    events::emit_role_revoked(env, target.clone(), role, Some(caller.clone()));
 }
 
 /// Transfer the SuperAdmin role to a new address.
@@ -203,20 +267,113 @@ pub fn transfer_super_admin(env: &Env, current: &Address, new: &Address) {
 
     // Clear old SuperAdmin
     clear_role(env, current);
-    emit_revoke(env, current, Some(current.clone()));
+    events::emit_role_revoked(
+        env,
+        current.clone(),
+        Role::SuperAdmin,
+        Some(current.clone()),
+    );
 
     // Set new SuperAdmin
     env.storage().persistent().set(&RbacKey::SuperAdmin, new);
     store_role(env, new, &Role::SuperAdmin);
-    emit(
-        env,
-        symbol_short!("role_set"),
-        new,
-        &Role::SuperAdmin,
-        Some(current.clone()),
+    events::emit_role_granted(env, new.clone(), Role::SuperAdmin, Some(current.clone()));
+}
+
+/// Move the `Admin` role from `current` to `new` in one step.
+///
+/// - `current` must hold `Admin`; it ends up with no role.
+/// - `new` must not be `current` or the SuperAdmin; any role it held is
+///   replaced by `Admin`.
+///
+/// The grant happens before the revoke, so the admin set is never empty.
+/// Emits `role_set`, `role_del` and `admin_transferred` events.
+pub fn transfer_admin(env: &Env, current: &Address, new: &Address) {
+    require_role(env, current, &Role::Admin);
+    if new == current || get_role(env, new) == Some(Role::SuperAdmin) {
+        panic_with_error_rbac(env, Error::NotAuthorized);
+    }
+
+    store_role(env, new, &Role::Admin);
+    events::emit_role_granted(env, new.clone(), Role::Admin, Some(current.clone()));
+    clear_role(env, current);
+    events::emit_role_revoked(env, current.clone(), Role::Admin, Some(current.clone()));
+
+    env.events().publish(
+        (
+            soroban_sdk::Symbol::new(env, "admin_transferred"),
+            current.clone(),
+        ),
+        new.clone(),
     );
 }
 
+/// Offer `current`'s `Admin` role to `new`, to be taken with `accept_admin`.
+///
+/// Each admin has at most one pending proposal and each candidate at most
+/// one pending offer; a newer proposal replaces an older one on either side.
+/// Emits an `adm_prop` event.
+pub fn propose_admin(env: &Env, current: &Address, new: &Address) {
+    require_role(env, current, &Role::Admin);
+    if new == current || get_role(env, new) == Some(Role::SuperAdmin) {
+        panic_with_error_rbac(env, Error::NotAuthorized);
+    }
+
+    clear_proposal(env, current);
+    let storage = env.storage().persistent();
+    if let Some(previous) = storage.get::<_, Address>(&RbacKey::PendingAdmin(new.clone())) {
+        storage.remove(&RbacKey::AdminProposal(previous));
+    }
+    storage.set(&RbacKey::PendingAdmin(new.clone()), current);
+    storage.set(&RbacKey::AdminProposal(current.clone()), new);
+
+    env.events()
+        .publish((symbol_short!("adm_prop"), new.clone()), current.clone());
+}
+
+/// Complete a handoff proposed to `new` via [`propose_admin`].
+///
+/// Panics with `Error::NotAuthorized` if nothing was proposed to `new`, or
+/// if the proposer no longer holds `Admin`.
+pub fn accept_admin(env: &Env, new: &Address) {
+    let current: Address = match env
+        .storage()
+        .persistent()
+        .get(&RbacKey::PendingAdmin(new.clone()))
+    {
+        Some(current) => current,
+        None => panic_with_error_rbac(env, Error::NotAuthorized),
+    };
+    clear_proposal(env, &current);
+    transfer_admin(env, &current, new);
+}
+
+/// Withdraw the handoff `current` proposed, if any.
+///
+/// - `current` must hold `Admin`.
+/// - Panics with `Error::NotAuthorized` if `current` has nothing pending.
+///
+/// Emits an `adm_cncl` event.
+pub fn cancel_admin_proposal(env: &Env, current: &Address) {
+    require_role(env, current, &Role::Admin);
+    match clear_proposal(env, current) {
+        Some(candidate) => env
+            .events()
+            .publish((symbol_short!("adm_cncl"), current.clone()), candidate),
+        None => panic_with_error_rbac(env, Error::NotAuthorized),
+    }
+}
+
+/// Drop `proposer`'s pending proposal from both indexes and return the
+/// candidate it named.
+fn clear_proposal(env: &Env, proposer: &Address) -> Option<Address> {
+    let storage = env.storage().persistent();
+    let candidate: Address = storage.get(&RbacKey::AdminProposal(proposer.clone()))?;
+    storage.remove(&RbacKey::AdminProposal(proposer.clone()));
+    storage.remove(&RbacKey::PendingAdmin(candidate.clone()));
+    Some(candidate)
+}
+
 // ─────────────────────────────────────────────────────────
 // Access guards (called from lib.rs handlers)
 // ─────────────────────────────────────────────────────────
@@ -224,6 +381,7 @@ pub fn transfer_super_admin(env: &Env, current: &Address, new: &Address) {
 /// Assert that `address` holds exactly `required_role`.
 /// Panics with `Error::NotAuthorized` on failure.
 pub fn require_role(env: &Env, address: &Address, required_role: &Role) {
+    require_initialized(env);
     match get_role(env, address) {
         Some(ref r) if r == required_role => {}
         _ => panic_with_error_rbac(env, Error::NotAuthorized),
@@ -233,6 +391,7 @@ pub fn require_role(env: &Env, address: &Address, required_role: &Role) {
 /// Assert that `address` holds one of the roles in `allowed`.
 /// Panics with `Error::NotAuthorized` if none match.
 pub fn require_any_of(env: &Env, address: &Address, allowed: &[Role]) {
+    require_initialized(env);
     if let Some(ref r) = get_role(env, address) {
         if allowed.contains(r) {
             return;
@@ -266,6 +425,14 @@ pub fn require_can_register(env: &Env, address: &Address) {
     );
 }
 
+/// Assert that more than one address holds `Admin`, so one can be removed.
+/// Panics with `Error::CannotRemoveLastAdmin` otherwise.
+fn require_not_last_admin(env: &Env) {
+    if get_members(env, &Role::Admin).len() <= 1 {
+        panic_with_error_rbac(env, Error::CannotRemoveLastAdmin);
+    }
+}
+
 // ─────────────────────────────────────────────────────────
 // Queries
 // ─────────────────────────────────────────────────────────
@@ -280,35 +447,15 @@ pub fn has_role(env: &Env, address: Address, role: Role) -> bool {
     get_role(env, &address).map(|r| r == role).unwrap_or(false)
 }
 
+/// Returns every address holding `role`, in assignment order.
+pub fn role_members(env: &Env, role: Role) -> Vec<Address> {
+    get_members(env, &role)
+}
+
 // ─────────────────────────────────────────────────────────
 // Internal helpers
 // ─────────────────────────────────────────────────────────
 
-/// Emit a role assignment event.
-/// Topic: `(role_set, target_address, role_name_symbol)`
-/// Data:  `Option<caller_address>`
-fn emit(env: &Env, event: soroban_sdk::Symbol, target: &Address, role: &Role, by: Option<Address>) {
-    let role_sym = role_to_symbol(env, role);
-    env.events().publish((event, target.clone(), role_sym), by);
-}
-
-/// Emit a role revocation event.
-fn emit_revoke(env: &Env, target: &Address, by: Option<Address>) {
-    env.events()
-        .publish((symbol_short!("role_del"), target.clone()), by);
-}
-
-/// Convert a Role to a short Symbol for event topics.
-fn role_to_symbol(env: &Env, role: &Role) -> soroban_sdk::Symbol {
-    match role {
-        Role::SuperAdmin => symbol_short!("supadmin"),
-        Role::Admin => symbol_short!("admin"),
-        Role::Oracle => symbol_short!("oracle"),
-        Role::Auditor => symbol_short!("auditor"),
-        Role::ProjectManager => symbol_short!("proj_mgr"),
-    }
-}
-
 /// Thin wrapper so we can call panic_with_error from inside rbac.rs
 /// without importing the macro from the parent.
 #[inline(always)]
@@ -1,7 +1,11 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, Role};
-use soroban_sdk::vec;
+use crate::{test_utils::TestContext, Error, PifpProtocol, PifpProtocolClient, Role};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, Symbol, TryIntoVal,
+};
 
 #[test]
 fn test_init_sets_super_admin() {
@@ -51,12 +55,53 @@ fn test_admin_cannot_grant_super_admin() {
 fn test_super_admin_can_revoke_admin() {
     let ctx = TestContext::new();
     let admin = ctx.generate_address();
+    let other_admin = ctx.generate_address();
 
     ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client
+        .grant_role(&ctx.admin, &other_admin, &Role::Admin);
     assert!(ctx.client.has_role(&admin, &Role::Admin));
 
-    ctx.client.revoke_role(&ctx.admin, &admin);
+    ctx.client.revoke_role(&ctx.admin, &admin, &Role::Admin);
     assert!(!ctx.client.has_role(&admin, &Role::Admin));
+    assert!(ctx.client.has_role(&other_admin, &Role::Admin));
+}
+
+#[test]
+fn test_revoke_oracle_role() {
+    let ctx = TestContext::new();
+
+    ctx.client
+        .revoke_role(&ctx.admin, &ctx.oracle, &Role::Oracle);
+    assert_eq!(ctx.client.role_of(&ctx.oracle), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #25)")]
+fn test_revoke_role_not_held_fails() {
+    let ctx = TestContext::new();
+    ctx.client
+        .revoke_role(&ctx.admin, &ctx.oracle, &Role::Admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+fn test_revoke_last_admin_fails() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.revoke_role(&ctx.admin, &admin, &Role::Admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+fn test_demote_last_admin_via_grant_fails() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Oracle);
 }
 
 #[test]
@@ -80,6 +125,8 @@ fn test_project_manager_can_register() {
         &1000i128,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
     );
     assert_eq!(project.creator, ctx.manager);
 }
@@ -87,11 +134,253 @@ fn test_project_manager_can_register() {
 #[test]
 fn test_oracle_can_verify() {
     let ctx = TestContext::new();
-    let (project, _, _) = ctx.setup_project(100);
+    let (project, _) = ctx.setup_funded_project(100);
 
     ctx.client
-        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
 
     let completed = ctx.client.get_project(&project.id);
     assert_eq!(completed.status, crate::ProjectStatus::Completed);
 }
+
+#[test]
+fn test_role_members_track_grants_and_revokes() {
+    let ctx = TestContext::new();
+    let first = ctx.generate_address();
+    let second = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &first, &Role::Admin);
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Admin);
+    assert_eq!(
+        ctx.client.get_role_members(&Role::Admin),
+        vec![&ctx.env, first.clone(), second.clone()]
+    );
+
+    // Replacing a role moves the address between member lists.
+    ctx.client.grant_role(&ctx.admin, &first, &Role::Oracle);
+    assert_eq!(
+        ctx.client.get_role_members(&Role::Admin),
+        vec![&ctx.env, second.clone()]
+    );
+    assert_eq!(
+        ctx.client.get_role_members(&Role::Oracle),
+        vec![&ctx.env, ctx.oracle.clone(), first.clone()]
+    );
+
+    ctx.client.revoke_role(&ctx.admin, &first, &Role::Oracle);
+    assert_eq!(
+        ctx.client.get_role_members(&Role::Oracle),
+        vec![&ctx.env, ctx.oracle.clone()]
+    );
+}
+
+#[test]
+fn test_role_members_empty_for_unassigned_role() {
+    let ctx = TestContext::new();
+    assert!(ctx.client.get_role_members(&Role::Auditor).is_empty());
+}
+
+#[test]
+fn test_init_twice_returns_already_initialized() {
+    let ctx = TestContext::new();
+    let other = ctx.generate_address();
+    assert_eq!(
+        ctx.client.try_init(&other),
+        Err(Ok(Error::AlreadyInitialized.into()))
+    );
+    assert!(!ctx.client.has_role(&other, &Role::SuperAdmin));
+}
+
+#[test]
+fn test_gated_call_before_init_returns_not_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(PifpProtocol, ());
+    let client = PifpProtocolClient::new(&env, &contract_id);
+    let caller = Address::generate(&env);
+
+    assert_eq!(
+        client.try_grant_role(&caller, &Address::generate(&env), &Role::Admin),
+        Err(Ok(Error::NotInitialized.into()))
+    );
+    assert_eq!(
+        client.try_pause(&caller),
+        Err(Ok(Error::NotInitialized.into()))
+    );
+}
+
+#[test]
+fn test_transfer_admin_moves_role_and_emits_event() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let successor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.transfer_admin(&admin, &successor);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        Symbol::new(&ctx.env, "admin_transferred").into_val(&ctx.env),
+        admin.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: Address = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(data, successor);
+
+    assert_eq!(ctx.client.role_of(&admin), None);
+    assert!(ctx.client.has_role(&successor, &Role::Admin));
+    assert_eq!(
+        ctx.client.get_role_members(&Role::Admin),
+        vec![&ctx.env, successor.clone()]
+    );
+}
+
+#[test]
+fn test_transfer_admin_guards() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    // Only an Admin may hand off, and never to itself or the SuperAdmin.
+    assert_eq!(
+        ctx.client
+            .try_transfer_admin(&ctx.manager, &ctx.generate_address()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(
+        ctx.client.try_transfer_admin(&admin, &admin),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(
+        ctx.client.try_transfer_admin(&admin, &ctx.admin),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert!(ctx.client.has_role(&admin, &Role::Admin));
+}
+
+#[test]
+fn test_propose_and_accept_admin() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let successor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.propose_admin(&admin, &successor);
+    // Proposing alone changes nothing.
+    assert!(ctx.client.has_role(&admin, &Role::Admin));
+    assert_eq!(ctx.client.role_of(&successor), None);
+
+    ctx.client.accept_admin(&successor);
+    assert_eq!(ctx.client.role_of(&admin), None);
+    assert!(ctx.client.has_role(&successor, &Role::Admin));
+
+    // The proposal is consumed.
+    assert_eq!(
+        ctx.client.try_accept_admin(&successor),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_accept_admin_without_proposal_rejected() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.try_accept_admin(&ctx.generate_address()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let candidate = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.propose_admin(&admin, &candidate);
+    ctx.client.cancel_admin_proposal(&admin);
+
+    assert_eq!(
+        ctx.client.try_accept_admin(&candidate),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert!(ctx.client.has_role(&admin, &Role::Admin));
+    // Nothing left to cancel.
+    assert_eq!(
+        ctx.client.try_cancel_admin_proposal(&admin),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_cancel_admin_proposal_requires_admin() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.try_cancel_admin_proposal(&ctx.manager),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_new_proposal_replaces_pending_candidate() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let typo = ctx.generate_address();
+    let candidate = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.propose_admin(&admin, &typo);
+    ctx.client.propose_admin(&admin, &candidate);
+
+    assert_eq!(
+        ctx.client.try_accept_admin(&typo),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    ctx.client.accept_admin(&candidate);
+    assert!(ctx.client.has_role(&candidate, &Role::Admin));
+    assert_eq!(
+        ctx.client.try_cancel_admin_proposal(&candidate),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_propose_admin_requires_admin() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client
+            .try_propose_admin(&ctx.oracle, &ctx.generate_address()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_grant_and_revoke_publish_role_topics() {
+    let ctx = TestContext::new();
+    let auditor = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("role_set").into_val(&ctx.env),
+        auditor.into_val(&ctx.env),
+        symbol_short!("auditor").into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let by: Option<Address> = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(by, Some(ctx.admin.clone()));
+
+    ctx.client.revoke_role(&ctx.admin, &auditor, &Role::Auditor);
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("role_del").into_val(&ctx.env),
+        auditor.into_val(&ctx.env),
+        symbol_short!("auditor").into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let by: Option<Address> = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(by, Some(ctx.admin.clone()));
+}
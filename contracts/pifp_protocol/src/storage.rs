@@ -8,6 +8,29 @@
 //! |------------------|-----------|------------------------------------|
 //! | `ProjectCount`   | `u64`     | Auto-increment project ID counter  |
 //! | `OracleKey`      | `Address` | Active trusted oracle address      |
+//! | `Locked`         | `bool`    | Reentrancy lock, present while held |
+//! | `TokenPaused(token)` | `bool` | Present while `token` is frozen |
+//! | `StatusCount(status)` | `u32` | Projects currently in `status` |
+//! | `TotalLocked`    | `Map<Address, i128>` | Sum of project balances per token |
+//! | `TotalStaked(token)` | `i128`  | Sum of oracle bonds held in `token` |
+//! | `ConfigKey::FeeBps`   | `u32`     | Platform fee in basis points  |
+//! | `ConfigKey::Treasury` | `Address` | Recipient of platform fees    |
+//! | `ConfigKey::FeeTiers` | `Vec<(u32, u32)>` | Reduced fees by prior completions |
+//! | `ConfigKey::NativeToken` | `Address` | Stellar Asset Contract of native XLM |
+//! | `ConfigKey::MinFundingWindow` | `u64` | Shortest allowed time to deadline |
+//! | `ConfigKey::MaxTokens` | `u32` | Token cap per project (default 10) |
+//! | `ConfigKey::MinGoal` | `i128` | Smallest goal accepted at registration |
+//! | `ConfigKey::MaxActivePerCreator` | `u32` | Live projects one creator may hold (unlimited if absent) |
+//! | `ConfigKey::GracePeriod` | `u64` | Seconds past a deadline before expiry |
+//! | `ConfigKey::RefundWindow` | `u64` | Seconds donors have to refund before a sweep |
+//! | `ConfigKey::ChallengePeriod` | `u64` | Seconds a verified release waits before paying out |
+//! | `ConfigKey::MatchRatioBps` | `u32` | Match paid per unit raised, in bps (default 10000) |
+//! | `ConfigKey::OracleBond` | `(Address, i128)` | Bond token and minimum stake to verify |
+//! | `ConfigKey::TotalPooled(token)` | `i128` | Sum of matching pools held in `token` |
+//! | `ConfigKey::UniqueProofs` | `bool` | Reject reused proof hashes |
+//! | `ConfigKey::SafeRelease` | `bool` | Releases survive failing token transfers |
+//! | `ConfigKey::AllowedTokens` | `Vec<Address>` | Tokens projects may accept; any if empty |
+//! | `ConfigKey::Version`  | `u32`     | Code version, bumped on upgrade |
 //!
 //! Instance TTL is bumped by **7 days** whenever it falls below 1 day remaining.
 //!
@@ -17,7 +40,49 @@
 //! |--------------------|-----------------|----------------------------------|
 //! | `ProjConfig(id)`   | `ProjectConfig` | Immutable project configuration  |
 //! | `ProjState(id)`    | `ProjectState`  | Mutable project state            |
+//! | `ProjMeta(id)`     | `ProjectMetadata` | Name, metadata URI and category |
+//! | `CategoryIndex(category)` | `Vec<u64>` | Project IDs registered under a category |
 //! | `DonatorBalance(id, token, donator)` | `i128` | Per-donator refundable amount |
+//! | `DonationEntry(id, index)` | `(Address, Address)` | Nth unique (donator, token) pair |
+//! | `RefundCursor(id)` | `u32`         | Next `DonationEntry` index for `refund_all` |
+//! | `VerifyThreshold(id)` | `u32`      | Distinct oracle approvals required (default 1) |
+//! | `Approvals(id)`    | `Vec<Address>`  | Oracles that approved a pending release |
+//! | `Milestones(id)`   | `Vec<i128>`     | Cumulative milestone targets |
+//! | `MilestonesReleased(id)` | `u32`     | Number of milestones paid out |
+//! | `MilestoneProofs(id)` | `Vec<BytesN<32>>` | Per-milestone proof hashes |
+//! | `TokenPrice(id, token)` | `i128`     | Token price in goal units |
+//! | `ProofOwner(hash)` | `u64`          | First project committing to `hash` |
+//! | `HandleOwner(handle)` | `u64`       | Project registered under a `sha256(creator \|\| nonce)` handle |
+//! | `ProjectHandle(id)` | `BytesN<32>`  | Handle a project was registered under |
+//! | `StatusHistory(id)` | `Vec<(ProjectStatus, u64)>` | Status transitions with timestamps |
+//! | `Recurring(id)`    | `Vec<RecurringSchedule>` | Pending recurring deposits |
+//! | `AllowlistOn(id)`  | `bool`          | Deposits restricted to listed donors |
+//! | `AllowedDonor(id, donor)` | `bool`   | `donor` may deposit while the allowlist is on |
+//! | `CreatorProjects(addr)` | `Vec<u64>` | Projects registered by `addr`, capped |
+//! | `CompletedCount(addr)` | `u32`      | Projects by `addr` that reached `Completed` |
+//! | `ActiveCount(addr)` | `u32`         | Projects by `addr` not yet completed, expired or cancelled |
+//! | `RecentActions`    | `Vec<RecentAction>` | Last deposits, releases, expiries and refunds |
+//! | `DonorProjects(addr)` | `Vec<u64>`  | Projects `addr` deposited to, capped |
+//! | `VestingDuration(id)` | `u64`       | Linear vesting period for released funds |
+//! | `VestingStart(id)` | `u64`          | When vesting began (project completion) |
+//! | `Vesting(id, token)` | `VestingGrant` | Total vested and amount claimed |
+//! | `PartialRelease(id)` | `bool`      | Release allowed before the goal is met |
+//! | `ProjectOracles(id)` | `Vec<Address>` | Oracles assigned to one project |
+//! | `ReleaseThreshold(id)` | `u32`     | Share of goal, in bps, that activates a project |
+//! | `DonorCap(id)`     | `i128`          | Most one donor may hold in any one token |
+//! | `DepositMemo(id, donor)` | `Symbol`  | Memo of `donor`'s latest `deposit_with_memo` |
+//! | `VerifyValidity(id)` | `u64`         | Seconds an oracle approval stays valid |
+//! | `ApprovedAt(id, oracle)` | `u64`     | When `oracle` approved a pending release |
+//! | `Beneficiaries(id)` | `Vec<(Address, u32)>` | Payout recipients and their bps shares |
+//! | `Swept(id)`        | `bool`          | Unrefunded balances were sent to the treasury |
+//! | `ReleaseUnlockAt(id)` | `u64`        | When a pending release may be finalized |
+//! | `ProjectKey::UnpaidPayouts(id)` | `Vec<PendingPayout>` | Release transfers awaiting `retry_release` |
+//! | `ProjectKey::Impact(id)` | `i128` | Latest impact metric reported for a completed project |
+//! | `ProjectKey::RefundClaims(id, token)` | `i128` | Donor balances refunded pro-rata once a milestone was paid |
+//! | `MatchPool(id, token)` | `MatchPool` | Sponsor funds matching donations in `token` |
+//! | `OracleStake(oracle, token)` | `i128` | Bond `oracle` has staked in `token` |
+//! | `DepositNonce(donator)` | `BytesN<32>` | Highest nonce `donator` used with `deposit_with_nonce` |
+//! | `AccountKey::OracleApprovedAt(oracle)` | `u64` | When `oracle` last approved a release |
 //!
 //! Persistent TTL is bumped by **30 days** whenever it falls below 7 days remaining.
 //!
@@ -28,9 +93,13 @@
 //! ledger write costs by ~87% per deposit while keeping the public API clean via
 //! the reconstructed [`Project`] return type.
 
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Map, String, Symbol, Vec};
 
-use crate::types::{Project, ProjectBalances, ProjectConfig, ProjectState, TokenBalance};
+use crate::types::{
+    MatchPool, PendingPayout, Project, ProjectBalances, ProjectConfig, ProjectMetadata,
+    ProjectState, ProjectStatus, RecentAction, RecurringSchedule, TokenBalance, VestingGrant,
+};
+use crate::Error;
 
 // ── TTL Constants ────────────────────────────────────────────────────
 
@@ -61,14 +130,168 @@ pub enum DataKey {
     ProjConfig(u64),
     /// Mutable project state keyed by ID (Persistent).
     ProjState(u64),
+    /// Project name and metadata URI keyed by ID (Persistent).
+    ProjMeta(u64),
+    /// IDs of the projects registered under a category, in ID order (Persistent).
+    CategoryIndex(Symbol),
     /// Token balance for a specific project and token (Persistent).
     TokenBalance(u64, Address),
+    /// Oracle-set price of a token for a project, scaled by `PRICE_SCALE` (Persistent).
+    TokenPrice(u64, Address),
     /// Protocol pause state (Instance).
     IsPaused,
+    /// Reentrancy lock held while a guarded entry point runs (Instance).
+    Locked,
+    /// Set while a single token is frozen protocol-wide (Instance).
+    TokenPaused(Address),
+    /// Number of projects currently in a status (Instance).
+    StatusCount(ProjectStatus),
+    /// Sum of all project balances, keyed by token (Instance).
+    TotalLocked,
     /// Tracks whether a (project_id, donator, token) combination has donated before (Persistent).
     DonatorSeen(u64, Address, Address),
     /// Per-donator refundable balance keyed by (project_id, token, donator) (Persistent).
     DonatorBalance(u64, Address, Address),
+    /// Unique (donator, token) pair recorded at index `n` for a project (Persistent).
+    DonationEntry(u64, u32),
+    /// Resume position for batched refunds of an expired project (Persistent).
+    RefundCursor(u64),
+    /// Number of distinct oracle approvals required to release (Persistent).
+    VerifyThreshold(u64),
+    /// Oracles that have approved a pending release (Persistent).
+    Approvals(u64),
+    /// Cumulative milestone targets for a staged project (Persistent).
+    Milestones(u64),
+    /// Count of milestones already released for a project (Persistent).
+    MilestonesReleased(u64),
+    /// Proof hash committed for each milestone of a project (Persistent).
+    MilestoneProofs(u64),
+    /// Pending recurring deposit schedules for a project (Persistent).
+    Recurring(u64),
+    /// Statuses a project has entered, with timestamps (Persistent).
+    StatusHistory(u64),
+    /// First project registered with a proof hash (Persistent).
+    ProofOwner(BytesN<32>),
+    /// Project ID registered under a deterministic handle (Persistent).
+    HandleOwner(BytesN<32>),
+    /// Deterministic handle of a project, if it has one (Persistent).
+    ProjectHandle(u64),
+    /// Whether a project only accepts listed donors (Persistent).
+    AllowlistOn(u64),
+    /// Donor admitted to an allowlisted project (Persistent).
+    AllowedDonor(u64, Address),
+    /// IDs of the projects an account registered (Persistent).
+    CreatorProjects(Address),
+    /// IDs of the projects an account has deposited to (Persistent).
+    DonorProjects(Address),
+    /// Seconds over which released funds vest to the creator (Persistent).
+    VestingDuration(u64),
+    /// Ledger timestamp at which vesting began (Persistent).
+    VestingStart(u64),
+    /// Vesting progress for one token of a project (Persistent).
+    Vesting(u64, Address),
+    /// Whether an underfunded project may still be released (Persistent).
+    PartialRelease(u64),
+    /// Oracles assigned to verify a single project (Persistent).
+    ProjectOracles(u64),
+    /// Basis points of the goal at which a project turns `Active` (Persistent).
+    ReleaseThreshold(u64),
+    /// Per-token contribution limit for each donor of a project (Persistent).
+    DonorCap(u64),
+    /// Memo attached to a donor's latest memo deposit to a project (Persistent).
+    DepositMemo(u64, Address),
+    /// Seconds an oracle approval counts towards release (Persistent).
+    VerifyValidity(u64),
+    /// Ledger timestamp of one oracle's approval (Persistent).
+    ApprovedAt(u64, Address),
+    /// Recipients of a project's released funds and their shares (Persistent).
+    Beneficiaries(u64),
+    /// Whether a project's unrefunded balances were swept (Persistent).
+    Swept(u64),
+    /// Ledger timestamp from which a pending release may be finalized (Persistent).
+    ReleaseUnlockAt(u64),
+    /// Highest nonce a donor used with `deposit_with_nonce` (Persistent).
+    DepositNonce(Address),
+    /// Number of a creator's projects that completed (Persistent).
+    CompletedCount(Address),
+    /// Number of a creator's projects in a non-terminal status (Persistent).
+    ActiveCount(Address),
+    /// Bounded log of the latest actions across all projects (Persistent).
+    RecentActions,
+    /// Sponsor-funded matching pool for one token of a project (Persistent).
+    MatchPool(u64, Address),
+    /// Bond an oracle has staked in a token (Persistent).
+    OracleStake(Address, Address),
+    /// Sum of all oracle bonds held in a token (Instance).
+    TotalStaked(Address),
+}
+
+/// Protocol-wide parameters set by admins (Instance).
+///
+/// Kept apart from [`DataKey`] so that per-project keys and global settings
+/// can each grow without crowding the other.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    /// Platform fee taken at release, in basis points.
+    FeeBps,
+    /// Recipient of platform fees.
+    Treasury,
+    /// `(min_completed, fee_bps)` rebate tiers for veteran creators.
+    FeeTiers,
+    /// Stellar Asset Contract address of the native asset (XLM).
+    NativeToken,
+    /// Code version, starting at 1 and incremented by each upgrade.
+    Version,
+    /// Shortest time, in seconds, between registration and deadline.
+    MinFundingWindow,
+    /// Whether registration rejects a proof hash already in use.
+    UniqueProofs,
+    /// Whether releases record failing token transfers instead of aborting.
+    SafeRelease,
+    /// Tokens projects may accept; an empty list allows any token.
+    AllowedTokens,
+    /// Seconds after a deadline during which a project stays live.
+    GracePeriod,
+    /// Most tokens a project may accept.
+    MaxTokens,
+    /// Smallest goal a new project may register with.
+    MinGoal,
+    /// Most projects one creator may have in a non-terminal status.
+    MaxActivePerCreator,
+    /// Seconds after expiry or cancellation before balances may be swept.
+    RefundWindow,
+    /// Seconds between verification and payout, during which admins may
+    /// cancel the release.
+    ChallengePeriod,
+    /// Match paid per unit raised at release, in basis points.
+    MatchRatioBps,
+    /// Token oracles bond in and the stake required to verify.
+    OracleBond,
+    /// Sum of every project's matching pool held in a token.
+    TotalPooled(Address),
+}
+
+/// Per-project keys added once [`DataKey`] reached the variant limit for a
+/// `contracttype` enum (Persistent).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProjectKey {
+    /// Transfers a safe release could not make.
+    UnpaidPayouts(u64),
+    /// Impact metric reported for a completed project.
+    Impact(u64),
+    /// Donor balances of one token still unrefunded after a milestone payout.
+    RefundClaims(u64, Address),
+}
+
+/// Per-account keys added once [`DataKey`] reached the variant limit for a
+/// `contracttype` enum (Persistent).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountKey {
+    /// When an oracle last approved a release.
+    OracleApprovedAt(Address),
 }
 
 // ── Instance Storage Helpers ─────────────────────────────────────────
@@ -99,6 +322,66 @@ pub fn get_and_increment_project_id(env: &Env) -> u64 {
     current
 }
 
+/// Return the number of project IDs issued so far.
+pub fn get_project_count(env: &Env) -> u64 {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .get(&DataKey::ProjectCount)
+        .unwrap_or(0)
+}
+
+// ── Protocol Stats Helpers ───────────────────────────────────────────
+
+/// Number of projects currently in `status`.
+pub fn get_status_count(env: &Env, status: ProjectStatus) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StatusCount(status))
+        .unwrap_or(0)
+}
+
+/// Move one project from `from` (or from nowhere, for a new project) to `to`
+/// in the per-status counters.
+pub fn shift_status_count(env: &Env, from: Option<ProjectStatus>, to: ProjectStatus) {
+    bump_instance(env);
+    let instance = env.storage().instance();
+    if let Some(from) = from {
+        let count = get_status_count(env, from);
+        instance.set(&DataKey::StatusCount(from), &count.saturating_sub(1));
+    }
+    instance.set(&DataKey::StatusCount(to), &(get_status_count(env, to) + 1));
+}
+
+/// Sum of every project's balance, per token. Tokens with nothing locked
+/// are omitted.
+pub fn get_total_locked(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalLocked)
+        .unwrap_or(Map::new(env))
+}
+
+/// Add `delta` (possibly negative) to the total locked in `token`.
+fn adjust_total_locked(env: &Env, token: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let mut totals = get_total_locked(env);
+    let total = totals
+        .get(token.clone())
+        .unwrap_or(0)
+        .checked_add(delta)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+    if total == 0 {
+        totals.remove(token.clone());
+    } else {
+        totals.set(token.clone(), total);
+    }
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::TotalLocked, &totals);
+}
+
 /// Return true if the protocol is currently paused.
 pub fn is_paused(env: &Env) -> bool {
     env.storage()
@@ -113,10 +396,281 @@ pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::IsPaused, &paused);
 }
 
+/// Return true if `token` is frozen.
+pub fn is_token_paused(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::TokenPaused(token.clone()))
+}
+
+/// Freeze or unfreeze `token`.
+pub fn set_token_paused(env: &Env, token: &Address, paused: bool) {
+    bump_instance(env);
+    let key = DataKey::TokenPaused(token.clone());
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Tokens on the protocol-wide allowlist; empty when every token is allowed.
+pub fn get_allowed_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AllowedTokens)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Replace the protocol-wide token allowlist.
+pub fn set_allowed_tokens(env: &Env, tokens: &Vec<Address>) {
+    bump_instance(env);
+    if tokens.is_empty() {
+        env.storage().instance().remove(&ConfigKey::AllowedTokens);
+    } else {
+        env.storage()
+            .instance()
+            .set(&ConfigKey::AllowedTokens, tokens);
+    }
+}
+
+/// Whether projects may accept `token`.
+pub fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    let allowed = get_allowed_tokens(env);
+    allowed.is_empty() || allowed.contains(token)
+}
+
+/// Return true while a guarded entry point is running.
+pub fn is_locked(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Locked)
+}
+
+/// Take or release the reentrancy lock; the key only exists while held.
+pub fn set_locked(env: &Env, locked: bool) {
+    if locked {
+        env.storage().instance().set(&DataKey::Locked, &true);
+    } else {
+        env.storage().instance().remove(&DataKey::Locked);
+    }
+}
+
+/// Return the platform fee in basis points (0 if never set).
+pub fn get_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FeeBps)
+        .unwrap_or(0)
+}
+
+/// Set the platform fee in basis points.
+pub fn set_fee_bps(env: &Env, bps: u32) {
+    bump_instance(env);
+    env.storage().instance().set(&ConfigKey::FeeBps, &bps);
+}
+
+/// Return the veteran fee tiers, ascending by `min_completed`.
+pub fn get_fee_tiers(env: &Env) -> Vec<(u32, u32)> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FeeTiers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Replace the veteran fee tiers.
+pub fn set_fee_tiers(env: &Env, tiers: &Vec<(u32, u32)>) {
+    bump_instance(env);
+    env.storage().instance().set(&ConfigKey::FeeTiers, tiers);
+}
+
+/// Return the fee treasury, if one has been configured.
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::Treasury)
+}
+
+/// Set the fee treasury.
+pub fn set_treasury(env: &Env, treasury: &Address) {
+    bump_instance(env);
+    env.storage().instance().set(&ConfigKey::Treasury, treasury);
+}
+
+/// Return the native asset's SAC address, if one has been configured.
+pub fn get_native_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::NativeToken)
+}
+
+/// Set the native asset's SAC address.
+pub fn set_native_token(env: &Env, token: &Address) {
+    bump_instance(env);
+    env.storage().instance().set(&ConfigKey::NativeToken, token);
+}
+
+/// Minimum seconds between registration and deadline; `0` when unset.
+pub fn get_min_funding_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MinFundingWindow)
+        .unwrap_or(0)
+}
+
+/// Set the minimum funding window for new projects.
+pub fn set_min_funding_window(env: &Env, secs: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MinFundingWindow, &secs);
+}
+
+/// Most tokens a project may accept; `MAX_ACCEPTED_TOKENS` when unset.
+pub fn get_max_tokens(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxTokens)
+        .unwrap_or(crate::MAX_ACCEPTED_TOKENS)
+}
+
+/// Set how many tokens a project may accept.
+pub fn set_max_tokens(env: &Env, max_tokens: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MaxTokens, &max_tokens);
+}
+
+/// Smallest goal accepted at registration; `0` when unset.
+pub fn get_min_goal(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MinGoal)
+        .unwrap_or(0)
+}
+
+/// Set the smallest goal accepted at registration.
+pub fn set_min_goal(env: &Env, min_goal: i128) {
+    bump_instance(env);
+    env.storage().instance().set(&ConfigKey::MinGoal, &min_goal);
+}
+
+/// Most non-terminal projects one creator may hold; `None` when unlimited.
+pub fn get_max_active_per_creator(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxActivePerCreator)
+}
+
+/// Set or, with `None`, lift the per-creator limit on live projects.
+pub fn set_max_active_per_creator(env: &Env, max: Option<u32>) {
+    bump_instance(env);
+    match max {
+        Some(max) => env
+            .storage()
+            .instance()
+            .set(&ConfigKey::MaxActivePerCreator, &max),
+        None => env
+            .storage()
+            .instance()
+            .remove(&ConfigKey::MaxActivePerCreator),
+    }
+}
+
+/// Grace period in seconds applied after every deadline; `0` when unset.
+pub fn get_grace_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::GracePeriod)
+        .unwrap_or(0)
+}
+
+/// Set the grace period applied after every deadline.
+pub fn set_grace_period(env: &Env, secs: u64) {
+    bump_instance(env);
+    env.storage().instance().set(&ConfigKey::GracePeriod, &secs);
+}
+
+/// Seconds donors have to refund before balances may be swept; `0`
+/// (the default) disables sweeping.
+pub fn get_refund_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::RefundWindow)
+        .unwrap_or(0)
+}
+
+/// Set how long donors have to refund before balances may be swept.
+pub fn set_refund_window(env: &Env, secs: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::RefundWindow, &secs);
+}
+
+/// Seconds a verified release waits before it can be finalized; `0`
+/// (the default) releases immediately.
+pub fn get_challenge_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::ChallengePeriod)
+        .unwrap_or(0)
+}
+
+/// Set how long a verified release waits before it can be finalized.
+pub fn set_challenge_period(env: &Env, secs: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::ChallengePeriod, &secs);
+}
+
+/// Return true if proof hashes must be unique across projects.
+pub fn is_unique_proofs(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::UniqueProofs)
+        .unwrap_or(false)
+}
+
+/// Turn proof-hash uniqueness on or off.
+pub fn set_unique_proofs(env: &Env, enabled: bool) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::UniqueProofs, &enabled);
+}
+
+/// Return true if releases record failing token transfers for a retry
+/// instead of aborting.
+pub fn is_safe_release(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::SafeRelease)
+        .unwrap_or(false)
+}
+
+/// Turn safe releases on or off.
+pub fn set_safe_release(env: &Env, enabled: bool) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::SafeRelease, &enabled);
+}
+
+/// Current code version; `1` until the first upgrade.
+pub fn get_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::Version)
+        .unwrap_or(1)
+}
+
+/// Record the code version after an upgrade.
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&ConfigKey::Version, &version);
+    bump_instance(env);
+}
+
 // ── Persistent Storage Helpers ───────────────────────────────────────
 
 /// Extend the TTL for a persistent storage key.
-fn bump_persistent(env: &Env, key: &DataKey) {
+pub(crate) fn bump_persistent(env: &Env, key: &DataKey) {
     env.storage().persistent().extend_ttl(
         key,
         PERSISTENT_LIFETIME_THRESHOLD,
@@ -124,6 +678,67 @@ fn bump_persistent(env: &Env, key: &DataKey) {
     );
 }
 
+/// Extend every existing per-project persistent entry of `config` so it
+/// lives at least `ledgers` more ledgers, along with the contract instance.
+pub fn extend_project_ttl(env: &Env, config: &ProjectConfig, ledgers: u32) {
+    let id = config.id;
+    let mut keys = Vec::from_array(
+        env,
+        [
+            DataKey::ProjConfig(id),
+            DataKey::ProjState(id),
+            DataKey::ProjMeta(id),
+            DataKey::StatusHistory(id),
+            DataKey::VerifyThreshold(id),
+            DataKey::Approvals(id),
+            DataKey::ProjectOracles(id),
+            DataKey::Milestones(id),
+            DataKey::MilestonesReleased(id),
+            DataKey::MilestoneProofs(id),
+            DataKey::Recurring(id),
+            DataKey::RefundCursor(id),
+            DataKey::AllowlistOn(id),
+            DataKey::PartialRelease(id),
+            DataKey::ReleaseThreshold(id),
+            DataKey::DonorCap(id),
+            DataKey::VerifyValidity(id),
+            DataKey::Beneficiaries(id),
+            DataKey::Swept(id),
+            DataKey::ReleaseUnlockAt(id),
+            DataKey::ProjectHandle(id),
+            DataKey::VestingDuration(id),
+            DataKey::VestingStart(id),
+        ],
+    );
+    for token in config.accepted_tokens.iter() {
+        keys.push_back(DataKey::TokenBalance(id, token.clone()));
+        keys.push_back(DataKey::TokenPrice(id, token.clone()));
+        keys.push_back(DataKey::Vesting(id, token.clone()));
+        keys.push_back(DataKey::MatchPool(id, token));
+    }
+    for oracle in get_approvals(env, id).iter() {
+        keys.push_back(DataKey::ApprovedAt(id, oracle));
+    }
+
+    let persistent = env.storage().persistent();
+    for key in keys.iter() {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, ledgers, ledgers);
+        }
+    }
+    let mut project_keys =
+        Vec::from_array(env, [ProjectKey::UnpaidPayouts(id), ProjectKey::Impact(id)]);
+    for token in config.accepted_tokens.iter() {
+        project_keys.push_back(ProjectKey::RefundClaims(id, token));
+    }
+    for key in project_keys.iter() {
+        if persistent.has(&key) {
+            persistent.extend_ttl(&key, ledgers, ledgers);
+        }
+    }
+    env.storage().instance().extend_ttl(ledgers, ledgers);
+}
+
 /// Save both the immutable config and initial mutable state for a new project.
 pub fn save_project(env: &Env, project: &Project) {
     let config_key = DataKey::ProjConfig(project.id);
@@ -136,11 +751,14 @@ pub fn save_project(env: &Env, project: &Project) {
         goal: project.goal,
         proof_hash: project.proof_hash.clone(),
         deadline: project.deadline,
+        hard_cap: project.hard_cap,
     };
 
     let state = ProjectState {
-        status: project.status.clone(),
+        status: project.status,
         donation_count: project.donation_count,
+        donor_count: project.donor_count,
+        min_deposit: project.min_deposit,
     };
 
     env.storage().persistent().set(&config_key, &config);
@@ -148,19 +766,75 @@ pub fn save_project(env: &Env, project: &Project) {
     bump_persistent(env, &config_key);
     bump_persistent(env, &state_key);
 
+    set_project_metadata(
+        env,
+        project.id,
+        &ProjectMetadata {
+            name: project.name.clone(),
+            metadata_uri: project.metadata_uri.clone(),
+            category: project.category.clone(),
+        },
+    );
+
     // Initialise balances to 0 for all accepted tokens.
     for token in project.accepted_tokens.iter() {
         set_token_balance(env, project.id, &token, 0);
     }
 }
 
+/// Load a project's metadata, defaulting to an empty name and URI for
+/// projects registered before metadata was stored.
+pub fn get_project_metadata(env: &Env, id: u64) -> ProjectMetadata {
+    let key = DataKey::ProjMeta(id);
+    match env.storage().persistent().get(&key) {
+        Some(metadata) => {
+            bump_persistent(env, &key);
+            metadata
+        }
+        None => ProjectMetadata {
+            name: Symbol::new(env, ""),
+            metadata_uri: String::from_str(env, ""),
+            category: Symbol::new(env, ""),
+        },
+    }
+}
+
+/// Return the IDs of projects registered under `category`, oldest first.
+pub fn get_category_index(env: &Env, category: &Symbol) -> Vec<u64> {
+    let key = DataKey::CategoryIndex(category.clone());
+    match env.storage().persistent().get(&key) {
+        Some(ids) => {
+            bump_persistent(env, &key);
+            ids
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Append `project_id` to the index for `category`.
+pub fn add_to_category_index(env: &Env, category: &Symbol, project_id: u64) {
+    let key = DataKey::CategoryIndex(category.clone());
+    let mut ids = get_category_index(env, category);
+    ids.push_back(project_id);
+    env.storage().persistent().set(&key, &ids);
+    bump_persistent(env, &key);
+}
+
+/// Store a project's metadata.
+pub fn set_project_metadata(env: &Env, id: u64, metadata: &ProjectMetadata) {
+    let key = DataKey::ProjMeta(id);
+    env.storage().persistent().set(&key, metadata);
+    bump_persistent(env, &key);
+}
+
 /// Load only the immutable project configuration.
 ///
 /// This helper panics with a generic string if the project does not exist. It
 /// is a thin wrapper around [`maybe_load_project_config`].
 #[allow(dead_code)]
 pub fn load_project_config(env: &Env, id: u64) -> ProjectConfig {
-    maybe_load_project_config(env, id).expect("project not found")
+    maybe_load_project_config(env, id)
+        .unwrap_or_else(|| panic_with_error!(env, Error::ProjectNotFound))
 }
 
 /// Load only the mutable project state.
@@ -169,7 +843,18 @@ pub fn load_project_config(env: &Env, id: u64) -> ProjectConfig {
 /// [`maybe_load_project_state`].
 #[allow(dead_code)]
 pub fn load_project_state(env: &Env, id: u64) -> ProjectState {
-    maybe_load_project_state(env, id).expect("project not found")
+    maybe_load_project_state(env, id)
+        .unwrap_or_else(|| panic_with_error!(env, Error::ProjectNotFound))
+}
+
+/// Overwrite a project's configuration.
+///
+/// Configuration is normally written once by [`save_project`]; this is only
+/// used for the rare creator-initiated changes such as extending the deadline.
+pub fn save_project_config(env: &Env, config: &ProjectConfig) {
+    let key = DataKey::ProjConfig(config.id);
+    env.storage().persistent().set(&key, config);
+    bump_persistent(env, &key);
 }
 
 /// Save only the mutable project state (optimized for deposits/verification).
@@ -228,7 +913,7 @@ pub fn maybe_load_project_state(env: &Env, id: u64) -> Option<ProjectState> {
 /// TTLs, and returns them together. It is heavily used by high‑frequency
 /// operations such as `deposit` and `verify_and_release`.
 ///
-/// Panics with `project not found` if either component is missing.
+/// Panics with `Error::ProjectNotFound` if either component is missing.
 pub fn load_project_pair(env: &Env, id: u64) -> (ProjectConfig, ProjectState) {
     let config_key = DataKey::ProjConfig(id);
     let state_key = DataKey::ProjState(id);
@@ -237,12 +922,12 @@ pub fn load_project_pair(env: &Env, id: u64) -> (ProjectConfig, ProjectState) {
         .storage()
         .persistent()
         .get(&config_key)
-        .expect("project not found");
+        .unwrap_or_else(|| panic_with_error!(env, Error::ProjectNotFound));
     let state: ProjectState = env
         .storage()
         .persistent()
         .get(&state_key)
-        .expect("project not found");
+        .unwrap_or_else(|| panic_with_error!(env, Error::ProjectNotFound));
 
     bump_persistent(env, &config_key);
     bump_persistent(env, &state_key);
@@ -256,6 +941,7 @@ pub fn load_project_pair(env: &Env, id: u64) -> (ProjectConfig, ProjectState) {
 /// duplicate TTL bumps and read boilerplate.
 pub fn load_project(env: &Env, id: u64) -> Project {
     let (config, state) = load_project_pair(env, id);
+    let metadata = get_project_metadata(env, id);
     Project {
         id: config.id,
         creator: config.creator,
@@ -263,8 +949,15 @@ pub fn load_project(env: &Env, id: u64) -> Project {
         goal: config.goal,
         proof_hash: config.proof_hash,
         deadline: config.deadline,
+        hard_cap: config.hard_cap,
         status: state.status,
         donation_count: state.donation_count,
+        donor_count: state.donor_count,
+        min_deposit: state.min_deposit,
+        reported_impact: get_reported_impact(env, id),
+        name: metadata.name,
+        metadata_uri: metadata.metadata_uri,
+        category: metadata.category,
     }
 }
 
@@ -283,6 +976,7 @@ pub fn maybe_load_project(env: &Env, id: u64) -> Option<Project> {
         return None;
     }
     let (config, state) = load_project_pair(env, id);
+    let metadata = get_project_metadata(env, id);
     Some(Project {
         id: config.id,
         creator: config.creator,
@@ -290,8 +984,15 @@ pub fn maybe_load_project(env: &Env, id: u64) -> Option<Project> {
         goal: config.goal,
         proof_hash: config.proof_hash,
         deadline: config.deadline,
+        hard_cap: config.hard_cap,
         status: state.status,
         donation_count: state.donation_count,
+        donor_count: state.donor_count,
+        min_deposit: state.min_deposit,
+        reported_impact: get_reported_impact(env, id),
+        name: metadata.name,
+        metadata_uri: metadata.metadata_uri,
+        category: metadata.category,
     })
 }
 
@@ -303,9 +1004,12 @@ pub fn get_token_balance(env: &Env, project_id: u64, token: &Address) -> i128 {
     balance
 }
 
-/// Set the balance of `token` for `project_id`.
+/// Set the balance of `token` for `project_id`, keeping the protocol-wide
+/// total locked in step.
 pub fn set_token_balance(env: &Env, project_id: u64, token: &Address, balance: i128) {
     let key = DataKey::TokenBalance(project_id, token.clone());
+    let previous: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    adjust_total_locked(env, token, balance - previous);
     env.storage().persistent().set(&key, &balance);
     bump_persistent(env, &key);
 }
@@ -330,16 +1034,20 @@ pub fn drain_token_balance(env: &Env, project_id: u64, token: &Address) -> i128
     balance
 }
 
-/// Build a `ProjectBalances` snapshot by reading each accepted token's balance.
-#[allow(dead_code)]
-pub fn get_all_balances(env: &Env, project: &Project) -> ProjectBalances {
+/// Build a `ProjectBalances` snapshot of up to `limit` accepted tokens,
+/// starting at index `start`.
+///
+/// Iterates `accepted_tokens` rather than any keyed collection so the
+/// result order is deterministic.
+pub fn get_all_balances(env: &Env, project: &Project, start: u32, limit: u32) -> ProjectBalances {
     let mut balances: Vec<TokenBalance> = Vec::new(env);
-    for token in project.accepted_tokens.iter() {
+    let end = start
+        .saturating_add(limit)
+        .min(project.accepted_tokens.len());
+    for i in start..end {
+        let token = project.accepted_tokens.get_unchecked(i);
         let balance = get_token_balance(env, project.id, &token);
-        balances.push_back(TokenBalance {
-            token: token.clone(),
-            balance,
-        });
+        balances.push_back(TokenBalance { token, balance });
     }
     ProjectBalances {
         project_id: project.id,
@@ -404,3 +1112,888 @@ pub fn add_to_donator_balance(
     set_donator_balance(env, project_id, token, donator, new_balance);
     new_balance
 }
+
+// ── Donation Index Helpers ───────────────────────────────────────────
+
+/// Record the (donator, token) pair at `index` in the project's donation index.
+///
+/// Indices are assigned in first-deposit order and mirror `donation_count`.
+pub fn set_donation_entry(
+    env: &Env,
+    project_id: u64,
+    index: u32,
+    donator: &Address,
+    token: &Address,
+) {
+    let key = DataKey::DonationEntry(project_id, index);
+    env.storage()
+        .persistent()
+        .set(&key, &(donator.clone(), token.clone()));
+    bump_persistent(env, &key);
+}
+
+/// Read the (donator, token) pair at `index`, or `None` if it was never recorded.
+pub fn get_donation_entry(env: &Env, project_id: u64, index: u32) -> Option<(Address, Address)> {
+    let key = DataKey::DonationEntry(project_id, index);
+    let entry: Option<(Address, Address)> = env.storage().persistent().get(&key);
+    if entry.is_some() {
+        bump_persistent(env, &key);
+    }
+    entry
+}
+
+/// Retrieve the next donation index `refund_all` should process.
+pub fn get_refund_cursor(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundCursor(project_id))
+        .unwrap_or(0)
+}
+
+/// Persist the next donation index `refund_all` should process.
+pub fn set_refund_cursor(env: &Env, project_id: u64, cursor: u32) {
+    let key = DataKey::RefundCursor(project_id);
+    env.storage().persistent().set(&key, &cursor);
+    bump_persistent(env, &key);
+}
+
+// ── Verification Threshold Helpers ───────────────────────────────────
+
+/// Retrieve the number of oracle approvals required to release `project_id`.
+/// Defaults to 1, i.e. a single oracle verification releases funds.
+pub fn get_verification_threshold(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VerifyThreshold(project_id))
+        .unwrap_or(1)
+}
+
+/// Set the number of oracle approvals required to release `project_id`.
+pub fn set_verification_threshold(env: &Env, project_id: u64, threshold: u32) {
+    let key = DataKey::VerifyThreshold(project_id);
+    env.storage().persistent().set(&key, &threshold);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve the oracles that have approved `project_id` so far.
+pub fn get_approvals(env: &Env, project_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Approvals(project_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Persist the oracles that have approved `project_id` so far.
+pub fn set_approvals(env: &Env, project_id: u64, approvals: &Vec<Address>) {
+    let key = DataKey::Approvals(project_id);
+    env.storage().persistent().set(&key, approvals);
+    bump_persistent(env, &key);
+}
+
+/// Seconds an approval of `project_id` stays valid; `0` means forever.
+pub fn get_verification_validity(env: &Env, project_id: u64) -> u64 {
+    let key = DataKey::VerifyValidity(project_id);
+    match env.storage().persistent().get(&key) {
+        Some(secs) => {
+            bump_persistent(env, &key);
+            secs
+        }
+        None => 0,
+    }
+}
+
+/// Set how long an approval of `project_id` stays valid; `0` removes the limit.
+pub fn set_verification_validity(env: &Env, project_id: u64, secs: u64) {
+    let key = DataKey::VerifyValidity(project_id);
+    if secs == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &secs);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Ledger timestamp at which `oracle` approved `project_id`, if recorded.
+pub fn get_approved_at(env: &Env, project_id: u64, oracle: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ApprovedAt(project_id, oracle.clone()))
+}
+
+/// Record the ledger timestamp of `oracle`'s approval of `project_id`.
+pub fn set_approved_at(env: &Env, project_id: u64, oracle: &Address, timestamp: u64) {
+    let key = DataKey::ApprovedAt(project_id, oracle.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+// ── Price Helpers ────────────────────────────────────────────────────
+
+/// Retrieve the oracle-set price of `token` for `project_id`, if any.
+pub fn get_token_price(env: &Env, project_id: u64, token: &Address) -> Option<i128> {
+    let key = DataKey::TokenPrice(project_id, token.clone());
+    let price: Option<i128> = env.storage().persistent().get(&key);
+    if price.is_some() {
+        bump_persistent(env, &key);
+    }
+    price
+}
+
+/// Store the price of `token` for `project_id`.
+pub fn set_token_price(env: &Env, project_id: u64, token: &Address, price: i128) {
+    let key = DataKey::TokenPrice(project_id, token.clone());
+    env.storage().persistent().set(&key, &price);
+    bump_persistent(env, &key);
+}
+
+// ── Milestone Helpers ────────────────────────────────────────────────
+
+/// Retrieve a project's cumulative milestone targets, if it was registered
+/// with milestones.
+pub fn get_milestones(env: &Env, project_id: u64) -> Option<Vec<i128>> {
+    let key = DataKey::Milestones(project_id);
+    let milestones: Option<Vec<i128>> = env.storage().persistent().get(&key);
+    if milestones.is_some() {
+        bump_persistent(env, &key);
+    }
+    milestones
+}
+
+/// Store a project's cumulative milestone targets.
+pub fn set_milestones(env: &Env, project_id: u64, milestones: &Vec<i128>) {
+    let key = DataKey::Milestones(project_id);
+    env.storage().persistent().set(&key, milestones);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve the per-milestone proof hashes of `project_id`, if it was
+/// registered with one per milestone.
+pub fn get_milestone_proofs(env: &Env, project_id: u64) -> Option<Vec<BytesN<32>>> {
+    let key = DataKey::MilestoneProofs(project_id);
+    let proofs: Option<Vec<BytesN<32>>> = env.storage().persistent().get(&key);
+    if proofs.is_some() {
+        bump_persistent(env, &key);
+    }
+    proofs
+}
+
+/// Persist the proof hash committed for each milestone of `project_id`.
+pub fn set_milestone_proofs(env: &Env, project_id: u64, proofs: &Vec<BytesN<32>>) {
+    let key = DataKey::MilestoneProofs(project_id);
+    env.storage().persistent().set(&key, proofs);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve how many milestones have been released for `project_id`.
+pub fn get_released_milestones(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MilestonesReleased(project_id))
+        .unwrap_or(0)
+}
+
+/// Record how many milestones have been released for `project_id`.
+pub fn set_released_milestones(env: &Env, project_id: u64, released: u32) {
+    let key = DataKey::MilestonesReleased(project_id);
+    env.storage().persistent().set(&key, &released);
+    bump_persistent(env, &key);
+}
+
+// ── Recurring Deposit Helpers ────────────────────────────────────────
+
+/// Pending recurring deposit schedules for `project_id`.
+pub fn get_recurring(env: &Env, project_id: u64) -> Vec<RecurringSchedule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recurring(project_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the schedules for `project_id`, removing the entry once none remain.
+pub fn set_recurring(env: &Env, project_id: u64, schedules: &Vec<RecurringSchedule>) {
+    let key = DataKey::Recurring(project_id);
+    if schedules.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, schedules);
+    bump_persistent(env, &key);
+}
+
+// ── Status History Helpers ───────────────────────────────────────────
+
+/// Status transitions kept per project. The longest forward path is
+/// Funding → Active → PendingRelease → Completed; the rest leaves room for
+/// releases cancelled during their challenge period.
+const MAX_STATUS_HISTORY: u32 = 8;
+
+/// Status transitions recorded for `project_id`, oldest first.
+pub fn get_status_history(env: &Env, project_id: u64) -> Vec<(ProjectStatus, u64)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusHistory(project_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append `status` at the current ledger timestamp.
+///
+/// The log holds at most `MAX_STATUS_HISTORY` entries. Once full, the
+/// newest entry is overwritten instead, so the log keeps the earliest
+/// transitions and always ends with the current status.
+pub fn record_status(env: &Env, project_id: u64, status: ProjectStatus) {
+    let key = DataKey::StatusHistory(project_id);
+    let mut history = get_status_history(env, project_id);
+    if history.len() >= MAX_STATUS_HISTORY {
+        history.pop_back();
+    }
+    history.push_back((status, env.ledger().timestamp()));
+    env.storage().persistent().set(&key, &history);
+    bump_persistent(env, &key);
+}
+
+// ── Recent Action Helpers ────────────────────────────────────────────
+
+/// Entries kept in the recent-action log.
+const MAX_RECENT_ACTIONS: u32 = 32;
+
+/// The latest logged actions across all projects, oldest first.
+pub fn get_recent_actions(env: &Env) -> Vec<RecentAction> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecentActions)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Log `action` on `project_id` at the current ledger timestamp, dropping
+/// the oldest entry once `MAX_RECENT_ACTIONS` are held.
+pub fn push_recent_action(env: &Env, action: Symbol, project_id: u64) {
+    let key = DataKey::RecentActions;
+    let mut actions = get_recent_actions(env);
+    if actions.len() >= MAX_RECENT_ACTIONS {
+        actions.pop_front();
+    }
+    actions.push_back(RecentAction {
+        action,
+        project_id,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&key, &actions);
+    bump_persistent(env, &key);
+}
+
+// ── Proof Index Helpers ──────────────────────────────────────────────
+
+/// ID of the first project registered with `proof_hash`, if any.
+pub fn get_proof_owner(env: &Env, proof_hash: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProofOwner(proof_hash.clone()))
+}
+
+/// Index `proof_hash` as belonging to `project_id`.
+pub fn set_proof_owner(env: &Env, proof_hash: &BytesN<32>, project_id: u64) {
+    let key = DataKey::ProofOwner(proof_hash.clone());
+    env.storage().persistent().set(&key, &project_id);
+    bump_persistent(env, &key);
+}
+
+// ── Project Handle Helpers ───────────────────────────────────────────
+
+/// ID of the project registered under `handle`, if any.
+pub fn get_handle_owner(env: &Env, handle: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HandleOwner(handle.clone()))
+}
+
+/// Deterministic handle `project_id` was registered under, if any.
+pub fn get_project_handle(env: &Env, project_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProjectHandle(project_id))
+}
+
+/// Index `project_id` under `handle`, in both directions.
+pub fn set_project_handle(env: &Env, project_id: u64, handle: &BytesN<32>) {
+    let owner_key = DataKey::HandleOwner(handle.clone());
+    env.storage().persistent().set(&owner_key, &project_id);
+    bump_persistent(env, &owner_key);
+
+    let handle_key = DataKey::ProjectHandle(project_id);
+    env.storage().persistent().set(&handle_key, handle);
+    bump_persistent(env, &handle_key);
+}
+
+// ── Partial Release Helpers ──────────────────────────────────────────
+
+/// Return true if `project_id` may be released before reaching its goal.
+pub fn is_partial_release_allowed(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartialRelease(project_id))
+        .unwrap_or(false)
+}
+
+/// Allow or forbid releasing `project_id` before it reaches its goal.
+pub fn set_partial_release_allowed(env: &Env, project_id: u64, allowed: bool) {
+    let key = DataKey::PartialRelease(project_id);
+    env.storage().persistent().set(&key, &allowed);
+    bump_persistent(env, &key);
+}
+
+// ── Project Oracle Helpers ───────────────────────────────────────────
+
+/// Oracles assigned to `project_id`; empty when the project relies on the
+/// global Oracle role.
+pub fn get_project_oracles(env: &Env, project_id: u64) -> Vec<Address> {
+    let key = DataKey::ProjectOracles(project_id);
+    match env.storage().persistent().get(&key) {
+        Some(oracles) => {
+            bump_persistent(env, &key);
+            oracles
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Assign `oracle` to `project_id`. No-op if it is already assigned.
+pub fn add_project_oracle(env: &Env, project_id: u64, oracle: &Address) {
+    let key = DataKey::ProjectOracles(project_id);
+    let mut oracles = get_project_oracles(env, project_id);
+    if oracles.contains(oracle) {
+        return;
+    }
+    oracles.push_back(oracle.clone());
+    env.storage().persistent().set(&key, &oracles);
+    bump_persistent(env, &key);
+}
+
+/// Unassign `oracle` from `project_id`. Returns `false` if it was not
+/// assigned. The entry is deleted once the last oracle is removed.
+pub fn remove_project_oracle(env: &Env, project_id: u64, oracle: &Address) -> bool {
+    let key = DataKey::ProjectOracles(project_id);
+    let mut oracles = get_project_oracles(env, project_id);
+    let Some(index) = oracles.first_index_of(oracle) else {
+        return false;
+    };
+    oracles.remove(index);
+    if oracles.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &oracles);
+        bump_persistent(env, &key);
+    }
+    true
+}
+
+// ── Release Threshold Helpers ────────────────────────────────────────
+
+/// Share of the goal, in basis points, that moves `project_id` to `Active`.
+/// Defaults to 10 000, i.e. the full goal.
+pub fn get_release_threshold_bps(env: &Env, project_id: u64) -> u32 {
+    let key = DataKey::ReleaseThreshold(project_id);
+    match env.storage().persistent().get(&key) {
+        Some(bps) => {
+            bump_persistent(env, &key);
+            bps
+        }
+        None => 10_000,
+    }
+}
+
+/// Set the share of the goal, in basis points, that activates `project_id`.
+pub fn set_release_threshold_bps(env: &Env, project_id: u64, bps: u32) {
+    let key = DataKey::ReleaseThreshold(project_id);
+    env.storage().persistent().set(&key, &bps);
+    bump_persistent(env, &key);
+}
+
+// ── Donor Cap Helpers ────────────────────────────────────────────────
+
+/// Most a single donor may contribute to `project_id` in any one token,
+/// if capped.
+pub fn get_donor_cap(env: &Env, project_id: u64) -> Option<i128> {
+    let key = DataKey::DonorCap(project_id);
+    let cap = env.storage().persistent().get(&key);
+    if cap.is_some() {
+        bump_persistent(env, &key);
+    }
+    cap
+}
+
+/// Memo `donator` attached to their latest memo deposit to `project_id`.
+pub fn get_deposit_memo(env: &Env, project_id: u64, donator: &Address) -> Option<Symbol> {
+    let key = DataKey::DepositMemo(project_id, donator.clone());
+    let memo = env.storage().persistent().get(&key);
+    if memo.is_some() {
+        bump_persistent(env, &key);
+    }
+    memo
+}
+
+/// Record the memo of `donator`'s latest memo deposit to `project_id`.
+pub fn set_deposit_memo(env: &Env, project_id: u64, donator: &Address, memo: &Symbol) {
+    let key = DataKey::DepositMemo(project_id, donator.clone());
+    env.storage().persistent().set(&key, memo);
+    bump_persistent(env, &key);
+}
+
+/// Set or, with `None`, lift the per-donor cap of `project_id`.
+pub fn set_donor_cap(env: &Env, project_id: u64, cap: Option<i128>) {
+    let key = DataKey::DonorCap(project_id);
+    match cap {
+        Some(cap) => {
+            env.storage().persistent().set(&key, &cap);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ── Pending Release Helpers ──────────────────────────────────────────
+
+/// When the pending release of `project_id` may be finalized, if any.
+pub fn get_release_unlock_at(env: &Env, project_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReleaseUnlockAt(project_id))
+}
+
+/// Set or, with `None`, clear when `project_id`'s release may be finalized.
+pub fn set_release_unlock_at(env: &Env, project_id: u64, unlock_at: Option<u64>) {
+    let key = DataKey::ReleaseUnlockAt(project_id);
+    match unlock_at {
+        Some(at) => {
+            env.storage().persistent().set(&key, &at);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Release transfers of `project_id` still waiting for `retry_release`.
+pub fn get_unpaid_payouts(env: &Env, project_id: u64) -> Vec<PendingPayout> {
+    env.storage()
+        .persistent()
+        .get(&ProjectKey::UnpaidPayouts(project_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the unpaid release transfers of `project_id`, dropping the key
+/// once none are left.
+pub fn set_unpaid_payouts(env: &Env, project_id: u64, payouts: &Vec<PendingPayout>) {
+    let key = ProjectKey::UnpaidPayouts(project_id);
+    let persistent = env.storage().persistent();
+    if payouts.is_empty() {
+        persistent.remove(&key);
+        return;
+    }
+    persistent.set(&key, payouts);
+    persistent.extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Impact metric last reported for `project_id`; `0` if none was.
+pub fn get_reported_impact(env: &Env, project_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ProjectKey::Impact(project_id))
+        .unwrap_or(0)
+}
+
+/// Record the impact metric of `project_id`.
+pub fn set_reported_impact(env: &Env, project_id: u64, value: i128) {
+    let key = ProjectKey::Impact(project_id);
+    let persistent = env.storage().persistent();
+    persistent.set(&key, &value);
+    persistent.extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Sum of donor balances of `token` that `project_id` still owes refunds
+/// against, recorded when its first milestone was paid; `0` before then.
+pub fn get_refund_claims(env: &Env, project_id: u64, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ProjectKey::RefundClaims(project_id, token.clone()))
+        .unwrap_or(0)
+}
+
+/// Record the unrefunded donor balances of `token` for `project_id`.
+pub fn set_refund_claims(env: &Env, project_id: u64, token: &Address, claims: i128) {
+    let key = ProjectKey::RefundClaims(project_id, token.clone());
+    let persistent = env.storage().persistent();
+    persistent.set(&key, &claims);
+    persistent.extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// ── Sweep Helpers ────────────────────────────────────────────────────
+
+/// Return true once `project_id`'s unrefunded balances went to the treasury.
+pub fn is_swept(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Swept(project_id))
+        .unwrap_or(false)
+}
+
+/// Record that `project_id`'s unrefunded balances were swept.
+pub fn set_swept(env: &Env, project_id: u64) {
+    let key = DataKey::Swept(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+// ── Creator Track Record Helpers ─────────────────────────────────────
+
+/// Number of `creator`'s projects that reached `Completed`.
+pub fn get_completed_count(env: &Env, creator: &Address) -> u32 {
+    let key = DataKey::CompletedCount(creator.clone());
+    match env.storage().persistent().get(&key) {
+        Some(count) => {
+            bump_persistent(env, &key);
+            count
+        }
+        None => 0,
+    }
+}
+
+/// Count one more completed project for `creator`.
+pub fn increment_completed_count(env: &Env, creator: &Address) {
+    let key = DataKey::CompletedCount(creator.clone());
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &count.saturating_add(1));
+    bump_persistent(env, &key);
+}
+
+/// Number of `creator`'s projects in a non-terminal status.
+pub fn get_active_count(env: &Env, creator: &Address) -> u32 {
+    let key = DataKey::ActiveCount(creator.clone());
+    match env.storage().persistent().get(&key) {
+        Some(count) => {
+            bump_persistent(env, &key);
+            count
+        }
+        None => 0,
+    }
+}
+
+/// Set the number of `creator`'s projects in a non-terminal status.
+pub fn set_active_count(env: &Env, creator: &Address, count: u32) {
+    let key = DataKey::ActiveCount(creator.clone());
+    if count == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &count);
+        bump_persistent(env, &key);
+    }
+}
+
+// ── Deposit Nonce Helpers ────────────────────────────────────────────
+
+/// Highest nonce `donator` has used with `deposit_with_nonce`, if any.
+pub fn get_last_nonce(env: &Env, donator: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::DepositNonce(donator.clone());
+    let nonce = env.storage().persistent().get(&key);
+    if nonce.is_some() {
+        bump_persistent(env, &key);
+    }
+    nonce
+}
+
+/// Record `nonce` as the highest `donator` has used.
+pub fn set_last_nonce(env: &Env, donator: &Address, nonce: &BytesN<32>) {
+    let key = DataKey::DepositNonce(donator.clone());
+    env.storage().persistent().set(&key, nonce);
+    bump_persistent(env, &key);
+}
+
+// ── Beneficiary Helpers ──────────────────────────────────────────────
+
+/// Recipients of `project_id`'s released funds with their shares in basis
+/// points, if the creator split the payout.
+pub fn get_beneficiaries(env: &Env, project_id: u64) -> Option<Vec<(Address, u32)>> {
+    let key = DataKey::Beneficiaries(project_id);
+    let beneficiaries = env.storage().persistent().get(&key);
+    if beneficiaries.is_some() {
+        bump_persistent(env, &key);
+    }
+    beneficiaries
+}
+
+/// Persist the payout split of `project_id`.
+pub fn set_beneficiaries(env: &Env, project_id: u64, beneficiaries: &Vec<(Address, u32)>) {
+    let key = DataKey::Beneficiaries(project_id);
+    env.storage().persistent().set(&key, beneficiaries);
+    bump_persistent(env, &key);
+}
+
+// ── Donor Allowlist Helpers ──────────────────────────────────────────
+
+/// Return true if `project_id` only accepts deposits from listed donors.
+pub fn is_allowlist_on(env: &Env, project_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowlistOn(project_id))
+        .unwrap_or(false)
+}
+
+/// Restrict `project_id` to listed donors.
+pub fn set_allowlist_on(env: &Env, project_id: u64) {
+    let key = DataKey::AllowlistOn(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Return true if `donor` is on `project_id`'s allowlist.
+pub fn is_listed_donor(env: &Env, project_id: u64, donor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AllowedDonor(project_id, donor.clone()))
+}
+
+/// Add `donor` to `project_id`'s allowlist.
+pub fn add_listed_donor(env: &Env, project_id: u64, donor: &Address) {
+    let key = DataKey::AllowedDonor(project_id, donor.clone());
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+// ── Account Index Helpers ────────────────────────────────────────────
+
+fn get_id_list(env: &Env, key: &DataKey) -> Vec<u64> {
+    match env.storage().persistent().get(key) {
+        Some(ids) => {
+            bump_persistent(env, key);
+            ids
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Append `project_id` unless the list already holds it or is full.
+fn push_capped(env: &Env, key: &DataKey, project_id: u64) {
+    let mut ids = get_id_list(env, key);
+    if ids.len() >= crate::MAX_ACCOUNT_PROJECTS || ids.contains(project_id) {
+        return;
+    }
+    ids.push_back(project_id);
+    env.storage().persistent().set(key, &ids);
+    bump_persistent(env, key);
+}
+
+/// IDs of the projects registered by `creator`, oldest first.
+pub fn get_creator_projects(env: &Env, creator: &Address) -> Vec<u64> {
+    get_id_list(env, &DataKey::CreatorProjects(creator.clone()))
+}
+
+/// Record that `creator` registered `project_id`.
+pub fn add_creator_project(env: &Env, creator: &Address, project_id: u64) {
+    push_capped(env, &DataKey::CreatorProjects(creator.clone()), project_id);
+}
+
+/// IDs of the projects `donor` has deposited to, in first-deposit order.
+pub fn get_donor_projects(env: &Env, donor: &Address) -> Vec<u64> {
+    get_id_list(env, &DataKey::DonorProjects(donor.clone()))
+}
+
+/// Record that `donor` deposited to `project_id`.
+pub fn add_donor_project(env: &Env, donor: &Address, project_id: u64) {
+    push_capped(env, &DataKey::DonorProjects(donor.clone()), project_id);
+}
+
+// ── Vesting Helpers ──────────────────────────────────────────────────
+
+/// Vesting period of `project_id`, or `None` if it pays out immediately.
+pub fn get_vesting_duration(env: &Env, project_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VestingDuration(project_id))
+}
+
+/// Make `project_id` vest its released funds over `secs` seconds.
+pub fn set_vesting_duration(env: &Env, project_id: u64, secs: u64) {
+    let key = DataKey::VestingDuration(project_id);
+    env.storage().persistent().set(&key, &secs);
+    bump_persistent(env, &key);
+}
+
+/// Ledger timestamp at which `project_id` started vesting, if it has.
+pub fn get_vesting_start(env: &Env, project_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VestingStart(project_id))
+}
+
+/// Record that `project_id` started vesting at `timestamp`.
+pub fn set_vesting_start(env: &Env, project_id: u64, timestamp: u64) {
+    let key = DataKey::VestingStart(project_id);
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+/// Vesting progress of `token` in `project_id`, if any was granted.
+pub fn get_vesting_grant(env: &Env, project_id: u64, token: &Address) -> Option<VestingGrant> {
+    let key = DataKey::Vesting(project_id, token.clone());
+    let grant = env.storage().persistent().get(&key);
+    if grant.is_some() {
+        bump_persistent(env, &key);
+    }
+    grant
+}
+
+/// Store the vesting progress of `token` in `project_id`.
+pub fn set_vesting_grant(env: &Env, project_id: u64, token: &Address, grant: &VestingGrant) {
+    let key = DataKey::Vesting(project_id, token.clone());
+    env.storage().persistent().set(&key, grant);
+    bump_persistent(env, &key);
+}
+
+// ─────────────────────────────────────────────────────────
+// Matching pools
+// ─────────────────────────────────────────────────────────
+
+/// Match paid per unit raised at release, in basis points; defaults to
+/// 10_000 (1:1).
+pub fn get_match_ratio_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MatchRatioBps)
+        .unwrap_or(10_000)
+}
+
+/// Set the match paid per unit raised, in basis points.
+pub fn set_match_ratio_bps(env: &Env, bps: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MatchRatioBps, &bps);
+}
+
+/// The matching pool funded for `token` in `project_id`, if any.
+pub fn get_match_pool(env: &Env, project_id: u64, token: &Address) -> Option<MatchPool> {
+    let key = DataKey::MatchPool(project_id, token.clone());
+    let pool = env.storage().persistent().get(&key);
+    if pool.is_some() {
+        bump_persistent(env, &key);
+    }
+    pool
+}
+
+/// Store the matching pool for `token` in `project_id`, keeping
+/// `TotalPooled` in step.
+pub fn set_match_pool(env: &Env, project_id: u64, token: &Address, pool: &MatchPool) {
+    let previous = get_match_pool(env, project_id, token).map_or(0, |p| p.balance);
+    adjust_total_pooled(env, token, pool.balance - previous);
+    let key = DataKey::MatchPool(project_id, token.clone());
+    env.storage().persistent().set(&key, pool);
+    bump_persistent(env, &key);
+}
+
+/// Drop the matching pool for `token` in `project_id` once it is paid out.
+pub fn remove_match_pool(env: &Env, project_id: u64, token: &Address) {
+    if let Some(pool) = get_match_pool(env, project_id, token) {
+        adjust_total_pooled(env, token, -pool.balance);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MatchPool(project_id, token.clone()));
+}
+
+/// Sum of every project's matching pool held in `token`.
+pub fn get_total_pooled(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::TotalPooled(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `delta` (possibly negative) to the total pooled in `token`.
+fn adjust_total_pooled(env: &Env, token: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let total = get_total_pooled(env, token)
+        .checked_add(delta)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+    bump_instance(env);
+    let key = ConfigKey::TotalPooled(token.clone());
+    if total == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &total);
+    }
+}
+
+// ─────────────────────────────────────────────────────────
+// Oracle bonds
+// ─────────────────────────────────────────────────────────
+
+/// Token oracles bond in and the minimum stake required to verify, if set.
+pub fn get_oracle_bond(env: &Env) -> Option<(Address, i128)> {
+    env.storage().instance().get(&ConfigKey::OracleBond)
+}
+
+/// Set the bond token and minimum stake required to verify.
+pub fn set_oracle_bond(env: &Env, token: &Address, min_stake: i128) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::OracleBond, &(token.clone(), min_stake));
+}
+
+/// When `oracle` last approved any release, if it ever did.
+pub fn get_oracle_approved_at(env: &Env, oracle: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&AccountKey::OracleApprovedAt(oracle.clone()))
+}
+
+/// Record that `oracle` approved a release at `timestamp`.
+pub fn set_oracle_approved_at(env: &Env, oracle: &Address, timestamp: u64) {
+    let key = AccountKey::OracleApprovedAt(oracle.clone());
+    let persistent = env.storage().persistent();
+    persistent.set(&key, &timestamp);
+    persistent.extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Bond `oracle` has staked in `token`; `0` if none.
+pub fn get_oracle_stake(env: &Env, oracle: &Address, token: &Address) -> i128 {
+    let key = DataKey::OracleStake(oracle.clone(), token.clone());
+    let stake = env.storage().persistent().get(&key);
+    if stake.is_some() {
+        bump_persistent(env, &key);
+    }
+    stake.unwrap_or(0)
+}
+
+/// Store `oracle`'s bond in `token`, keeping `TotalStaked` in step.
+pub fn set_oracle_stake(env: &Env, oracle: &Address, token: &Address, stake: i128) {
+    let key = DataKey::OracleStake(oracle.clone(), token.clone());
+    let previous = get_oracle_stake(env, oracle, token);
+    let total = get_total_staked(env, token)
+        .checked_add(stake - previous)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+
+    bump_instance(env);
+    let total_key = DataKey::TotalStaked(token.clone());
+    if total == 0 {
+        env.storage().instance().remove(&total_key);
+    } else {
+        env.storage().instance().set(&total_key, &total);
+    }
+    if stake == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &stake);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Sum of every oracle's bond held in `token`.
+pub fn get_total_staked(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalStaked(token.clone()))
+        .unwrap_or(0)
+}
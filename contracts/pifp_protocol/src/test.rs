@@ -1,6 +1,6 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, ProjectStatus, Role};
+use crate::{test_utils::TestContext, Error, ProjectStatus, Role};
 use soroban_sdk::Vec;
 
 #[test]
@@ -52,7 +52,7 @@ fn test_register_zero_goal_fails() {
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #13)")]
+#[should_panic(expected = "HostError: Error(Contract, #37)")]
 fn test_register_past_deadline_fails() {
     let ctx = TestContext::new();
     let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
@@ -68,6 +68,8 @@ fn test_register_past_deadline_fails() {
         &1000,
         &ctx.dummy_proof(),
         &past_deadline,
+        &ctx.dummy_metadata(),
+        &None,
     );
 }
 
@@ -81,18 +83,33 @@ fn test_deposit_zero_amount_fails() {
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[should_panic(expected = "HostError: Error(Contract, #29)")]
 fn test_deposit_after_deadline_fails() {
     let ctx = TestContext::new();
     let (project, token, _) = ctx.setup_project(1000);
 
     // Fast-forward time
-    ctx.jump_time(project.deadline + 1);
+    ctx.advance_past_deadline(project.id);
 
     ctx.client
         .deposit(&project.id, &ctx.admin, &token.address, &100i128);
 }
 
+#[test]
+fn test_deposit_at_deadline_succeeds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    // Land exactly on the deadline: still inside the funding window.
+    ctx.jump_time(project.deadline - ctx.env.ledger().timestamp());
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
 #[test]
 fn test_admin_can_pause_and_unpause() {
     let ctx = TestContext::new();
@@ -161,6 +178,17 @@ fn test_deposit_fails_when_paused() {
         .deposit(&project.id, &ctx.manager, &token.address, &100i128);
 }
 
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+fn test_verify_fails_when_paused() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.pause(&ctx.admin);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+}
+
 #[test]
 fn test_queries_work_when_paused() {
     let ctx = TestContext::new();
@@ -172,3 +200,100 @@ fn test_queries_work_when_paused() {
     let loaded = ctx.client.get_project(&project.id);
     assert_eq!(loaded.id, project.id);
 }
+
+#[test]
+fn test_min_deposit_defaults_to_zero() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(project.min_deposit, 0);
+    assert_eq!(ctx.client.get_project(&project.id).min_deposit, 0);
+}
+
+#[test]
+fn test_deposit_at_min_deposit_succeeds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    ctx.client.set_min_deposit(&ctx.manager, &project.id, &50);
+    assert_eq!(ctx.client.get_project(&project.id).min_deposit, 50);
+
+    sac.mint(&donator, &50);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &50);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 50);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_deposit_below_min_deposit_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    ctx.client.set_min_deposit(&ctx.manager, &project.id, &50);
+
+    sac.mint(&donator, &49);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &49);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_creator_cannot_set_min_deposit() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.set_min_deposit(&ctx.admin, &project.id, &50);
+}
+
+#[test]
+fn test_verify_underfunded_project_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    let result = ctx
+        .client
+        .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(result, Err(Ok(Error::GoalNotMet.into())));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+}
+
+#[test]
+fn test_partial_release_pays_out_what_was_raised() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    ctx.client
+        .set_allow_partial_release(&ctx.manager, &project.id, &true);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 400);
+}
+
+#[test]
+fn test_only_creator_allows_partial_release() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let result =
+        ctx.client
+            .try_set_allow_partial_release(&ctx.generate_address(), &project.id, &true);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized.into())));
+}
@@ -0,0 +1,69 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, Error};
+
+#[test]
+fn test_projects_are_open_by_default() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    assert!(ctx.client.is_donor_allowed(&project.id, &donator));
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+fn test_allowlist_blocks_unlisted_donors() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let listed = ctx.generate_address();
+    let stranger = ctx.generate_address();
+    sac.mint(&listed, &100);
+    sac.mint(&stranger, &100);
+
+    ctx.client.enable_allowlist(&ctx.manager, &project.id);
+    ctx.client
+        .add_allowed_donor(&ctx.manager, &project.id, &listed);
+
+    assert!(ctx.client.is_donor_allowed(&project.id, &listed));
+    assert!(!ctx.client.is_donor_allowed(&project.id, &stranger));
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &stranger, &token.address, &100),
+        Err(Ok(Error::DonorNotAllowed.into()))
+    );
+    ctx.client
+        .deposit(&project.id, &listed, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_allowlist_is_per_project() {
+    let ctx = TestContext::new();
+    let (gated, _, _) = ctx.setup_project(1000);
+    let (open, _, _) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    ctx.client.enable_allowlist(&ctx.manager, &gated.id);
+    assert!(!ctx.client.is_donor_allowed(&gated.id, &donator));
+    assert!(ctx.client.is_donor_allowed(&open.id, &donator));
+}
+
+#[test]
+fn test_only_creator_manages_allowlist() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let outsider = ctx.generate_address();
+
+    assert_eq!(
+        ctx.client.try_enable_allowlist(&outsider, &project.id),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_add_allowed_donor(&outsider, &project.id, &outsider),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
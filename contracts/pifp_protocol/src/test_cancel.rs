@@ -0,0 +1,98 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::ProjectCancelled;
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_cancel_project_sets_status_and_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("cancelled").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: ProjectCancelled = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        ProjectCancelled {
+            project_id: project.id,
+            creator: ctx.manager.clone(),
+        }
+    );
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_donor_refunded_after_cancel() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    ctx.client
+        .refund_deposit(&project.id, &donator, &token.address, &None);
+
+    assert_eq!(token.balance(&donator), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_cannot_deposit_to_cancelled_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_cannot_verify_cancelled_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cannot_cancel_active_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_creator_cannot_cancel() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.admin, &project.id);
+}
@@ -0,0 +1,54 @@
+extern crate std;
+
+use soroban_sdk::{Symbol, Vec};
+
+use crate::{test_utils::TestContext, Project, ProjectMetadata};
+
+fn register_in(ctx: &TestContext, category: &str, goal: i128) -> Project {
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &goal,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ProjectMetadata {
+            name: ctx.dummy_name(),
+            metadata_uri: ctx.dummy_metadata_uri(),
+            category: Symbol::new(&ctx.env, category),
+        },
+        &None,
+    )
+}
+
+#[test]
+fn test_list_projects_by_category_filters_and_pages() {
+    let ctx = TestContext::new();
+    register_in(&ctx, "health", 100);
+    register_in(&ctx, "water", 200);
+    register_in(&ctx, "health", 300);
+    register_in(&ctx, "health", 400);
+
+    let health = Symbol::new(&ctx.env, "health");
+    let all = ctx.client.list_projects_by_category(&health, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().goal, 100);
+    assert_eq!(all.get(1).unwrap().goal, 300);
+    assert_eq!(all.get(2).unwrap().category, health);
+
+    let page = ctx.client.list_projects_by_category(&health, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 2);
+}
+
+#[test]
+fn test_unknown_category_is_empty() {
+    let ctx = TestContext::new();
+    register_in(&ctx, "health", 100);
+
+    let result = ctx
+        .client
+        .list_projects_by_category(&Symbol::new(&ctx.env, "unknown"), &0, &10);
+    assert_eq!(result.len(), 0);
+}
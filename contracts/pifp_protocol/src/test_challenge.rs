@@ -0,0 +1,135 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal};
+
+use crate::{test_utils::TestContext, Error, ProjectStatus};
+
+#[test]
+fn test_release_waits_for_challenge_period() {
+    let ctx = TestContext::new();
+    ctx.client.set_challenge_period(&ctx.admin, &3600);
+    let (project, token) = ctx.setup_funded_project(1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    let unlock_at = ctx.env.ledger().timestamp() + 3600;
+    let last_event = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        vec![
+            &ctx.env,
+            symbol_short!("rel_pend").into_val(&ctx.env),
+            project.id.into_val(&ctx.env),
+        ]
+    );
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PendingRelease
+    );
+    assert_eq!(
+        ctx.client.get_release_unlock_at(&project.id),
+        Some(unlock_at)
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(
+        ctx.client.try_finalize_release(&project.id),
+        Err(Ok(Error::WindowNotElapsed.into()))
+    );
+
+    ctx.jump_time(3600);
+    ctx.client.finalize_release(&project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_release_unlock_at(&project.id), None);
+}
+
+#[test]
+fn test_cancel_release_requires_reverification() {
+    let ctx = TestContext::new();
+    ctx.client.set_challenge_period(&ctx.admin, &3600);
+    let (project, token) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    ctx.client.cancel_release(&ctx.admin, &project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+    assert!(ctx.client.get_approvals(&project.id).is_empty());
+    assert_eq!(
+        ctx.client.try_finalize_release(&project.id),
+        Err(Ok(Error::InvalidTransition.into()))
+    );
+
+    // Lifting the challenge period lets a fresh verification pay out.
+    ctx.client.set_challenge_period(&ctx.admin, &0);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_cancel_release_only_during_window() {
+    let ctx = TestContext::new();
+    ctx.client.set_challenge_period(&ctx.admin, &3600);
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(
+        ctx.client.try_cancel_release(&ctx.manager, &project.id),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    ctx.jump_time(3600);
+    assert_eq!(
+        ctx.client.try_cancel_release(&ctx.admin, &project.id),
+        Err(Ok(Error::InvalidTransition.into()))
+    );
+}
+
+#[test]
+fn test_pending_release_rejects_deposits() {
+    let ctx = TestContext::new();
+    ctx.client.set_challenge_period(&ctx.admin, &3600);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &token.address, &500),
+        Err(Ok(Error::ProjectNotActive.into()))
+    );
+    assert_eq!(ctx.client.get_stats().pending_release, 1);
+}
+
+#[test]
+fn test_status_history_ends_with_current_status() {
+    let ctx = TestContext::new();
+    ctx.client.set_challenge_period(&ctx.admin, &3600);
+    let (project, _) = ctx.setup_funded_project(1000);
+
+    for _ in 0..4 {
+        ctx.client
+            .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+        ctx.client.cancel_release(&ctx.admin, &project.id);
+    }
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    ctx.jump_time(3600);
+    ctx.client.finalize_release(&project.id);
+
+    let history = ctx.client.get_status_history(&project.id);
+    assert_eq!(history.len(), 8);
+    assert_eq!(history.get(0).unwrap().0, ProjectStatus::Funding);
+    assert_eq!(history.last().unwrap().0, ProjectStatus::Completed);
+}
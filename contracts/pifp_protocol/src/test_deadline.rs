@@ -0,0 +1,229 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::DeadlineExtended;
+use crate::{test_utils::TestContext, DeadlineStatus, Error, ProjectStatus};
+
+#[test]
+fn test_extend_deadline_updates_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_deadline = project.deadline + 3600;
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &new_deadline);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("extended").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: DeadlineExtended = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        DeadlineExtended {
+            project_id: project.id,
+            old_deadline: project.deadline,
+            new_deadline,
+        }
+    );
+
+    assert_eq!(ctx.client.get_project(&project.id).deadline, new_deadline);
+}
+
+#[test]
+fn test_deposit_allowed_after_original_deadline_once_extended() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &(project.deadline + 3600));
+    ctx.jump_time(project.deadline - ctx.env.ledger().timestamp() + 1);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_extend_deadline_must_increase() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &project.deadline);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_creator_cannot_extend_deadline() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .extend_deadline(&ctx.admin, &project.id, &(project.deadline + 3600));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_cannot_extend_after_deadline_passed() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.advance_past_deadline(project.id);
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &(project.deadline + 3600));
+}
+
+fn try_register_with_deadline(ctx: &TestContext, deadline: u64) -> Result<(), soroban_sdk::Error> {
+    let tokens = vec![&ctx.env, ctx.generate_address()];
+    match ctx.client.try_register_project(
+        &ctx.manager,
+        &tokens,
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    ) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.unwrap()),
+    }
+}
+
+#[test]
+fn test_register_deadline_boundaries() {
+    let ctx = TestContext::new();
+    let now = ctx.env.ledger().timestamp();
+
+    assert_eq!(
+        try_register_with_deadline(&ctx, now - 1),
+        Err(Error::DeadlineInPast.into())
+    );
+    assert_eq!(
+        try_register_with_deadline(&ctx, now),
+        Err(Error::DeadlineInPast.into())
+    );
+    assert_eq!(try_register_with_deadline(&ctx, now + 1), Ok(()));
+}
+
+#[test]
+fn test_min_funding_window_enforced() {
+    let ctx = TestContext::new();
+    let now = ctx.env.ledger().timestamp();
+
+    ctx.client.set_min_funding_window(&ctx.admin, &3600);
+    assert_eq!(ctx.client.get_min_funding_window(), 3600);
+
+    assert_eq!(
+        try_register_with_deadline(&ctx, now + 3599),
+        Err(Error::InvalidDeadline.into())
+    );
+    assert_eq!(try_register_with_deadline(&ctx, now + 3600), Ok(()));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_admin_sets_min_funding_window() {
+    let ctx = TestContext::new();
+    ctx.client.set_min_funding_window(&ctx.manager, &3600);
+}
+
+#[test]
+fn test_grace_period_delays_expiry() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.client.set_grace_period(&ctx.admin, &3600);
+    assert_eq!(
+        ctx.client.get_effective_deadline(&project.id),
+        project.deadline + 3600
+    );
+
+    // Inside the grace window: still live and accepting deposits.
+    ctx.advance_past_deadline(project.id);
+    assert_eq!(
+        ctx.client.try_expire_project(&project.id),
+        Err(Ok(Error::ProjectNotExpired.into()))
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    // One second past the effective deadline.
+    ctx.jump_time(3600);
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &token.address, &1),
+        Err(Ok(Error::DeadlinePassed.into()))
+    );
+    ctx.client.expire_project(&project.id);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Expired
+    );
+}
+
+#[test]
+fn test_effective_deadline_defaults_to_deadline() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_grace_period(), 0);
+    assert_eq!(
+        ctx.client.get_effective_deadline(&project.id),
+        project.deadline
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_admin_sets_grace_period() {
+    let ctx = TestContext::new();
+    ctx.client.set_grace_period(&ctx.manager, &3600);
+}
+
+#[test]
+fn test_deadline_status_progression() {
+    let ctx = TestContext::new();
+    ctx.client.set_grace_period(&ctx.admin, &600);
+    // Registered with a deadline 24 hours out.
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &(project.deadline + 1));
+    assert_eq!(
+        ctx.client.get_deadline_status(&project.id),
+        DeadlineStatus::Upcoming
+    );
+
+    ctx.jump_time(1);
+    assert_eq!(
+        ctx.client.get_deadline_status(&project.id),
+        DeadlineStatus::Imminent
+    );
+
+    ctx.jump_time(86_400);
+    assert_eq!(
+        ctx.client.get_deadline_status(&project.id),
+        DeadlineStatus::Imminent
+    );
+
+    ctx.jump_time(1);
+    assert_eq!(
+        ctx.client.get_deadline_status(&project.id),
+        DeadlineStatus::InGracePeriod
+    );
+    assert!(ctx.client.try_expire_project(&project.id).is_err());
+
+    ctx.jump_time(600);
+    assert_eq!(
+        ctx.client.get_deadline_status(&project.id),
+        DeadlineStatus::Passed
+    );
+    ctx.client.expire_project(&project.id);
+}
@@ -1,5 +1,7 @@
 extern crate std;
 
+use soroban_sdk::Vec;
+
 use crate::test_utils::TestContext;
 
 #[test]
@@ -74,7 +76,11 @@ fn test_donation_count_increments_for_same_donor_different_tokens() {
         &10_000,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
     );
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token2.address, &10_000_000);
 
     let donator = ctx.generate_address();
     sac1.mint(&donator, &1_000);
@@ -102,7 +108,11 @@ fn test_donation_count_complex_scenario() {
         &10_000,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
     );
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token2.address, &10_000_000);
 
     let donator1 = ctx.generate_address();
     let donator2 = ctx.generate_address();
@@ -143,3 +153,68 @@ fn test_donation_count_complex_scenario() {
         .deposit(&project.id, &donator2, &token2.address, &100i128);
     assert_eq!(ctx.client.get_project(&project.id).donation_count, 5);
 }
+
+#[test]
+fn test_donor_count_counts_addresses_not_tokens() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token_b.address, &10_000_000);
+    assert_eq!(ctx.client.get_donor_count(&project.id), 0);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1_000);
+    sac_b.mint(&donator, &1_000);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &100);
+    assert_eq!(ctx.client.get_donor_count(&project.id), 1);
+    assert_eq!(ctx.client.get_project(&project.id).donor_count, 1);
+
+    let other = ctx.generate_address();
+    sac_a.mint(&other, &1_000);
+    ctx.client
+        .deposit(&project.id, &other, &token_a.address, &100);
+    assert_eq!(ctx.client.get_donor_count(&project.id), 2);
+}
+
+#[test]
+fn test_donor_count_drops_when_withdrawal_empties_all_tokens() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token_b.address, &10_000_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1_000);
+    sac_b.mint(&donator, &1_000);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &100);
+
+    // Partial and single-token withdrawals keep the donor counted.
+    ctx.client
+        .withdraw(&project.id, &donator, &token_a.address, &50);
+    ctx.client
+        .withdraw(&project.id, &donator, &token_a.address, &50);
+    assert_eq!(ctx.client.get_donor_count(&project.id), 1);
+
+    ctx.client
+        .withdraw(&project.id, &donator, &token_b.address, &100);
+    assert_eq!(ctx.client.get_donor_count(&project.id), 0);
+
+    // Coming back counts them again.
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &10);
+    assert_eq!(ctx.client.get_donor_count(&project.id), 1);
+}
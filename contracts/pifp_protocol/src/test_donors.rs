@@ -0,0 +1,537 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, BytesN, IntoVal, Symbol, TryIntoVal, Vec};
+
+use crate::{
+    events::{DepositMemo, NonceReplayed, RelayedDeposit},
+    test_utils::TestContext,
+    DonorBalance, Error, NonceOutcome,
+};
+
+#[test]
+fn test_donor_contribution_lists_each_token() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token_b.address, &10_000_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &300);
+    sac_b.mint(&donator, &700);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &700);
+    ctx.client
+        .withdraw(&project.id, &donator, &token_b.address, &200);
+
+    let contribution = ctx.client.get_donor_contribution(&project.id, &donator);
+    assert_eq!(
+        contribution,
+        Vec::from_array(
+            &ctx.env,
+            [
+                DonorBalance {
+                    token: token_a.address.clone(),
+                    balance: 300,
+                },
+                DonorBalance {
+                    token: token_b.address.clone(),
+                    balance: 500,
+                },
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_donor_contribution_empty_for_non_donor() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let stranger = ctx.generate_address();
+    assert_eq!(
+        ctx.client
+            .get_donor_contribution(&project.id, &stranger)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_top_donors_sorted_and_limited() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(100_000);
+
+    let mut donors = std::vec::Vec::new();
+    for amount in [300, 900, 100, 600] {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &amount);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+        donors.push(donator);
+    }
+
+    let top = ctx
+        .client
+        .get_top_donors(&project.id, &token.address, &0, &3);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.get(0).unwrap(), (donors[1].clone(), 900));
+    assert_eq!(top.get(1).unwrap(), (donors[3].clone(), 600));
+    assert_eq!(top.get(2).unwrap(), (donors[0].clone(), 300));
+}
+
+#[test]
+fn test_top_donors_scan_one_page_per_call() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(100_000);
+
+    // 30 donors: the largest sits beyond the first page of 25 entries.
+    let mut largest = None;
+    for amount in 1..=30 {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &amount);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+        largest = Some(donator);
+    }
+
+    let first = ctx
+        .client
+        .get_top_donors(&project.id, &token.address, &0, &1);
+    assert_eq!(first.get(0).unwrap().1, 25);
+    let second = ctx
+        .client
+        .get_top_donors(&project.id, &token.address, &25, &1);
+    assert_eq!(second.get(0).unwrap(), (largest.unwrap(), 30));
+    assert!(ctx
+        .client
+        .get_top_donors(&project.id, &token.address, &50, &1)
+        .is_empty());
+}
+
+#[test]
+fn test_top_donors_break_ties_by_address() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(100_000);
+
+    let first = ctx.generate_address();
+    let second = ctx.generate_address();
+    for donator in [&first, &second] {
+        sac.mint(donator, &500);
+        ctx.client
+            .deposit(&project.id, donator, &token.address, &500);
+    }
+
+    let (low, high) = if first < second {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    let top = ctx
+        .client
+        .get_top_donors(&project.id, &token.address, &0, &10);
+    assert_eq!(top.get(0).unwrap(), (low, 500));
+    assert_eq!(top.get(1).unwrap(), (high, 500));
+}
+
+#[test]
+fn test_deposit_for_credits_beneficiary() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let relayer = ctx.generate_address();
+    let beneficiary = ctx.generate_address();
+    sac.mint(&relayer, &300);
+
+    ctx.client
+        .deposit_for(&relayer, &project.id, &beneficiary, &token.address, &300);
+    assert_eq!(
+        ctx.env.auths()[0].0,
+        relayer,
+        "the relayer authorizes the transfer"
+    );
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("relayed").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: RelayedDeposit = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(data.relayer, relayer);
+    assert_eq!(data.beneficiary, beneficiary);
+    assert_eq!(data.amount, 300);
+
+    assert_eq!(token.balance(&relayer), 0);
+    assert_eq!(
+        ctx.client
+            .get_donor_contribution(&project.id, &beneficiary)
+            .len(),
+        1
+    );
+    assert!(ctx
+        .client
+        .get_donor_contribution(&project.id, &relayer)
+        .is_empty());
+
+    // Refunds go to the beneficiary, not the relayer.
+    ctx.advance_past_deadline(project.id);
+    ctx.client.refund(&beneficiary, &project.id, &token.address);
+    assert_eq!(token.balance(&beneficiary), 300);
+    assert_eq!(token.balance(&relayer), 0);
+}
+
+#[test]
+fn test_per_donor_cap_limits_cumulative_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_per_donor_cap(&project.id), None);
+    ctx.client
+        .set_per_donor_cap(&ctx.manager, &project.id, &Some(300));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &token.address, &1),
+        Err(Ok(Error::DonorCapExceeded.into()))
+    );
+
+    // Other donors have their own allowance.
+    let other = ctx.generate_address();
+    sac.mint(&other, &300);
+    ctx.client
+        .deposit(&project.id, &other, &token.address, &300);
+
+    // Lifting the cap reopens deposits.
+    ctx.client
+        .set_per_donor_cap(&ctx.manager, &project.id, &None);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1);
+}
+
+#[test]
+fn test_per_donor_cap_applies_to_beneficiary_of_deposit_for() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.client
+        .set_per_donor_cap(&ctx.manager, &project.id, &Some(300));
+
+    let relayer = ctx.generate_address();
+    let beneficiary = ctx.generate_address();
+    sac.mint(&relayer, &1000);
+    sac.mint(&beneficiary, &300);
+    ctx.client
+        .deposit(&project.id, &beneficiary, &token.address, &250);
+
+    assert_eq!(
+        ctx.client
+            .try_deposit_for(&relayer, &project.id, &beneficiary, &token.address, &100),
+        Err(Ok(Error::DonorCapExceeded.into()))
+    );
+    // The relayer's own allowance is untouched by relaying.
+    ctx.client
+        .deposit_for(&relayer, &project.id, &beneficiary, &token.address, &50);
+    ctx.client
+        .deposit(&project.id, &relayer, &token.address, &300);
+}
+
+#[test]
+fn test_set_per_donor_cap_guards() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(
+        ctx.client
+            .try_set_per_donor_cap(&ctx.manager, &project.id, &Some(0)),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_set_per_donor_cap(&ctx.admin, &project.id, &Some(10)),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_deposit_with_nonce_is_exactly_once() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    let nonce = BytesN::from_array(&ctx.env, &[7u8; 32]);
+
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce),
+        NonceOutcome::Deposited
+    );
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce),
+        NonceOutcome::Replayed
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+    assert_eq!(token.balance(&donator), 900);
+
+    // Nonces are per donor.
+    let other = ctx.generate_address();
+    sac.mint(&other, &100);
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &other, &token.address, &100, &nonce),
+        NonceOutcome::Deposited
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+}
+
+#[test]
+fn test_failed_deposit_does_not_consume_nonce() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    let nonce = BytesN::from_array(&ctx.env, &[9u8; 32]);
+
+    let result =
+        ctx.client
+            .try_deposit_with_nonce(&project.id, &donator, &token.address, &0, &nonce);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount.into())));
+
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_deposit_nonce_must_increase() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    let nonce = |n: u8| BytesN::from_array(&ctx.env, &[n; 32]);
+
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce(5)),
+        NonceOutcome::Deposited
+    );
+    // An older nonce is ignored like a repeated one, but reported as stale.
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce(4)),
+        NonceOutcome::Stale
+    );
+    let last_event = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        vec![
+            &ctx.env,
+            symbol_short!("dup_nonce").into_val(&ctx.env),
+            project.id.into_val(&ctx.env),
+        ]
+    );
+    let data: NonceReplayed = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(data.nonce, nonce(4));
+    assert!(data.stale);
+
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce(5)),
+        NonceOutcome::Replayed
+    );
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce(6)),
+        NonceOutcome::Deposited
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+}
+
+#[test]
+fn test_replayed_nonce_requires_donor_auth() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    let nonce = BytesN::from_array(&ctx.env, &[3u8; 32]);
+    ctx.client
+        .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce);
+
+    assert_eq!(
+        ctx.client
+            .deposit_with_nonce(&project.id, &donator, &token.address, &100, &nonce),
+        NonceOutcome::Replayed
+    );
+    assert_eq!(ctx.env.auths()[0].0, donator);
+}
+
+#[test]
+fn test_can_deposit_mirrors_deposit_guards() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    let (other_token, _) = ctx.create_token();
+    let donator = ctx.generate_address();
+    let can = |amount: i128| {
+        ctx.client
+            .can_deposit(&project.id, &donator, &token.address, &amount)
+    };
+
+    assert!(can(100));
+    assert!(!ctx.client.can_deposit(&99, &donator, &token.address, &100));
+    assert!(!can(0));
+    assert!(!ctx
+        .client
+        .can_deposit(&project.id, &donator, &other_token.address, &100));
+
+    ctx.client.set_min_deposit(&ctx.manager, &project.id, &50);
+    assert!(!can(49));
+    assert!(can(50));
+
+    ctx.client
+        .set_per_donor_cap(&ctx.manager, &project.id, &Some(300));
+    assert!(!can(301));
+    assert!(can(300));
+
+    ctx.client.pause_token(&ctx.admin, &token.address);
+    assert!(!can(100));
+    ctx.client.unpause_token(&ctx.admin, &token.address);
+
+    ctx.client.pause(&ctx.admin);
+    assert!(!can(100));
+    ctx.client.unpause(&ctx.admin);
+
+    ctx.client.enable_allowlist(&ctx.manager, &project.id);
+    assert!(!can(100));
+    ctx.client
+        .add_allowed_donor(&ctx.manager, &project.id, &donator);
+    assert!(can(100));
+
+    ctx.advance_past_deadline(project.id);
+    assert!(!can(100));
+}
+
+#[test]
+fn test_can_deposit_rejects_closed_projects() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    assert!(!ctx
+        .client
+        .can_deposit(&project.id, &donator, &token.address, &100));
+}
+
+#[test]
+fn test_deposit_with_memo_records_and_emits_memo() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    assert_eq!(ctx.client.get_deposit_memo(&project.id, &donator), None);
+
+    let memo = Symbol::new(&ctx.env, "spring_email");
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &200, &memo);
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("memo").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: DepositMemo = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(data.donator, donator);
+    assert_eq!(data.amount, 200);
+    assert_eq!(data.memo, memo);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+    assert_eq!(
+        ctx.client.get_deposit_memo(&project.id, &donator),
+        Some(memo)
+    );
+
+    // Plain deposits leave the recorded memo alone.
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(
+        ctx.client.get_deposit_memo(&project.id, &donator),
+        Some(Symbol::new(&ctx.env, "spring_email"))
+    );
+}
+
+#[test]
+fn test_batch_deposit_funds_several_projects() {
+    let ctx = TestContext::new();
+    let (first, token, sac) = ctx.setup_project(1000);
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let second = ctx.register_project(&tokens, 1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &700);
+
+    ctx.client.batch_deposit(
+        &donator,
+        &vec![
+            &ctx.env,
+            (first.id, token.address.clone(), 300),
+            (second.id, token.address.clone(), 400),
+        ],
+    );
+    assert_eq!(ctx.client.get_balance(&first.id, &token.address), 300);
+    assert_eq!(ctx.client.get_balance(&second.id, &token.address), 400);
+    assert_eq!(token.balance(&donator), 0);
+}
+
+#[test]
+fn test_batch_deposit_reverts_when_one_entry_fails() {
+    let ctx = TestContext::new();
+    let (first, token, sac) = ctx.setup_project(1000);
+    let (second, _, _) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &700);
+
+    // `second` does not accept `token`.
+    assert_eq!(
+        ctx.client.try_batch_deposit(
+            &donator,
+            &vec![
+                &ctx.env,
+                (first.id, token.address.clone(), 300),
+                (second.id, token.address.clone(), 400),
+            ],
+        ),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(ctx.client.get_balance(&first.id, &token.address), 0);
+    assert_eq!(token.balance(&donator), 700);
+    assert_eq!(ctx.client.get_project(&first.id).donation_count, 0);
+}
+
+#[test]
+fn test_batch_deposit_rejects_empty_and_oversized_batches() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    assert_eq!(
+        ctx.client.try_batch_deposit(&donator, &Vec::new(&ctx.env)),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    let mut deposits = Vec::new(&ctx.env);
+    for _ in 0..11 {
+        deposits.push_back((project.id, token.address.clone(), 1));
+    }
+    assert_eq!(
+        ctx.client.try_batch_deposit(&donator, &deposits),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+}
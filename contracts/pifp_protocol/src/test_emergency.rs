@@ -0,0 +1,242 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal, Vec};
+
+use crate::events::EmergencyWithdrawal;
+use crate::{test_utils::TestContext, Error};
+
+#[test]
+fn test_emergency_withdraw_sweeps_only_surplus() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    // A stray transfer straight to the contract.
+    sac.mint(&ctx.client.address, &300);
+
+    let recipient = ctx.generate_address();
+    ctx.client.pause(&ctx.admin);
+    ctx.client
+        .emergency_withdraw(&ctx.admin, &token.address, &recipient, &300);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("emergency").into_val(&ctx.env),
+        ctx.admin.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: EmergencyWithdrawal = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        EmergencyWithdrawal {
+            token: token.address.clone(),
+            to: recipient.clone(),
+            amount: 300,
+        }
+    );
+
+    assert_eq!(token.balance(&recipient), 300);
+    assert_eq!(token.balance(&ctx.client.address), 500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+fn test_reconcile_reports_untracked_transfer() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    assert_eq!(ctx.client.reconcile(&token.address), 0);
+
+    sac.mint(&ctx.client.address, &300);
+    assert_eq!(ctx.client.reconcile(&token.address), 300);
+
+    ctx.client.pause(&ctx.admin);
+    ctx.client
+        .emergency_withdraw(&ctx.admin, &token.address, &ctx.admin, &300);
+    assert_eq!(ctx.client.reconcile(&token.address), 0);
+}
+
+#[test]
+fn test_reconcile_counts_pools_and_bonds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let other = ctx.register_project(&tokens, 10_000);
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &700);
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &400);
+    ctx.client
+        .fund_match_pool(&sponsor, &other.id, &token.address, &300);
+    sac.mint(&ctx.oracle, &200);
+    ctx.client.stake_oracle(&ctx.oracle, &token.address, &200);
+    assert_eq!(ctx.client.reconcile(&token.address), 0);
+
+    // Paying a pool out lowers the tracked total with the held balance.
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &10_000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &10_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(ctx.client.get_match_pool(&project.id, &token.address), None);
+    assert_eq!(ctx.client.reconcile(&token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #35)")]
+fn test_emergency_withdraw_rejects_tracked_funds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.client.pause(&ctx.admin);
+    ctx.client
+        .emergency_withdraw(&ctx.admin, &token.address, &ctx.admin, &1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_emergency_withdraw_capped_at_surplus() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    sac.mint(&ctx.client.address, &100);
+
+    ctx.client.pause(&ctx.admin);
+    ctx.client
+        .emergency_withdraw(&ctx.admin, &token.address, &ctx.admin, &101);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+fn test_emergency_withdraw_requires_pause() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    sac.mint(&ctx.client.address, &100);
+
+    ctx.client
+        .emergency_withdraw(&ctx.admin, &token.address, &ctx.admin, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_emergency_withdraw_requires_admin() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    sac.mint(&ctx.client.address, &100);
+    ctx.client.pause(&ctx.admin);
+
+    ctx.client
+        .emergency_withdraw(&ctx.manager, &token.address, &ctx.manager, &100);
+}
+
+#[test]
+fn test_paused_token_is_frozen() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.client.pause_token(&ctx.admin, &token.address);
+    let last_event = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        vec![
+            &ctx.env,
+            symbol_short!("tok_pause").into_val(&ctx.env),
+            ctx.admin.into_val(&ctx.env),
+        ]
+    );
+    assert!(ctx.client.is_token_paused(&token.address));
+    assert!(!ctx.client.is_paused());
+
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &token.address, &500),
+        Err(Ok(Error::TokenPaused.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_withdraw(&project.id, &donator, &token.address, &100),
+        Err(Ok(Error::TokenPaused.into()))
+    );
+
+    ctx.client.unpause_token(&ctx.admin, &token.address);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+}
+
+#[test]
+fn test_paused_token_blocks_release_and_refund() {
+    let ctx = TestContext::new();
+    let (project, token) = ctx.setup_funded_project(1000);
+    ctx.client.pause_token(&ctx.admin, &token.address);
+
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::TokenPaused.into()))
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    let (expiring, token2, sac2) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac2.mint(&donator, &100);
+    ctx.client
+        .deposit(&expiring.id, &donator, &token2.address, &100);
+    ctx.client.pause_token(&ctx.admin, &token2.address);
+    ctx.advance_past_deadline(expiring.id);
+    assert_eq!(
+        ctx.client
+            .try_refund_deposit(&expiring.id, &donator, &token2.address, &None),
+        Err(Ok(Error::TokenPaused.into()))
+    );
+}
+
+#[test]
+fn test_pause_token_requires_admin() {
+    let ctx = TestContext::new();
+    let token = ctx.generate_address();
+    assert_eq!(
+        ctx.client.try_pause_token(&ctx.manager, &token),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_emergency_withdraw_leaves_match_pools_alone() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &400);
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &400);
+    sac.mint(&ctx.client.address, &100);
+
+    ctx.client.pause(&ctx.admin);
+    assert_eq!(
+        ctx.client
+            .try_emergency_withdraw(&ctx.admin, &token.address, &ctx.admin, &101),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+    ctx.client
+        .emergency_withdraw(&ctx.admin, &token.address, &ctx.admin, &100);
+    assert_eq!(token.balance(&ctx.client.address), 400);
+}
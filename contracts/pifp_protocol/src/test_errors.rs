@@ -0,0 +1,132 @@
+extern crate std;
+
+use soroban_sdk::{vec, Bytes, Vec};
+
+use crate::{test_utils::TestContext, Error};
+
+#[test]
+fn test_try_register_project_distinguishes_token_errors() {
+    let ctx = TestContext::new();
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+
+    let token = ctx.generate_address();
+    let duplicates = vec![&ctx.env, token.clone(), token];
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &duplicates,
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::DuplicateToken.into())));
+
+    let mut too_many = Vec::new(&ctx.env);
+    for _ in 0..11 {
+        too_many.push_back(ctx.generate_address());
+    }
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &too_many,
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::TooManyTokens.into())));
+}
+
+#[test]
+fn test_try_deposit_reports_error_and_keeps_state() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &token.address, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &ctx.generate_address(), &100),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+
+    assert!(ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &100)
+        .is_ok());
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_try_verify_and_release_reports_error() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let wrong_preimage = Bytes::from_slice(&ctx.env, b"forged");
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &wrong_preimage),
+        Err(Ok(Error::ProofMismatch.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &99, &ctx.dummy_preimage()),
+        Err(Ok(Error::ProjectNotFound.into()))
+    );
+}
+
+#[test]
+fn test_non_positive_amounts_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    for amount in [0i128, -1] {
+        assert_eq!(
+            ctx.client
+                .try_deposit(&project.id, &donator, &token.address, &amount),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        assert_eq!(
+            ctx.client
+                .try_withdraw(&project.id, &donator, &token.address, &amount),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+        assert_eq!(
+            ctx.client.try_register_project(
+                &ctx.manager,
+                &vec![&ctx.env, token.address.clone()],
+                &amount,
+                &ctx.dummy_proof(),
+                &(ctx.env.ledger().timestamp() + 86400),
+                &ctx.dummy_metadata(),
+                &None,
+            ),
+            Err(Ok(Error::InvalidGoal.into()))
+        );
+    }
+
+    // Balances are untouched by the rejected calls.
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+    assert_eq!(token.balance(&donator), 0);
+}
+
+#[test]
+fn test_every_error_has_a_description() {
+    let mut code = 1;
+    while let Ok(err) = Error::try_from(soroban_sdk::Error::from_contract_error(code)) {
+        assert!(!err.describe().is_empty(), "no description for {err:?}");
+        code += 1;
+    }
+    assert_eq!(code, Error::TokenNotAllowed as u32 + 1);
+}
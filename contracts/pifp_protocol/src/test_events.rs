@@ -2,8 +2,11 @@ extern crate std;
 
 use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
 
-use crate::events::{ProjectCreated, ProjectFunded, ProjectVerified};
+use crate::events::{
+    Deposited, FundsReleased, ProjectCreated, ProjectFunded, ProjectVerified, StatusChanged,
+};
 use crate::test_utils::TestContext;
+use crate::ProjectStatus;
 
 #[test]
 fn test_project_created_event() {
@@ -47,8 +50,11 @@ fn test_project_funded_event() {
     ctx.client
         .deposit(&project.id, &donator, &token.address, &amount);
 
+    // `funded` is followed by the `deposit` event.
     let all_events = ctx.env.events().all();
-    let last_event = all_events.last().expect("No events found");
+    let last_event = all_events
+        .get(all_events.len() - 2)
+        .expect("No events found");
 
     // Topic: (symbol_short!("funded"), project_id)
     assert_eq!(last_event.0, ctx.client.address);
@@ -72,16 +78,109 @@ fn test_project_funded_event() {
 }
 
 #[test]
-fn test_project_verified_event() {
+fn test_deposit_event_carries_running_total() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("deposit").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: Deposited = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        Deposited {
+            project_id: project.id,
+            donator: donator.clone(),
+            token: token.address.clone(),
+            amount: 500,
+            new_total: 1500,
+        }
+    );
+}
+
+#[test]
+fn test_deposit_reaching_goal_emits_status_change() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    let status_topics = vec![
+        &ctx.env,
+        symbol_short!("status").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    let changes: std::vec::Vec<StatusChanged> = ctx
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|e| e.1 == status_topics)
+        .map(|e| e.2.try_into_val(&ctx.env).unwrap())
+        .collect();
+    assert_eq!(
+        changes,
+        std::vec![StatusChanged {
+            project_id: project.id,
+            old_status: ProjectStatus::Funding,
+            new_status: ProjectStatus::Active,
+        }]
+    );
+}
+
+#[test]
+fn test_expire_emits_status_change() {
     let ctx = TestContext::new();
     let (project, _, _) = ctx.setup_project(1000);
+    ctx.advance_past_deadline(project.id);
+
+    ctx.client.expire_project(&project.id);
+
+    let all_events = ctx.env.events().all();
+    let status_event = all_events
+        .get(all_events.len() - 2)
+        .expect("No events found");
+    let event_data: StatusChanged = status_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        StatusChanged {
+            project_id: project.id,
+            old_status: ProjectStatus::Funding,
+            new_status: ProjectStatus::Expired,
+        }
+    );
+}
+
+#[test]
+fn test_project_verified_event() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
     let proof = ctx.dummy_proof();
 
     ctx.client
-        .verify_and_release(&ctx.oracle, &project.id, &proof);
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
 
+    // `verified` is followed by the `completed` event.
     let all_events = ctx.env.events().all();
-    let last_event = all_events.last().expect("No events found");
+    let last_event = all_events
+        .get(all_events.len() - 2)
+        .expect("No events found");
 
     // Topic: (symbol_short!("verified"), project_id)
     assert_eq!(last_event.0, ctx.client.address);
@@ -104,6 +203,64 @@ fn test_project_verified_event() {
     );
 }
 
+#[test]
+fn test_release_events_per_token_then_completed() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 1000);
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token_b.address, &10_000_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &400);
+    sac_b.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &600);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    let all_events = ctx.env.events().all();
+    let releases: std::vec::Vec<FundsReleased> = all_events
+        .iter()
+        .filter(|e| {
+            e.1.get(0)
+                .map(|t| t.shallow_eq(&symbol_short!("released").to_val()))
+                == Some(true)
+        })
+        .map(|e| e.2.try_into_val(&ctx.env).unwrap())
+        .collect();
+    assert_eq!(
+        releases,
+        std::vec![
+            FundsReleased {
+                project_id: project.id,
+                creator: ctx.manager.clone(),
+                token: token_a.address.clone(),
+                amount: 400,
+            },
+            FundsReleased {
+                project_id: project.id,
+                creator: ctx.manager.clone(),
+                token: token_b.address.clone(),
+                amount: 600,
+            },
+        ]
+    );
+
+    let last_event = all_events.last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("completed").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+}
+
 #[test]
 fn test_get_project_balances() {
     let ctx = TestContext::new();
@@ -120,8 +277,13 @@ fn test_get_project_balances() {
         &10_000,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
     );
 
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token_b.address, &10_000_000);
+
     let donator = ctx.generate_address();
     let amount_a = 2_500i128;
     let amount_b = 7_000i128;
@@ -149,10 +311,48 @@ fn test_get_project_balances() {
     assert_eq!(bal_b.balance, amount_b);
 }
 
+#[test]
+fn test_get_balances_for_preserves_order() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let tokens = vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 10_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &300);
+
+    let stranger = ctx.generate_address();
+    let query = vec![
+        &ctx.env,
+        token_b.address.clone(),
+        stranger,
+        token_a.address.clone(),
+    ];
+    assert_eq!(
+        ctx.client.get_balances_for(&project.id, &query),
+        vec![&ctx.env, 0i128, 0, 300]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_get_balances_for_caps_input() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let mut query = vec![&ctx.env];
+    for _ in 0..11 {
+        query.push_back(ctx.generate_address());
+    }
+    ctx.client.get_balances_for(&project.id, &query);
+}
+
 #[test]
 fn test_funds_released_to_creator() {
     let ctx = TestContext::new();
-    let (project, token, sac) = ctx.setup_project(5000);
+    let (project, token, sac) = ctx.setup_project(1000);
 
     let donator = ctx.generate_address();
     let deposit_amount = 1000i128;
@@ -163,7 +363,7 @@ fn test_funds_released_to_creator() {
 
     // Verify and release
     ctx.client
-        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
 
     // Check creator (manager) received the funds
     assert_eq!(token.balance(&ctx.manager), deposit_amount);
@@ -182,7 +382,7 @@ fn test_refunded_event() {
     ctx.client
         .deposit(&project.id, &donator, &token.address, &400i128);
 
-    ctx.jump_time(86_401);
+    ctx.advance_past_deadline(project.id);
     ctx.client.refund(&donator, &project.id, &token.address);
 
     let all_events = ctx.env.events().all();
@@ -196,7 +396,32 @@ fn test_refunded_event() {
     ];
     assert_eq!(last_event.1, expected_topics);
 
-    let event_data: (soroban_sdk::Address, i128) = last_event.2.try_into_val(&ctx.env).unwrap();
+    let event_data: (soroban_sdk::Address, i128, soroban_sdk::Address) =
+        last_event.2.try_into_val(&ctx.env).unwrap();
     assert_eq!(event_data.0, donator);
     assert_eq!(event_data.1, 400i128);
+    assert_eq!(event_data.2, donator);
+}
+
+#[test]
+fn test_pause_and_unpause_events() {
+    let ctx = TestContext::new();
+
+    ctx.client.pause(&ctx.admin);
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("paused").into_val(&ctx.env),
+        ctx.admin.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    ctx.client.unpause(&ctx.admin);
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("unpaused").into_val(&ctx.env),
+        ctx.admin.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
 }
@@ -10,7 +10,7 @@ fn test_expire_project_success() {
     assert_eq!(project.status, ProjectStatus::Funding);
 
     // Jump forward in time
-    ctx.jump_time(project.deadline + 1);
+    ctx.advance_past_deadline(project.id);
 
     ctx.client.expire_project(&project.id);
 
@@ -35,7 +35,7 @@ fn test_expire_wrong_status_panics() {
     let (project, _, _) = ctx.setup_project(1000);
 
     // Expire properly first
-    ctx.jump_time(project.deadline + 1);
+    ctx.advance_past_deadline(project.id);
     ctx.client.expire_project(&project.id);
 
     // Attempt to expire again (Expired status is wrong status for expire_project)
@@ -50,10 +50,10 @@ fn test_expire_completed_project_panics() {
 
     // Move to Completed
     ctx.client
-        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
 
     // Attempt to expire
-    ctx.jump_time(project.deadline + 1);
+    ctx.advance_past_deadline(project.id);
     ctx.client.expire_project(&project.id);
 }
 
@@ -71,10 +71,54 @@ fn test_expire_active_project_success() {
     assert_eq!(active_project.status, ProjectStatus::Active);
 
     // Jump forward in time
-    ctx.jump_time(project.deadline + 1);
+    ctx.advance_past_deadline(project.id);
 
     ctx.client.expire_project(&project.id);
 
     let expired_project = ctx.client.get_project(&project.id);
     assert_eq!(expired_project.status, ProjectStatus::Expired);
 }
+
+#[test]
+fn test_auto_expire_sweeps_overdue_projects() {
+    let ctx = TestContext::new();
+    let (funding, _, _) = ctx.setup_project(1000);
+    let (active, _) = ctx.setup_funded_project(1000);
+    let (completed, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &completed.id, &ctx.dummy_preimage());
+    let (later, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .extend_deadline(&ctx.manager, &later.id, &(later.deadline + 86400));
+
+    ctx.advance_past_deadline(funding.id);
+    assert_eq!(ctx.client.auto_expire(&0, &10), 2);
+
+    let status = |id: u64| ctx.client.get_project(&id).status;
+    assert_eq!(status(funding.id), ProjectStatus::Expired);
+    assert_eq!(status(active.id), ProjectStatus::Expired);
+    assert_eq!(status(completed.id), ProjectStatus::Completed);
+    assert_eq!(status(later.id), ProjectStatus::Funding);
+
+    // A second sweep finds nothing left to do.
+    assert_eq!(ctx.client.auto_expire(&0, &10), 0);
+}
+
+#[test]
+fn test_auto_expire_respects_range() {
+    let ctx = TestContext::new();
+    let (first, _, _) = ctx.setup_project(1000);
+    let (second, _, _) = ctx.setup_project(1000);
+    ctx.advance_past_deadline(first.id);
+
+    assert_eq!(ctx.client.auto_expire(&1, &1), 1);
+    assert_eq!(
+        ctx.client.get_project(&first.id).status,
+        ProjectStatus::Funding
+    );
+    assert_eq!(
+        ctx.client.get_project(&second.id).status,
+        ProjectStatus::Expired
+    );
+    assert_eq!(ctx.client.auto_expire(&5, &10), 0);
+}
@@ -0,0 +1,218 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, Error};
+use soroban_sdk::vec;
+
+#[test]
+fn test_release_splits_fee_to_treasury() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.set_fee_bps(&ctx.admin, &250);
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+
+    let (project, token, sac) = ctx.setup_project(999);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &999);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &999);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    // 999 * 250 / 10_000 = 24.975, rounded down to 24.
+    assert_eq!(token.balance(&treasury), 24);
+    assert_eq!(token.balance(&ctx.manager), 975);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_no_fee_without_treasury() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &500);
+
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_treasury(), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_fee_above_cap_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &1001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_admin_cannot_set_fee() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.manager, &100);
+}
+
+#[test]
+fn test_simulate_release_matches_actual_payout() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.set_fee_bps(&ctx.admin, &250);
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+
+    let (project, token, sac) = ctx.setup_project(999);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &999);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &999);
+
+    let preview = ctx.client.simulate_release(&project.id);
+    assert_eq!(preview, vec![&ctx.env, (token.address.clone(), 975)]);
+    // The preview is read-only.
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 999);
+    assert_eq!(token.balance(&treasury), 0);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 975);
+    assert!(ctx.client.simulate_release(&project.id).is_empty());
+}
+
+#[test]
+fn test_release_split_among_beneficiaries() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.set_fee_bps(&ctx.admin, &100);
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+
+    let (project, token, sac) = ctx.setup_project(1000);
+    let dev = ctx.generate_address();
+    let designer = ctx.generate_address();
+    ctx.client.set_beneficiaries(
+        &ctx.manager,
+        &project.id,
+        &vec![
+            &ctx.env,
+            (ctx.manager.clone(), 3333),
+            (dev.clone(), 3333),
+            (designer.clone(), 3334),
+        ],
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    // 990 net after the fee: 329 + 329, and the last share takes the rest.
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(token.balance(&ctx.manager), 329);
+    assert_eq!(token.balance(&dev), 329);
+    assert_eq!(token.balance(&designer), 332);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_default_beneficiary_is_creator() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(
+        ctx.client.get_beneficiaries(&project.id),
+        vec![&ctx.env, (ctx.manager.clone(), 10_000)]
+    );
+}
+
+#[test]
+fn test_beneficiary_shares_must_sum_to_10000() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let other = ctx.generate_address();
+
+    let short = vec![&ctx.env, (ctx.manager.clone(), 5000), (other.clone(), 4000)];
+    let result = ctx
+        .client
+        .try_set_beneficiaries(&ctx.manager, &project.id, &short);
+    assert_eq!(result, Err(Ok(Error::SharesMustSumTo10000.into())));
+
+    let result = ctx
+        .client
+        .try_set_beneficiaries(&ctx.manager, &project.id, &vec![&ctx.env]);
+    assert_eq!(result, Err(Ok(Error::SharesMustSumTo10000.into())));
+
+    let full = vec![&ctx.env, (other.clone(), 10_000)];
+    let result = ctx.client.try_set_beneficiaries(&other, &project.id, &full);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized.into())));
+}
+
+#[test]
+fn test_veteran_creator_pays_tier_fee() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.set_fee_bps(&ctx.admin, &500);
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+    ctx.client
+        .set_fee_tiers(&ctx.admin, &vec![&ctx.env, (1, 200), (3, 0)]);
+    assert_eq!(ctx.client.get_effective_fee_bps(&ctx.manager), 500);
+
+    // A first-time creator pays the base fee on their own completion.
+    let (first, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &2000);
+    ctx.client
+        .deposit(&first.id, &donator, &token.address, &1000);
+    assert_eq!(
+        ctx.client.simulate_release(&first.id).get(0).unwrap().1,
+        950
+    );
+    ctx.client
+        .verify_and_release(&ctx.oracle, &first.id, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&treasury), 50);
+    assert_eq!(ctx.client.completed_projects_count(&ctx.manager), 1);
+    assert_eq!(ctx.client.get_effective_fee_bps(&ctx.manager), 200);
+
+    let second = ctx.register_project(&vec![&ctx.env, token.address.clone()], 1000);
+    ctx.client
+        .deposit(&second.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &second.id, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&treasury), 70);
+    assert_eq!(token.balance(&ctx.manager), 950 + 980);
+}
+
+#[test]
+fn test_tier_fee_never_exceeds_base() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &100);
+    ctx.client
+        .set_fee_tiers(&ctx.admin, &vec![&ctx.env, (1, 800)]);
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(ctx.client.get_effective_fee_bps(&ctx.manager), 100);
+}
+
+#[test]
+fn test_fee_tiers_validated() {
+    let ctx = TestContext::new();
+    for tiers in [
+        vec![&ctx.env, (0, 100)],
+        vec![&ctx.env, (2, 100), (2, 50)],
+        vec![&ctx.env, (1, 1_001)],
+    ] {
+        assert_eq!(
+            ctx.client.try_set_fee_tiers(&ctx.admin, &tiers),
+            Err(Ok(Error::InvalidAmount.into()))
+        );
+    }
+    assert_eq!(
+        ctx.client
+            .try_set_fee_tiers(&ctx.manager, &vec![&ctx.env, (1, 100)]),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
@@ -5,6 +5,8 @@
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "testutils")]
+    use crate::gas_profiling::GasProfiler;
     use crate::test_utils::TestContext;
     use soroban_sdk::{vec, BytesN};
 
@@ -20,9 +22,15 @@ mod test {
         let deadline = ctx.env.ledger().timestamp() + 100_000;
 
         // Measure gas consumption for project registration
-        let project =
-            ctx.client
-                .register_project(&ctx.manager, &tokens, &goal, &proof_hash, &deadline);
+        let project = ctx.client.register_project(
+            &ctx.manager,
+            &tokens,
+            &goal,
+            &proof_hash,
+            &deadline,
+            &ctx.dummy_metadata(),
+            &None,
+        );
 
         // Basic assertion that operation succeeded
         assert_eq!(project.id, 0);
@@ -34,9 +42,10 @@ mod test {
         let ctx = TestContext::new();
 
         // Setup project and token
-        let (project, token, _) = ctx.setup_project(1000);
+        let (project, token, sac) = ctx.setup_project(1000);
         let donator = ctx.generate_address();
         let amount = 100i128;
+        sac.mint(&donator, &(amount * 2));
 
         // First deposit (new donor)
         ctx.client
@@ -53,18 +62,43 @@ mod test {
     }
 
     #[test]
-    fn test_baseline_verify_and_release_gas() {
+    #[cfg(feature = "testutils")]
+    fn test_checkpoint_diffs_profile_full_flow() {
         let ctx = TestContext::new();
+        let profiler = GasProfiler::new(&ctx.env);
+        let (token, sac) = ctx.create_token();
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &1000);
 
-        // Setup project
-        let (project, token, _) = ctx.setup_project(1000);
-        let proof_hash = ctx.dummy_proof();
-
-        // Make deposits to reach goal
+        let project = ctx.register_project(&vec![&ctx.env, token.address.clone()], 1000);
+        profiler.checkpoint("register");
         ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token.address, &500);
+            .deposit(&project.id, &donator, &token.address, &1000);
+        profiler.checkpoint("deposit");
         ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token.address, &500);
+            .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+        profiler.checkpoint("verify");
+
+        let diffs = profiler.diffs();
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(
+            (diffs[0].0.as_str(), diffs[0].1.as_str()),
+            ("register", "deposit")
+        );
+        assert_eq!(
+            (diffs[1].0.as_str(), diffs[1].1.as_str()),
+            ("deposit", "verify")
+        );
+        assert!(diffs.iter().all(|(_, _, cpu)| *cpu > 0));
+    }
+
+    #[test]
+    fn test_baseline_verify_and_release_gas() {
+        let ctx = TestContext::new();
+
+        // Setup a project that has reached its goal
+        let (project, _) = ctx.setup_funded_project(1000);
+        let preimage = ctx.dummy_preimage();
 
         // Grant oracle role
         ctx.client
@@ -72,7 +106,7 @@ mod test {
 
         // Measure verification and release
         ctx.client
-            .verify_and_release(&ctx.oracle, &project.id, &proof_hash);
+            .verify_and_release(&ctx.oracle, &project.id, &preimage);
 
         // Verify operation succeeds
         let updated_project = ctx.client.get_project(&project.id);
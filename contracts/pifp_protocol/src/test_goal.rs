@@ -0,0 +1,192 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::GoalUpdated;
+use crate::{test_utils::TestContext, Error, ProjectStatus};
+
+#[test]
+fn test_update_goal_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.update_goal(&ctx.manager, &project.id, &2000);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("goal").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: GoalUpdated = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        GoalUpdated {
+            project_id: project.id,
+            old_goal: 1000,
+            new_goal: 2000,
+        }
+    );
+
+    let loaded = ctx.client.get_project(&project.id);
+    assert_eq!(loaded.goal, 2000);
+    assert_eq!(loaded.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_lowering_goal_to_raised_activates_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+
+    ctx.client.update_goal(&ctx.manager, &project.id, &600);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #31)")]
+fn test_goal_below_raised_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+
+    ctx.client.update_goal(&ctx.manager, &project.id, &599);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_update_goal_rejected_once_active() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client.update_goal(&ctx.manager, &project.id, &5000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_creator_cannot_update_goal() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.update_goal(&ctx.admin, &project.id, &2000);
+}
+
+#[test]
+fn test_min_goal_rejects_small_registrations() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_min_goal(), 0);
+
+    ctx.client.set_min_goal(&ctx.admin, &500);
+    assert_eq!(ctx.client.get_min_goal(), 500);
+
+    let tokens = vec![&ctx.env, ctx.generate_address()];
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &tokens,
+        &499,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::GoalBelowMinimum.into())));
+
+    let project = ctx.register_project(&tokens, 500);
+    assert_eq!(project.goal, 500);
+}
+
+#[test]
+fn test_set_min_goal_guards() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.try_set_min_goal(&ctx.admin, &-1),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert!(ctx.client.try_set_min_goal(&ctx.manager, &10).is_err());
+}
+
+#[test]
+fn test_release_threshold_activates_below_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_release_threshold_bps(&project.id), 10_000);
+
+    ctx.client
+        .set_release_threshold_bps(&ctx.manager, &project.id, &8_000);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &799);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    // Activated projects release without the partial-release opt-in.
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 800);
+}
+
+#[test]
+fn test_lowering_release_threshold_activates_immediately() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.client
+        .set_release_threshold_bps(&ctx.manager, &project.id, &5_000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_release_threshold_guards() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(
+        ctx.client
+            .try_set_release_threshold_bps(&ctx.manager, &project.id, &10_001),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_set_release_threshold_bps(&ctx.manager, &project.id, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_set_release_threshold_bps(&ctx.admin, &project.id, &5_000),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
@@ -0,0 +1,147 @@
+extern crate std;
+
+use soroban_sdk::{token, Vec};
+
+use crate::{test_utils::TestContext, Error, Project, ProjectStatus};
+
+fn setup_capped_project(
+    ctx: &TestContext,
+    goal: i128,
+    hard_cap: i128,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &goal,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &Some(hard_cap),
+    );
+    (project, token, sac)
+}
+
+#[test]
+fn test_uncapped_by_default() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    assert_eq!(project.hard_cap, None);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &5000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &5000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 5000);
+}
+
+#[test]
+fn test_deposit_up_to_cap_activates_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 1000, 1500);
+    assert_eq!(project.hard_cap, Some(1500));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1500);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_deposit_past_cap_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 1000, 1500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1501);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &501);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_deposit_after_cap_hit_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 1000, 1500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1501);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_cap_below_goal_rejected() {
+    let ctx = TestContext::new();
+    setup_capped_project(&ctx, 1000, 999);
+}
+
+#[test]
+fn test_can_deposit_respects_hard_cap() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 1000, 1500);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    assert!(ctx
+        .client
+        .can_deposit(&project.id, &donator, &token.address, &500));
+    assert!(!ctx
+        .client
+        .can_deposit(&project.id, &donator, &token.address, &501));
+}
+
+#[test]
+fn test_set_hard_cap_after_registration() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1200);
+
+    assert_eq!(
+        ctx.client
+            .try_set_hard_cap(&donator, &project.id, &Some(2000)),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_set_hard_cap(&ctx.manager, &project.id, &Some(999)),
+        Err(Ok(Error::InvalidGoal.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_set_hard_cap(&ctx.manager, &project.id, &Some(1100)),
+        Err(Ok(Error::HardCapExceeded.into()))
+    );
+
+    ctx.client
+        .set_hard_cap(&ctx.manager, &project.id, &Some(1300));
+    assert_eq!(ctx.client.get_project(&project.id).hard_cap, Some(1300));
+    assert!(!ctx
+        .client
+        .can_deposit(&project.id, &donator, &token.address, &101));
+
+    ctx.client.set_hard_cap(&ctx.manager, &project.id, &None);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+}
@@ -0,0 +1,175 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::{test_utils::TestContext, Error, ProjectStatus, ProjectSummary};
+
+#[test]
+fn test_project_count_tracks_registrations() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_project_count(), 0);
+
+    ctx.setup_project(1000);
+    ctx.setup_project(2000);
+    assert_eq!(ctx.client.get_project_count(), 2);
+}
+
+#[test]
+fn test_list_projects_pages_through_ids() {
+    let ctx = TestContext::new();
+    for goal in [100, 200, 300, 400, 500] {
+        ctx.setup_project(goal);
+    }
+
+    let first = ctx.client.list_projects(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().goal, 100);
+    assert_eq!(first.get(1).unwrap().goal, 200);
+
+    let last = ctx.client.list_projects(&4, &10);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().id, 4);
+
+    assert_eq!(ctx.client.list_projects(&5, &10).len(), 0);
+}
+
+#[test]
+fn test_list_projects_caps_limit() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    for _ in 0..55 {
+        ctx.register_project(&tokens, 1000);
+    }
+
+    assert_eq!(ctx.client.list_projects(&0, &u32::MAX).len(), 50);
+}
+
+#[test]
+fn test_projects_by_creator() {
+    let ctx = TestContext::new();
+    let (first, _, _) = ctx.setup_project(1000);
+    let (second, _, _) = ctx.setup_project(2000);
+
+    let ids = ctx.client.get_projects_by_creator(&ctx.manager);
+    assert_eq!(ids, Vec::from_array(&ctx.env, [first.id, second.id]));
+    assert!(ctx
+        .client
+        .get_projects_by_creator(&ctx.generate_address())
+        .is_empty());
+}
+
+#[test]
+fn test_projects_donated_by_records_first_deposit_only() {
+    let ctx = TestContext::new();
+    let (first, token_a, sac_a) = ctx.setup_project(1000);
+    let (second, token_b, sac_b) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &300);
+    sac_b.mint(&donator, &100);
+
+    ctx.client
+        .deposit(&second.id, &donator, &token_b.address, &100);
+    ctx.client
+        .deposit(&first.id, &donator, &token_a.address, &100);
+    ctx.client
+        .deposit(&first.id, &donator, &token_a.address, &100);
+
+    // Withdrawing everything and coming back does not list it twice.
+    ctx.client
+        .withdraw(&first.id, &donator, &token_a.address, &200);
+    ctx.client
+        .deposit(&first.id, &donator, &token_a.address, &100);
+
+    let ids = ctx.client.get_projects_donated_by(&donator);
+    assert_eq!(ids, Vec::from_array(&ctx.env, [second.id, first.id]));
+}
+
+#[test]
+fn test_creator_index_is_capped() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    for _ in 0..=crate::MAX_ACCOUNT_PROJECTS {
+        ctx.register_project(&tokens, 1000);
+    }
+
+    let ids = ctx.client.get_projects_by_creator(&ctx.manager);
+    assert_eq!(ids.len(), crate::MAX_ACCOUNT_PROJECTS);
+    assert_eq!(ids.last(), Some(u64::from(crate::MAX_ACCOUNT_PROJECTS) - 1));
+}
+
+#[test]
+fn test_project_summary_aggregates_read_fields() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    for amount in [200, 300] {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &amount);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+    }
+
+    let summary = ctx.client.get_project_summary(&project.id);
+    assert_eq!(
+        summary,
+        ProjectSummary {
+            project_id: project.id,
+            status: ProjectStatus::Funding,
+            goal: 1000,
+            raised: 500,
+            token_count: 1,
+            donor_count: 2,
+            deadline: project.deadline,
+        }
+    );
+}
+
+#[test]
+fn test_find_project_returns_none_for_missing_ids() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(ctx.client.find_project(&project.id), Some(project.clone()));
+    assert_eq!(ctx.client.find_project(&(project.id + 1)), None);
+    assert!(ctx.client.try_get_project(&(project.id + 1)).is_err());
+}
+
+#[test]
+fn test_max_active_projects_per_creator() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    assert_eq!(ctx.client.get_max_active_per_creator(), None);
+    ctx.client.set_max_active_per_creator(&ctx.admin, &Some(2));
+
+    let first = ctx.register_project(&tokens, 1000);
+    let second = ctx.register_project(&tokens, 1000);
+    assert_eq!(ctx.client.active_projects_count(&ctx.manager), 2);
+    let register = || {
+        ctx.client.try_register_project(
+            &ctx.manager,
+            &tokens,
+            &1000,
+            &ctx.dummy_proof(),
+            &(ctx.env.ledger().timestamp() + 86400),
+            &ctx.dummy_metadata(),
+            &None,
+        )
+    };
+    assert_eq!(register(), Err(Ok(Error::TooManyActiveProjects.into())));
+
+    // Cancelling or expiring a project frees a slot.
+    ctx.client.cancel_project(&ctx.manager, &first.id);
+    assert_eq!(ctx.client.active_projects_count(&ctx.manager), 1);
+    let third = register().unwrap().unwrap();
+    assert_eq!(register(), Err(Ok(Error::TooManyActiveProjects.into())));
+
+    ctx.advance_past_deadline(second.id);
+    ctx.client.expire_project(&second.id);
+    ctx.client.expire_project(&third.id);
+    assert_eq!(ctx.client.active_projects_count(&ctx.manager), 0);
+
+    ctx.client.set_max_active_per_creator(&ctx.admin, &None);
+    for _ in 0..3 {
+        register().unwrap().unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, Error, MatchPool};
+
+#[test]
+fn test_release_adds_match_and_returns_unused_pool() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(400);
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &1000);
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &1000);
+    assert_eq!(
+        ctx.client.get_match_pool(&project.id, &token.address),
+        Some(MatchPool {
+            sponsor: sponsor.clone(),
+            balance: 1000,
+        })
+    );
+    // The pool is not part of the project's balance.
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+    assert_eq!(
+        ctx.client.simulate_release(&project.id).get(0).unwrap().1,
+        800
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(token.balance(&ctx.manager), 800);
+    assert_eq!(token.balance(&sponsor), 600);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(ctx.client.get_match_pool(&project.id, &token.address), None);
+}
+
+#[test]
+fn test_match_is_capped_by_pool_and_scaled_by_ratio() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_match_ratio_bps(), 10_000);
+    ctx.client.set_match_ratio_bps(&ctx.admin, &5_000);
+
+    let (project, token, sac) = ctx.setup_project(1000);
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &300);
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &200);
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &100);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    // Half of 1000 raised is 500, but the pool only holds 300.
+    assert_eq!(token.balance(&ctx.manager), 1300);
+    assert_eq!(token.balance(&sponsor), 0);
+}
+
+#[test]
+fn test_refunds_leave_the_pool_to_its_sponsor() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &500);
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &500);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+
+    assert_eq!(
+        ctx.client
+            .try_reclaim_match_pool(&sponsor, &project.id, &token.address),
+        Err(Ok(Error::ProjectNotExpired.into()))
+    );
+
+    ctx.advance_past_deadline(project.id);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 300);
+
+    let stranger = ctx.generate_address();
+    assert_eq!(
+        ctx.client
+            .try_reclaim_match_pool(&stranger, &project.id, &token.address),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    ctx.client
+        .reclaim_match_pool(&sponsor, &project.id, &token.address);
+    assert_eq!(token.balance(&sponsor), 500);
+    assert_eq!(
+        ctx.client
+            .try_reclaim_match_pool(&sponsor, &project.id, &token.address),
+        Err(Ok(Error::NothingToRefund.into()))
+    );
+}
+
+#[test]
+fn test_fund_match_pool_guards() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let (other_token, _) = ctx.create_token();
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &100);
+
+    assert_eq!(
+        ctx.client
+            .try_fund_match_pool(&sponsor, &project.id, &token.address, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_fund_match_pool(&sponsor, &project.id, &other_token.address, &10),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+
+    ctx.client
+        .fund_match_pool(&sponsor, &project.id, &token.address, &50);
+    let rival = ctx.generate_address();
+    sac.mint(&rival, &50);
+    assert_eq!(
+        ctx.client
+            .try_fund_match_pool(&rival, &project.id, &token.address, &50),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+
+    assert_eq!(
+        ctx.client.try_set_match_ratio_bps(&ctx.admin, &100_001),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+}
@@ -0,0 +1,95 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, String, Symbol, TryIntoVal};
+
+use crate::{events::ImpactReported, test_utils::TestContext, Error};
+
+#[test]
+fn test_register_stores_metadata() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(project.name, ctx.dummy_name());
+    assert_eq!(project.metadata_uri, ctx.dummy_metadata_uri());
+
+    let loaded = ctx.client.get_project(&project.id);
+    assert_eq!(loaded.name, Symbol::new(&ctx.env, "project"));
+    assert_eq!(
+        loaded.metadata_uri,
+        String::from_str(&ctx.env, "ipfs://project")
+    );
+}
+
+#[test]
+fn test_creator_updates_metadata_uri() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let uri = String::from_str(&ctx.env, "ipfs://updated");
+
+    ctx.client.set_metadata_uri(&ctx.manager, &project.id, &uri);
+
+    let loaded = ctx.client.get_project(&project.id);
+    assert_eq!(loaded.metadata_uri, uri);
+    assert_eq!(loaded.name, ctx.dummy_name());
+    assert_eq!(loaded.goal, 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_creator_cannot_set_metadata_uri() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.set_metadata_uri(
+        &ctx.admin,
+        &project.id,
+        &String::from_str(&ctx.env, "ipfs://hijack"),
+    );
+}
+
+#[test]
+fn test_report_impact_after_completion() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    assert_eq!(project.reported_impact, 0);
+    assert_eq!(
+        ctx.client
+            .try_report_impact(&ctx.manager, &project.id, &250),
+        Err(Ok(Error::ProjectNotActive.into()))
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    ctx.client.report_impact(&ctx.manager, &project.id, &250);
+    assert_eq!(ctx.client.get_project(&project.id).reported_impact, 250);
+
+    ctx.client.report_impact(&ctx.oracle, &project.id, &300);
+    let last_event = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        vec![
+            &ctx.env,
+            symbol_short!("impact").into_val(&ctx.env),
+            project.id.into_val(&ctx.env),
+        ]
+    );
+    let data: ImpactReported = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        ImpactReported {
+            project_id: project.id,
+            reporter: ctx.oracle.clone(),
+            value: 300,
+        }
+    );
+    assert_eq!(ctx.client.get_project(&project.id).reported_impact, 300);
+
+    assert_eq!(
+        ctx.client.try_report_impact(&ctx.manager, &project.id, &-1),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert!(ctx
+        .client
+        .try_report_impact(&ctx.generate_address(), &project.id, &1)
+        .is_err());
+}
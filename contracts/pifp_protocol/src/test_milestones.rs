@@ -0,0 +1,369 @@
+extern crate std;
+
+use soroban_sdk::{token, vec, Bytes, BytesN, Vec};
+
+use crate::{test_utils::TestContext, Error, Project, ProjectStatus, Role};
+
+fn setup_milestone_project(
+    ctx: &TestContext,
+    milestones: Vec<i128>,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
+    let project = ctx.client.register_project_with_milestones(
+        &ctx.manager,
+        &tokens,
+        &milestones,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    (project, token, sac)
+}
+
+#[test]
+fn test_milestones_release_in_stages() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_milestone_project(&ctx, vec![&ctx.env, 250, 1000]);
+    assert_eq!(project.goal, 1000);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 250);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 750);
+    assert_eq!(ctx.client.get_released_milestones(&project.id), 1);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_milestone_replay_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_milestone_project(&ctx, vec![&ctx.env, 250, 500, 1000]);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+fn test_milestone_out_of_order_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_milestone_project(&ctx, vec![&ctx.env, 250, 500, 1000]);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &ctx.dummy_preimage());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn test_milestone_index_out_of_range() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_milestone_project(&ctx, vec![&ctx.env, 1000]);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &ctx.dummy_preimage());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+fn test_non_increasing_milestones_rejected() {
+    let ctx = TestContext::new();
+    setup_milestone_project(&ctx, vec![&ctx.env, 500, 500]);
+}
+
+fn stage_preimage(ctx: &TestContext, stage: u8) -> Bytes {
+    Bytes::from_array(&ctx.env, &[b's', b't', b'a', b'g', b'e', stage])
+}
+
+fn setup_staged_proof_project(
+    ctx: &TestContext,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
+    let proofs = vec![
+        &ctx.env,
+        ctx.env.crypto().sha256(&stage_preimage(ctx, 0)).into(),
+        ctx.env.crypto().sha256(&stage_preimage(ctx, 1)).into(),
+    ];
+    let project = ctx.client.register_staged_project(
+        &ctx.manager,
+        &tokens,
+        &vec![&ctx.env, 250, 1000],
+        &proofs,
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    (project, token, sac)
+}
+
+#[test]
+fn test_each_milestone_checks_its_own_proof() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_staged_proof_project(&ctx);
+    let stage_one: BytesN<32> = ctx.env.crypto().sha256(&stage_preimage(&ctx, 1)).into();
+    assert_eq!(project.proof_hash, stage_one);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    // Stage 1's proof cannot release stage 0.
+    assert_eq!(
+        ctx.client
+            .try_release_milestone(&ctx.oracle, &project.id, &0, &stage_preimage(&ctx, 1)),
+        Err(Ok(Error::ProofMismatch.into()))
+    );
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &stage_preimage(&ctx, 0));
+    assert_eq!(token.balance(&ctx.manager), 250);
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &stage_preimage(&ctx, 1));
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_staged_proofs_out_of_range_index() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_staged_proof_project(&ctx);
+    assert_eq!(
+        ctx.client
+            .try_release_milestone(&ctx.oracle, &project.id, &2, &stage_preimage(&ctx, 1)),
+        Err(Ok(Error::MilestoneNotFound.into()))
+    );
+}
+
+#[test]
+fn test_staged_proofs_must_match_milestone_count() {
+    let ctx = TestContext::new();
+    let tokens = vec![&ctx.env, ctx.generate_address()];
+    let result = ctx.client.try_register_staged_project(
+        &ctx.manager,
+        &tokens,
+        &vec![&ctx.env, 250, 1000],
+        &vec![&ctx.env, ctx.dummy_proof()],
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidMilestones.into())));
+}
+
+#[test]
+fn test_single_proof_milestone_rejects_wrong_preimage() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_milestone_project(&ctx, vec![&ctx.env, 1000]);
+    assert_eq!(
+        ctx.client
+            .try_release_milestone(&ctx.oracle, &project.id, &0, &stage_preimage(&ctx, 0)),
+        Err(Ok(Error::ProofMismatch.into()))
+    );
+}
+
+#[test]
+fn test_refunds_after_milestone_release_are_pro_rata() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_milestone_project(&ctx, vec![&ctx.env, 250, 1000]);
+    ctx.client
+        .set_allow_partial_release(&ctx.manager, &project.id, &true);
+    let early = ctx.generate_address();
+    let late = ctx.generate_address();
+    sac.mint(&early, &600);
+    sac.mint(&late, &200);
+    ctx.client
+        .deposit(&project.id, &early, &token.address, &600);
+    ctx.client.deposit(&project.id, &late, &token.address, &200);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 200);
+    assert_eq!(
+        ctx.client
+            .try_withdraw(&project.id, &late, &token.address, &100),
+        Err(Ok(Error::ProjectNotActive.into()))
+    );
+
+    // 600 of the 800 raised is left, so each donor gets back three quarters.
+    ctx.advance_past_deadline(project.id);
+    ctx.client
+        .refund_deposit(&project.id, &early, &token.address, &None);
+    assert_eq!(token.balance(&early), 450);
+    assert_eq!(
+        ctx.client
+            .try_refund_deposit(&project.id, &early, &token.address, &None),
+        Err(Ok(Error::NothingToRefund.into()))
+    );
+
+    ctx.client.refund_all(&ctx.admin, &project.id, &None);
+    assert_eq!(token.balance(&late), 150);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+fn setup_funded_milestone_project(
+    ctx: &TestContext,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (project, token, sac) = setup_milestone_project(ctx, vec![&ctx.env, 250, 1000]);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    (project, token, sac)
+}
+
+#[test]
+fn test_milestone_requires_goal() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_milestone_project(&ctx, vec![&ctx.env, 250, 1000]);
+    assert_eq!(
+        ctx.client
+            .try_release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage()),
+        Err(Ok(Error::GoalNotMet.into()))
+    );
+}
+
+#[test]
+fn test_milestone_waits_for_threshold() {
+    let ctx = TestContext::new();
+    let (project, token, _) = setup_funded_milestone_project(&ctx);
+    let second_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &2);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_released_milestones(&project.id), 0);
+
+    ctx.client
+        .release_milestone(&second_oracle, &project.id, &0, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 250);
+    assert!(ctx.client.get_approvals(&project.id).is_empty());
+
+    // The next milestone starts a fresh round.
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 250);
+    ctx.client
+        .release_milestone(&second_oracle, &project.id, &1, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_milestone_waits_out_challenge_period() {
+    let ctx = TestContext::new();
+    let (project, token, _) = setup_funded_milestone_project(&ctx);
+    ctx.client.set_challenge_period(&ctx.admin, &3600);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PendingRelease
+    );
+    assert_eq!(
+        ctx.client.try_finalize_release(&project.id),
+        Err(Ok(Error::WindowNotElapsed.into()))
+    );
+
+    ctx.jump_time(3600);
+    ctx.client.finalize_release(&project.id);
+    assert_eq!(token.balance(&ctx.manager), 250);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &ctx.dummy_preimage());
+    ctx.jump_time(3600);
+    ctx.client.finalize_release(&project.id);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_safe_milestone_release_holds_back_failed_transfers() {
+    let ctx = TestContext::new();
+    ctx.client.set_safe_release(&ctx.admin, &true);
+    let (project, token, _) = setup_funded_milestone_project(&ctx);
+    ctx.client.pause_token(&ctx.admin, &token.address);
+
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &0, &ctx.dummy_preimage());
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_unpaid_payouts(&project.id).len(), 1);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    // Retrying a live milestone project leaves it live.
+    assert_eq!(ctx.client.retry_release(&project.id), 1);
+
+    // The final milestone pays only what earlier payouts do not claim.
+    ctx.client
+        .release_milestone(&ctx.oracle, &project.id, &1, &ctx.dummy_preimage());
+    let unpaid = ctx.client.get_unpaid_payouts(&project.id);
+    assert_eq!(unpaid.get(1).unwrap().amount, 750);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PartiallyReleased
+    );
+
+    ctx.client.unpause_token(&ctx.admin, &token.address);
+    assert_eq!(ctx.client.retry_release(&project.id), 0);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
@@ -0,0 +1,392 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, Error, ProjectStatus, Role};
+use soroban_sdk::vec;
+
+#[test]
+fn test_release_waits_for_threshold() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let second_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &2);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+    assert_eq!(ctx.client.get_approvals(&project.id).len(), 1);
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.client
+        .verify_and_release(&second_oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_default_threshold_is_one() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_verification_threshold(&project.id), 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_same_oracle_cannot_approve_twice() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &2);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_admin_cannot_set_threshold() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .set_verification_threshold(&ctx.manager, &project.id, &2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_zero_threshold_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &0);
+}
+
+#[test]
+fn test_get_authorized_oracles_tracks_role_holders() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(
+        ctx.client.get_authorized_oracles(&project.id),
+        vec![&ctx.env, ctx.oracle.clone()]
+    );
+
+    let second_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    assert_eq!(
+        ctx.client.get_authorized_oracles(&project.id),
+        vec![&ctx.env, ctx.oracle.clone(), second_oracle.clone()]
+    );
+
+    ctx.client
+        .revoke_role(&ctx.admin, &ctx.oracle, &Role::Oracle);
+    ctx.client
+        .revoke_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    assert!(ctx.client.get_authorized_oracles(&project.id).is_empty());
+}
+
+#[test]
+fn test_get_authorized_oracles_unknown_project() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.try_get_authorized_oracles(&99),
+        Err(Ok(Error::ProjectNotFound.into()))
+    );
+}
+
+#[test]
+fn test_assigned_oracle_restricts_verification() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    let project_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &project_oracle, &Role::Oracle);
+    ctx.client
+        .assign_project_oracle(&ctx.manager, &project.id, &project_oracle);
+
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::NotProjectOracle.into()))
+    );
+    assert_eq!(
+        ctx.client.get_authorized_oracles(&project.id),
+        vec![&ctx.env, project_oracle.clone()]
+    );
+
+    ctx.client
+        .verify_and_release(&project_oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_unassigned_project_falls_back_to_global_oracle() {
+    let ctx = TestContext::new();
+    let (assigned, _) = ctx.setup_funded_project(1000);
+    let (open, _) = ctx.setup_funded_project(1000);
+    let project_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &project_oracle, &Role::Oracle);
+    ctx.client
+        .assign_project_oracle(&ctx.admin, &assigned.id, &project_oracle);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &open.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&open.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_assign_project_oracle_by_stranger_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+    assert_eq!(
+        ctx.client
+            .try_assign_project_oracle(&stranger, &project.id, &stranger),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_assigned_oracle_must_hold_oracle_role() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(
+        ctx.client
+            .try_assign_project_oracle(&ctx.manager, &project.id, &ctx.generate_address()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_creator_cannot_be_assigned_as_oracle() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .grant_role(&ctx.admin, &ctx.manager, &Role::Oracle);
+
+    assert_eq!(
+        ctx.client
+            .try_assign_project_oracle(&ctx.admin, &project.id, &ctx.manager),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert!(ctx
+        .client
+        .get_authorized_oracles(&project.id)
+        .contains(&ctx.oracle));
+}
+
+#[test]
+fn test_assign_project_oracle_limit() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    for _ in 0..10 {
+        let oracle = ctx.generate_address();
+        ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+        ctx.client
+            .assign_project_oracle(&ctx.manager, &project.id, &oracle);
+    }
+    // Re-assigning an existing oracle is still a no-op.
+    let first = ctx
+        .client
+        .get_authorized_oracles(&project.id)
+        .get(0)
+        .unwrap();
+    ctx.client
+        .assign_project_oracle(&ctx.manager, &project.id, &first);
+
+    let extra = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &extra, &Role::Oracle);
+    assert_eq!(
+        ctx.client
+            .try_assign_project_oracle(&ctx.manager, &project.id, &extra),
+        Err(Ok(Error::TooManyOracles.into()))
+    );
+    assert_eq!(ctx.client.get_authorized_oracles(&project.id).len(), 10);
+}
+
+#[test]
+fn test_remove_project_oracle_restores_global_oracles() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    let project_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &project_oracle, &Role::Oracle);
+    ctx.client
+        .assign_project_oracle(&ctx.manager, &project.id, &project_oracle);
+
+    let stranger = ctx.generate_address();
+    assert_eq!(
+        ctx.client
+            .try_remove_project_oracle(&stranger, &project.id, &project_oracle),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    ctx.client
+        .remove_project_oracle(&ctx.admin, &project.id, &project_oracle);
+    assert_eq!(
+        ctx.client
+            .try_remove_project_oracle(&ctx.manager, &project.id, &project_oracle),
+        Err(Ok(Error::NotProjectOracle.into()))
+    );
+    assert_eq!(
+        ctx.client.get_authorized_oracles(&project.id),
+        vec![&ctx.env, ctx.oracle.clone(), project_oracle]
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_assigned_oracle_loses_access_with_role() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    let project_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &project_oracle, &Role::Oracle);
+    ctx.client
+        .assign_project_oracle(&ctx.manager, &project.id, &project_oracle);
+    ctx.client
+        .revoke_role(&ctx.admin, &project_oracle, &Role::Oracle);
+
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&project_oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_default_validity_never_expires() {
+    let ctx = TestContext::new();
+    let (project, token) = ctx.setup_funded_project(1000);
+    let second_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &2);
+    assert_eq!(ctx.client.get_verification_validity(&project.id), 0);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    ctx.jump_time(80_000);
+    ctx.client
+        .approve_release(&second_oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_stale_approval_does_not_count() {
+    let ctx = TestContext::new();
+    let (project, token) = ctx.setup_funded_project(1000);
+    let second_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &2);
+    ctx.client
+        .set_verification_validity(&ctx.admin, &project.id, &3600);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    ctx.jump_time(3601);
+    assert_eq!(ctx.client.get_approvals(&project.id).len(), 0);
+
+    ctx.client
+        .approve_release(&second_oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    // The first oracle renews its expired approval.
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_admin_cannot_set_validity() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .set_verification_validity(&ctx.manager, &project.id, &3600);
+}
+
+#[test]
+fn test_approve_release_rejects_public_proof_hash() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    // The stored hash is public; submitting it as the preimage must fail.
+    let public_hash = soroban_sdk::Bytes::from_array(&ctx.env, &ctx.dummy_proof().to_array());
+    assert_eq!(
+        ctx.client
+            .try_approve_release(&ctx.oracle, &project.id, &public_hash),
+        Err(Ok(Error::ProofMismatch.into()))
+    );
+    assert!(ctx.client.get_approvals(&project.id).is_empty());
+}
+
+#[test]
+fn test_revoked_oracle_approval_does_not_count() {
+    let ctx = TestContext::new();
+    let (project, token) = ctx.setup_funded_project(1000);
+    let second_oracle = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &second_oracle, &Role::Oracle);
+    ctx.client
+        .set_verification_threshold(&ctx.admin, &project.id, &2);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    ctx.client
+        .revoke_role(&ctx.admin, &ctx.oracle, &Role::Oracle);
+    assert!(ctx.client.get_approvals(&project.id).is_empty());
+
+    ctx.client
+        .approve_release(&second_oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+    assert_eq!(
+        ctx.client.get_approvals(&project.id),
+        vec![&ctx.env, second_oracle]
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
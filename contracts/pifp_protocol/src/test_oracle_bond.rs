@@ -0,0 +1,127 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, Error, Role};
+
+#[test]
+fn test_verification_requires_minimum_stake() {
+    let ctx = TestContext::new();
+    let (bond, bond_sac) = ctx.create_token();
+    ctx.client.set_oracle_bond(&ctx.admin, &bond.address, &500);
+    assert_eq!(
+        ctx.client.get_oracle_bond(),
+        Some((bond.address.clone(), 500))
+    );
+    let (project, _) = ctx.setup_funded_project(1000);
+
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+
+    bond_sac.mint(&ctx.oracle, &500);
+    ctx.client.stake_oracle(&ctx.oracle, &bond.address, &300);
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    ctx.client.stake_oracle(&ctx.oracle, &bond.address, &200);
+    assert_eq!(ctx.client.get_oracle_stake(&ctx.oracle, &bond.address), 500);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+}
+
+#[test]
+fn test_slash_sends_bond_to_treasury() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (bond, bond_sac) = ctx.create_token();
+    ctx.client.set_oracle_bond(&ctx.admin, &bond.address, &100);
+    bond_sac.mint(&ctx.oracle, &400);
+    ctx.client.stake_oracle(&ctx.oracle, &bond.address, &400);
+
+    assert_eq!(
+        ctx.client.try_slash_oracle(&ctx.admin, &ctx.oracle, &100),
+        Err(Ok(Error::NotInitialized.into()))
+    );
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+    assert_eq!(
+        ctx.client.try_slash_oracle(&ctx.manager, &ctx.oracle, &100),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert_eq!(
+        ctx.client.try_slash_oracle(&ctx.admin, &ctx.oracle, &401),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+
+    ctx.client.slash_oracle(&ctx.admin, &ctx.oracle, &350);
+    assert_eq!(bond.balance(&treasury), 350);
+    assert_eq!(ctx.client.get_oracle_stake(&ctx.oracle, &bond.address), 50);
+
+    // Below the minimum the oracle can no longer verify.
+    let (project, _) = ctx.setup_funded_project(1000);
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_unstake_only_after_role_is_revoked() {
+    let ctx = TestContext::new();
+    let (bond, bond_sac) = ctx.create_token();
+    bond_sac.mint(&ctx.oracle, &300);
+    ctx.client.stake_oracle(&ctx.oracle, &bond.address, &300);
+
+    assert_eq!(
+        ctx.client
+            .try_unstake_oracle(&ctx.oracle, &bond.address, &100),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+
+    ctx.client
+        .revoke_role(&ctx.admin, &ctx.oracle, &Role::Oracle);
+    assert_eq!(
+        ctx.client
+            .try_unstake_oracle(&ctx.oracle, &bond.address, &301),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+    ctx.client.unstake_oracle(&ctx.oracle, &bond.address, &300);
+    assert_eq!(bond.balance(&ctx.oracle), 300);
+    assert_eq!(ctx.client.get_oracle_stake(&ctx.oracle, &bond.address), 0);
+}
+
+#[test]
+fn test_bond_stays_locked_after_last_approval() {
+    let ctx = TestContext::new();
+    let (bond, bond_sac) = ctx.create_token();
+    ctx.client.set_oracle_bond(&ctx.admin, &bond.address, &300);
+    bond_sac.mint(&ctx.oracle, &300);
+    ctx.client.stake_oracle(&ctx.oracle, &bond.address, &300);
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    ctx.client
+        .revoke_role(&ctx.admin, &ctx.oracle, &Role::Oracle);
+    assert_eq!(
+        ctx.client
+            .try_unstake_oracle(&ctx.oracle, &bond.address, &300),
+        Err(Ok(Error::WindowNotElapsed.into()))
+    );
+
+    // A challenge period longer than the default delay extends the lock.
+    ctx.client.set_challenge_period(&ctx.admin, &864_000);
+    ctx.jump_time(604_800);
+    assert_eq!(
+        ctx.client
+            .try_unstake_oracle(&ctx.oracle, &bond.address, &300),
+        Err(Ok(Error::WindowNotElapsed.into()))
+    );
+
+    ctx.jump_time(259_200);
+    ctx.client.unstake_oracle(&ctx.oracle, &bond.address, &300);
+    assert_eq!(bond.balance(&ctx.oracle), 300);
+}
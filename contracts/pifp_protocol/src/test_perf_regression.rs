@@ -6,7 +6,7 @@
 #[cfg(test)]
 mod test {
     use crate::test_utils::TestContext;
-    use soroban_sdk::{vec, BytesN};
+    use soroban_sdk::{vec, BytesN, Vec};
 
     #[test]
     fn test_duplicate_detection_performance_regression() {
@@ -25,6 +25,8 @@ mod test {
             &1000i128,
             &BytesN::from_array(&ctx.env, &[0; 32]),
             &(ctx.env.ledger().timestamp() + 100_000),
+            &ctx.dummy_metadata(),
+            &None,
         );
 
         assert_eq!(project.id, 0);
@@ -36,26 +38,30 @@ mod test {
         let ctx = TestContext::new();
 
         // Setup project with multiple tokens
-        let token1 = ctx.generate_address();
-        let token2 = ctx.generate_address();
-        let token3 = ctx.generate_address();
-        let tokens = vec![&ctx.env, token1.clone(), token2.clone(), token3.clone()];
+        let (token1, sac1) = ctx.create_token();
+        let (token2, _) = ctx.create_token();
+        let (token3, sac3) = ctx.create_token();
+        let tokens = vec![
+            &ctx.env,
+            token1.address.clone(),
+            token2.address.clone(),
+            token3.address.clone(),
+        ];
+        let project = ctx.register_project(&tokens, 1000);
+        ctx.client
+            .set_token_price(&ctx.oracle, &project.id, &token3.address, &10_000_000);
 
-        let project = ctx.client.register_project(
-            &ctx.manager,
-            &tokens,
-            &1000i128,
-            &ctx.dummy_proof(),
-            &(ctx.env.ledger().timestamp() + 100_000),
-        );
+        let donator = ctx.generate_address();
+        sac1.mint(&donator, &100);
+        sac3.mint(&donator, &100);
 
         // Test first token (should be fastest due to early termination)
         ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token1, &100);
+            .deposit(&project.id, &donator, &token1.address, &100);
 
         // Test last token (should still be reasonably fast)
         ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token3, &100);
+            .deposit(&project.id, &donator, &token3.address, &100);
 
         // Verify both deposits succeeded
         let balances = ctx.client.get_project_balances(&project.id);
@@ -69,29 +75,25 @@ mod test {
         let ctx = TestContext::new();
 
         // Setup project with multiple tokens
-        let token1 = ctx.create_token(1_000_000);
-        let token2 = ctx.create_token(1_000_000);
+        let (token1, sac1) = ctx.create_token();
+        let (token2, sac2) = ctx.create_token();
         let tokens = vec![&ctx.env, token1.address.clone(), token2.address.clone()];
-
-        let project = ctx.client.register_project(
-            &ctx.manager,
-            &tokens,
-            &1000i128,
-            &ctx.dummy_proof(),
-            &(ctx.env.ledger().timestamp() + 100_000),
-        );
+        let project = ctx.register_project(&tokens, 800);
+        ctx.client
+            .set_token_price(&ctx.oracle, &project.id, &token2.address, &10_000_000);
 
         // Make deposits to multiple tokens
+        let donator = ctx.generate_address();
+        sac1.mint(&donator, &500);
+        sac2.mint(&donator, &300);
         ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token1.address, &500);
+            .deposit(&project.id, &donator, &token1.address, &500);
         ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token2.address, &300);
+            .deposit(&project.id, &donator, &token2.address, &300);
 
-        // Grant oracle role and verify
-        ctx.client
-            .grant_role(&ctx.admin, &ctx.oracle, &crate::Role::Oracle);
+        // Verify and release
         ctx.client
-            .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+            .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
 
         // Verify all funds were transferred
         let updated_project = ctx.client.get_project(&project.id);
@@ -109,24 +111,11 @@ mod test {
         let ctx = TestContext::new();
 
         // Test that optimized implementation produces same results as original logic
-        let token = ctx.generate_address();
-        let tokens = vec![&ctx.env, token.clone()];
-
-        // Register project
-        let project = ctx.client.register_project(
-            &ctx.manager,
-            &tokens,
-            &1000i128,
-            &ctx.dummy_proof(),
-            &(ctx.env.ledger().timestamp() + 100_000),
-        );
+        let (project, token) = ctx.setup_funded_project(1000);
 
         // Deposit should work with optimized token checking
-        ctx.client
-            .deposit(&project.id, &ctx.generate_address(), &token, &100);
-
-        // Verify deposit succeeded
-        let balance = ctx.client.get_balance(&project.id, token);
-        assert_eq!(balance, 100);
+        let balance = ctx.client.get_balance(&project.id, &token.address);
+        assert_eq!(balance, 1000);
+        assert_eq!(project.status, crate::ProjectStatus::Active);
     }
 }
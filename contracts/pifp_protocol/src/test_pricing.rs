@@ -0,0 +1,152 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::{test_utils::TestContext, Project, ProjectStatus};
+
+fn setup_two_token_project(
+    ctx: &TestContext,
+    goal: i128,
+) -> (
+    Project,
+    soroban_sdk::token::StellarAssetClient<'static>,
+    soroban_sdk::token::StellarAssetClient<'static>,
+) {
+    let (_, sac_a) = ctx.create_token();
+    let (_, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [sac_a.address.clone(), sac_b.address.clone()]);
+    let project = ctx.register_project(&tokens, goal);
+    (project, sac_a, sac_b)
+}
+
+#[test]
+fn test_primary_token_priced_at_par_by_default() {
+    let ctx = TestContext::new();
+    let (project, sac_a, sac_b) = setup_two_token_project(&ctx, 1000);
+
+    assert_eq!(
+        ctx.client.get_token_price(&project.id, &sac_a.address),
+        Some(10_000_000)
+    );
+    assert_eq!(
+        ctx.client.get_token_price(&project.id, &sac_b.address),
+        None
+    );
+}
+
+#[test]
+fn test_priced_deposits_count_toward_goal() {
+    let ctx = TestContext::new();
+    let (project, sac_a, sac_b) = setup_two_token_project(&ctx, 1000);
+    // One unit of token B is worth two goal units.
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &sac_b.address, &20_000_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &600);
+    sac_b.mint(&donator, &200);
+
+    ctx.client
+        .deposit(&project.id, &donator, &sac_a.address, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &sac_b.address, &199);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    // 600 + 2 * 200 = 1000 goal units.
+    ctx.client
+        .deposit(&project.id, &donator, &sac_b.address, &1);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+fn test_deposit_of_unpriced_token_fails() {
+    let ctx = TestContext::new();
+    let (project, _, sac_b) = setup_two_token_project(&ctx, 1000);
+
+    let donator = ctx.generate_address();
+    sac_b.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &sac_b.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_oracle_can_set_token_price() {
+    let ctx = TestContext::new();
+    let (project, _, sac_b) = setup_two_token_project(&ctx, 1000);
+
+    ctx.client
+        .set_token_price(&ctx.manager, &project.id, &sac_b.address, &10_000_000);
+}
+
+#[test]
+fn test_funding_progress_single_token_is_raw_balance() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_funding_progress(&project.id), (0, 1000));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &250);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &250);
+
+    assert_eq!(ctx.client.get_funding_progress(&project.id), (250, 1000));
+}
+
+#[test]
+fn test_funding_progress_normalizes_priced_tokens() {
+    let ctx = TestContext::new();
+    let (project, sac_a, sac_b) = setup_two_token_project(&ctx, 1000);
+    // One unit of token B is worth half a goal unit.
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &sac_b.address, &5_000_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &300);
+    sac_b.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &sac_a.address, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &sac_b.address, &400);
+
+    assert_eq!(ctx.client.get_funding_progress(&project.id), (500, 1000));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_funding_progress_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.get_funding_progress(&42);
+}
+
+#[test]
+fn test_remaining_to_goal_uses_normalized_total() {
+    let ctx = TestContext::new();
+    let (project, sac_a, sac_b) = setup_two_token_project(&ctx, 1000);
+    assert_eq!(ctx.client.get_remaining_to_goal(&project.id), 1000);
+
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &sac_b.address, &20_000_000);
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    sac_b.mint(&donator, &200);
+
+    ctx.client
+        .deposit(&project.id, &donator, &sac_a.address, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &sac_b.address, &100);
+    // 1000 - (300 + 2 * 100)
+    assert_eq!(ctx.client.get_remaining_to_goal(&project.id), 500);
+
+    // Overshooting the goal reports zero, never a negative amount.
+    ctx.client
+        .deposit(&project.id, &donator, &sac_a.address, &700);
+    assert_eq!(ctx.client.get_remaining_to_goal(&project.id), 0);
+}
@@ -0,0 +1,134 @@
+extern crate std;
+
+use soroban_sdk::{Bytes, BytesN, Vec};
+
+use crate::{test_utils::TestContext, Error, ProjectStatus};
+
+#[test]
+fn test_duplicate_proofs_allowed_by_default() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+
+    assert!(!ctx.client.is_unique_proofs());
+    let first = ctx.register_project(&tokens, 1000);
+    let second = ctx.register_project(&tokens, 1000);
+    assert_eq!(first.proof_hash, second.proof_hash);
+}
+
+#[test]
+fn test_unique_proofs_rejects_reused_hash() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+
+    // Registered before the toggle; still indexed.
+    ctx.register_project(&tokens, 1000);
+    ctx.client.set_unique_proofs(&ctx.admin, &true);
+    assert!(ctx.client.is_unique_proofs());
+
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &tokens,
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::DuplicateProof.into())));
+
+    let fresh = BytesN::from_array(&ctx.env, &[0x01; 32]);
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &1000,
+        &fresh,
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(project.proof_hash, fresh);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_admin_toggles_unique_proofs() {
+    let ctx = TestContext::new();
+    ctx.client.set_unique_proofs(&ctx.manager, &true);
+}
+
+#[test]
+fn test_verify_hashes_preimage_on_chain() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_verify_rejects_stored_hash_as_preimage() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+
+    // Submitting the public commitment itself must not pass.
+    let hash_bytes: Bytes = ctx.dummy_proof().into();
+    let result = ctx
+        .client
+        .try_verify_and_release(&ctx.oracle, &project.id, &hash_bytes);
+    assert_eq!(result, Err(Ok(Error::ProofMismatch.into())));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_project_handle_is_derived_from_creator_and_nonce() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [ctx.generate_address()]);
+    let nonce = BytesN::from_array(&ctx.env, &[5u8; 32]);
+    let handle = ctx.client.compute_project_handle(&ctx.manager, &nonce);
+    let register = |nonce: &BytesN<32>| {
+        ctx.client.try_register_project_with_handle(
+            &ctx.manager,
+            &tokens,
+            &1000,
+            &ctx.dummy_proof(),
+            &(ctx.env.ledger().timestamp() + 86400),
+            &ctx.dummy_metadata(),
+            nonce,
+        )
+    };
+
+    // Plain registrations have no handle.
+    let plain = ctx.register_project(&tokens, 1000);
+    assert_eq!(ctx.client.get_project_handle(&plain.id), None);
+
+    let project = register(&nonce).unwrap().unwrap();
+    assert_eq!(
+        ctx.client.get_project_handle(&project.id),
+        Some(handle.clone())
+    );
+    assert_eq!(ctx.client.get_project_by_hash(&handle), project);
+    assert_eq!(project.hard_cap, None);
+    ctx.client
+        .set_hard_cap(&ctx.manager, &project.id, &Some(1500));
+    assert_eq!(ctx.client.get_project_by_hash(&handle).hard_cap, Some(1500));
+
+    // The same creator and nonce cannot be registered twice.
+    assert_eq!(register(&nonce), Err(Ok(Error::HandleTaken.into())));
+
+    // Another creator with the same nonce gets a different handle.
+    let other = ctx.generate_address();
+    assert_ne!(ctx.client.compute_project_handle(&other, &nonce), handle);
+    assert_eq!(
+        ctx.client
+            .try_get_project_by_hash(&BytesN::from_array(&ctx.env, &[0u8; 32])),
+        Err(Ok(Error::ProjectNotFound.into()))
+    );
+}
@@ -0,0 +1,65 @@
+extern crate std;
+
+use soroban_sdk::{vec, Env};
+
+use crate::qf::{compute_qf_match, isqrt};
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_isqrt_floors() {
+    assert_eq!(isqrt(0), 0);
+    assert_eq!(isqrt(-4), 0);
+    assert_eq!(isqrt(1), 1);
+    assert_eq!(isqrt(15), 3);
+    assert_eq!(isqrt(16), 4);
+    assert_eq!(isqrt(1_000_000_000_000), 1_000_000);
+    assert_eq!(isqrt(i128::MAX), 13_043_817_825_332_782_212);
+}
+
+#[test]
+fn test_qf_many_small_donors_beat_one_whale() {
+    let env = Env::default();
+
+    // One donor of 100: (√100)² − 100 = 0.
+    assert_eq!(compute_qf_match(vec![&env, 100]), 0);
+
+    // 100 donors of 1: (100 · √1)² − 100 = 9_900.
+    let mut crowd = vec![&env];
+    for _ in 0..100 {
+        crowd.push_back(1);
+    }
+    assert_eq!(compute_qf_match(crowd), 9_900);
+}
+
+#[test]
+fn test_qf_classic_example() {
+    let env = Env::default();
+
+    // (√4 + √9 + √16)² − (4 + 9 + 16) = 81 − 29 = 52.
+    assert_eq!(compute_qf_match(vec![&env, 4, 9, 16]), 52);
+    // (√1 + √1)² − 2 = 2.
+    assert_eq!(compute_qf_match(vec![&env, 1, 1]), 2);
+    // Empty and non-positive inputs contribute nothing.
+    assert_eq!(compute_qf_match(vec![&env]), 0);
+    assert_eq!(compute_qf_match(vec![&env, 0, -5]), 0);
+}
+
+#[test]
+fn test_get_qf_match_uses_donor_balances() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(100_000);
+
+    for amount in [4i128, 9, 16] {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &amount);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+    }
+
+    assert_eq!(ctx.client.get_qf_match(&project.id, &token.address), 52);
+    assert_eq!(
+        ctx.client
+            .get_qf_match(&project.id, &ctx.generate_address()),
+        0
+    );
+}
@@ -0,0 +1,192 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::RecurringScheduled;
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_schedule_recurring_emits_event_and_approves() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &3600, &4);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("recurring").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: RecurringScheduled = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        RecurringScheduled {
+            project_id: project.id,
+            donator: donator.clone(),
+            token: token.address.clone(),
+            amount: 100,
+            interval_secs: 3600,
+            occurrences: 4,
+        }
+    );
+
+    assert_eq!(token.allowance(&donator, &ctx.client.address), 400);
+    assert_eq!(ctx.client.get_recurring(&project.id).len(), 1);
+}
+
+#[test]
+fn test_execute_due_recurring_runs_until_exhausted() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &3600, &2);
+
+    // First run is due immediately; running again before the interval is a no-op.
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 1);
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+
+    let schedule = ctx.client.get_recurring(&project.id).get(0).unwrap();
+    assert_eq!(schedule.remaining, 1);
+    assert_eq!(schedule.next_run, ctx.env.ledger().timestamp() + 3600);
+
+    ctx.jump_time(3600);
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 1);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+    assert_eq!(token.balance(&donator), 800);
+    assert_eq!(
+        ctx.client
+            .get_donor_contribution(&project.id, &donator)
+            .get(0)
+            .unwrap()
+            .balance,
+        200
+    );
+
+    // The exhausted schedule is gone from storage.
+    assert!(ctx.client.get_recurring(&project.id).is_empty());
+    ctx.jump_time(3600);
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 0);
+}
+
+#[test]
+fn test_recurring_deposit_can_activate_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(200);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &200);
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &60, &2);
+    ctx.client.execute_due_recurring(&project.id);
+    ctx.jump_time(60);
+    ctx.client.execute_due_recurring(&project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_unfunded_run_is_skipped() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let broke = ctx.generate_address();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+
+    ctx.client
+        .schedule_recurring(&broke, &project.id, &token.address, &100, &60, &3);
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &60, &3);
+
+    // The failing run does not block the funded one, and its schedule
+    // stays with one run fewer.
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 1);
+    let schedules = ctx.client.get_recurring(&project.id);
+    assert_eq!(schedules.len(), 2);
+    assert_eq!(schedules.get(0).unwrap().donator, broke);
+    assert_eq!(schedules.get(0).unwrap().remaining, 2);
+
+    sac.mint(&broke, &100);
+    ctx.jump_time(60);
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 2);
+    assert_eq!(token.balance(&broke), 0);
+}
+
+#[test]
+fn test_paused_token_keeps_schedule() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &60, &3);
+
+    ctx.client.pause_token(&ctx.admin, &token.address);
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 0);
+    assert_eq!(ctx.client.get_recurring(&project.id).len(), 1);
+
+    ctx.client.unpause_token(&ctx.admin, &token.address);
+    ctx.jump_time(60);
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 1);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_schedules_dropped_once_project_closes() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &60, &3);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    assert_eq!(ctx.client.execute_due_recurring(&project.id), 0);
+    assert!(ctx.client.get_recurring(&project.id).is_empty());
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_too_many_occurrences_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &60, &53);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_zero_interval_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &0, &2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_schedule_on_cancelled_project_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    let donator = ctx.generate_address();
+
+    ctx.client
+        .schedule_recurring(&donator, &project.id, &token.address, &100, &60, &2);
+}
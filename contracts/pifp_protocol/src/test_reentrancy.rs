@@ -0,0 +1,97 @@
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+use crate::{storage, test_utils::TestContext, Error, PifpProtocolClient};
+
+/// Token whose `transfer` calls straight back into the protocol's `deposit`.
+#[contract]
+struct ReentrantToken;
+
+#[contractimpl]
+impl ReentrantToken {
+    pub fn set_target(env: Env, protocol: Address, project_id: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("target"), &(protocol, project_id));
+    }
+
+    pub fn transfer(env: Env, from: Address, _to: Address, amount: i128) {
+        let (protocol, project_id): (Address, u64) = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("target"))
+            .unwrap();
+        PifpProtocolClient::new(&env, &protocol).deposit(
+            &project_id,
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+    }
+}
+
+#[test]
+fn test_reentrant_token_cannot_deposit_twice() {
+    let ctx = TestContext::new();
+    let token = ctx.env.register(ReentrantToken, ());
+    let tokens = Vec::from_array(&ctx.env, [token.clone()]);
+    let project = ctx.register_project(&tokens, 1000);
+    ReentrantTokenClient::new(&ctx.env, &token).set_target(&ctx.client.address, &project.id);
+
+    let donator = ctx.generate_address();
+    let result = ctx.client.try_deposit(&project.id, &donator, &token, &100);
+
+    assert!(result.is_err());
+    assert_eq!(ctx.client.get_balance(&project.id, &token), 0);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 0);
+}
+
+#[test]
+fn test_guarded_entry_rejected_while_locked() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    ctx.env.as_contract(&ctx.client.address, || {
+        storage::set_locked(&ctx.env, true);
+    });
+
+    assert_eq!(
+        ctx.client
+            .try_deposit(&project.id, &donator, &token.address, &100),
+        Err(Ok(Error::Reentrancy.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_withdraw(&project.id, &donator, &token.address, &100),
+        Err(Ok(Error::Reentrancy.into()))
+    );
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::Reentrancy.into()))
+    );
+}
+
+#[test]
+fn test_lock_released_after_guarded_calls() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200);
+    ctx.client
+        .withdraw(&project.id, &donator, &token.address, &50);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    let locked = ctx
+        .env
+        .as_contract(&ctx.client.address, || storage::is_locked(&ctx.env));
+    assert!(!locked);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 250);
+}
@@ -1,11 +1,14 @@
 extern crate std;
 
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
-    token, Address, BytesN, Env,
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
+    token, Address, BytesN, Env, String, Symbol, TryIntoVal,
 };
 
-use crate::{PifpProtocol, PifpProtocolClient, ProjectStatus, Role};
+use crate::{
+    test_utils::TestContext, Error, PifpProtocol, PifpProtocolClient, ProjectMetadata,
+    ProjectStatus, Role,
+};
 
 fn setup() -> (Env, PifpProtocolClient<'static>) {
     let env = Env::default();
@@ -41,6 +44,14 @@ fn dummy_proof(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[0xabu8; 32])
 }
 
+fn dummy_metadata(env: &Env) -> ProjectMetadata {
+    ProjectMetadata {
+        name: Symbol::new(env, "project"),
+        metadata_uri: String::from_str(env, "ipfs://project"),
+        category: Symbol::new(env, "general"),
+    }
+}
+
 #[test]
 fn test_refund_success_after_expiry() {
     let (env, client, super_admin) = setup_with_init();
@@ -52,8 +63,15 @@ fn test_refund_success_after_expiry() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &dummy_metadata(&env),
+        &None,
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -102,8 +120,15 @@ fn test_refund_fails_when_not_expired() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &dummy_metadata(&env),
+        &None,
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -124,8 +149,15 @@ fn test_refund_double_refund_fails() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &dummy_metadata(&env),
+        &None,
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -159,8 +191,15 @@ fn test_refund_wrong_donator_fails() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &dummy_metadata(&env),
+        &None,
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -179,3 +218,256 @@ fn test_refund_wrong_donator_fails() {
 
     client.refund(&attacker, &project.id, &token.address);
 }
+
+fn setup_expired_deposit() -> (
+    Env,
+    PifpProtocolClient<'static>,
+    Address,
+    token::Client<'static>,
+    u64,
+) {
+    let (env, client, super_admin) = setup_with_init();
+    let creator = Address::generate(&env);
+    let donator = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token(&env, &token_admin);
+    let deadline = env.ledger().timestamp() + 100;
+
+    client.grant_role(&super_admin, &creator, &Role::ProjectManager);
+    let tokens = soroban_sdk::vec![&env, token.address.clone()];
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &dummy_metadata(&env),
+        &None,
+    );
+
+    let token_sac = token::StellarAssetClient::new(&env, &token.address);
+    token_sac.mint(&donator, &1_000i128);
+    client.deposit(&project.id, &donator, &token.address, &400i128);
+
+    let mut ledger = env.ledger().get();
+    ledger.timestamp = deadline + 1;
+    env.ledger().set(ledger);
+
+    (env, client, donator, token, project.id)
+}
+
+#[test]
+fn test_refund_deposit_returns_balance() {
+    let (_env, client, donator, token, project_id) = setup_expired_deposit();
+
+    client.refund_deposit(&project_id, &donator, &token.address, &None);
+
+    assert_eq!(token.balance(&donator), 1_000i128);
+    assert_eq!(token.balance(&client.address), 0i128);
+    assert_eq!(client.get_balance(&project_id, &token.address), 0i128);
+    assert_eq!(
+        client.get_project(&project_id).status,
+        ProjectStatus::Expired
+    );
+}
+
+#[test]
+fn test_refund_deposit_to_other_address() {
+    let (env, client, donator, token, project_id) = setup_expired_deposit();
+    let treasury = Address::generate(&env);
+
+    client.refund_deposit(
+        &project_id,
+        &donator,
+        &token.address,
+        &Some(treasury.clone()),
+    );
+    // The original donator authorizes the chosen destination.
+    assert_eq!(env.auths()[0].0, donator);
+    let last_event = env.events().all().last().expect("No events found");
+
+    assert_eq!(token.balance(&treasury), 400i128);
+    assert_eq!(token.balance(&donator), 600i128);
+
+    let event_data: (Address, i128, Address) = last_event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data, (donator, 400i128, treasury));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_refund_deposit_twice_fails() {
+    let (_env, client, donator, token, project_id) = setup_expired_deposit();
+
+    client.refund_deposit(&project_id, &donator, &token.address, &None);
+    client.refund_deposit(&project_id, &donator, &token.address, &None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_refund_deposit_fails_when_not_expired() {
+    let (env, client, super_admin) = setup_with_init();
+    let creator = Address::generate(&env);
+    let donator = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = create_token(&env, &token_admin);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    client.grant_role(&super_admin, &creator, &Role::ProjectManager);
+    let tokens = soroban_sdk::vec![&env, token.address.clone()];
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &dummy_metadata(&env),
+        &None,
+    );
+
+    let token_sac = token::StellarAssetClient::new(&env, &token.address);
+    token_sac.mint(&donator, &1_000i128);
+    client.deposit(&project.id, &donator, &token.address, &400i128);
+
+    client.refund_deposit(&project.id, &donator, &token.address, &None);
+}
+
+#[test]
+fn test_refund_all_pays_every_donator() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donators = [
+        ctx.generate_address(),
+        ctx.generate_address(),
+        ctx.generate_address(),
+    ];
+    for (i, donator) in donators.iter().enumerate() {
+        let amount = 100 * (i as i128 + 1);
+        sac.mint(donator, &amount);
+        ctx.client
+            .deposit(&project.id, donator, &token.address, &amount);
+    }
+
+    ctx.advance_past_deadline(project.id);
+    let processed = ctx.client.refund_all(&ctx.admin, &project.id, &None);
+
+    assert_eq!(processed, 3);
+    for (i, donator) in donators.iter().enumerate() {
+        assert_eq!(token.balance(donator), 100 * (i as i128 + 1));
+    }
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(ctx.client.refund_all(&ctx.admin, &project.id, &None), 0);
+}
+
+#[test]
+fn test_refund_all_paginates_and_skips_refunded() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let first = ctx.generate_address();
+    let second = ctx.generate_address();
+    let third = ctx.generate_address();
+    for donator in [&first, &second, &third] {
+        sac.mint(donator, &100);
+        ctx.client
+            .deposit(&project.id, donator, &token.address, &100);
+    }
+
+    ctx.advance_past_deadline(project.id);
+    ctx.client
+        .refund_deposit(&project.id, &second, &token.address, &None);
+
+    assert_eq!(ctx.client.refund_all(&ctx.admin, &project.id, &Some(1)), 1);
+    assert_eq!(token.balance(&first), 100);
+    assert_eq!(token.balance(&third), 0);
+
+    // The already-refunded entry uses up this call's budget.
+    assert_eq!(ctx.client.refund_all(&ctx.admin, &project.id, &Some(1)), 1);
+    assert_eq!(token.balance(&third), 0);
+
+    assert_eq!(ctx.client.refund_all(&ctx.admin, &project.id, &Some(1)), 1);
+    assert_eq!(token.balance(&third), 100);
+
+    assert_eq!(ctx.client.refund_all(&ctx.admin, &project.id, &Some(1)), 0);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_refund_all_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.advance_past_deadline(project.id);
+    ctx.client
+        .refund_all(&ctx.generate_address(), &project.id, &None);
+}
+
+#[test]
+fn test_sweep_unrefunded_after_window() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+    ctx.client.set_refund_window(&ctx.admin, &3600);
+
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let early = ctx.generate_address();
+    let late = ctx.generate_address();
+    for donator in [&early, &late] {
+        sac.mint(donator, &100);
+        ctx.client
+            .deposit(&project.id, donator, &token.address, &100);
+    }
+    ctx.advance_past_deadline(project.id);
+    ctx.client.expire_project(&project.id);
+
+    // Inside the window only donors move funds.
+    ctx.client
+        .refund_deposit(&project.id, &early, &token.address, &None);
+    assert_eq!(
+        ctx.client.try_sweep_unrefunded(&ctx.admin, &project.id),
+        Err(Ok(Error::WindowNotElapsed.into()))
+    );
+
+    ctx.jump_time(3600);
+    ctx.client.sweep_unrefunded(&ctx.admin, &project.id);
+    let last_event = ctx.env.events().all().last().unwrap();
+    let topic: Symbol = last_event.1.get(0).unwrap().try_into_val(&ctx.env).unwrap();
+    assert_eq!(topic, Symbol::new(&ctx.env, "swept"));
+
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(
+        ctx.client
+            .try_refund_deposit(&project.id, &late, &token.address, &None),
+        Err(Ok(Error::NothingToRefund.into()))
+    );
+    assert_eq!(
+        ctx.client.try_sweep_unrefunded(&ctx.admin, &project.id),
+        Err(Ok(Error::NothingToRefund.into()))
+    );
+}
+
+#[test]
+fn test_sweep_disabled_by_default() {
+    let ctx = TestContext::new();
+    ctx.client.set_treasury(&ctx.admin, &ctx.generate_address());
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.advance_past_deadline(project.id);
+    ctx.client.expire_project(&project.id);
+    ctx.jump_time(1_000_000);
+
+    assert_eq!(ctx.client.get_refund_window(), 0);
+    assert_eq!(
+        ctx.client.try_sweep_unrefunded(&ctx.admin, &project.id),
+        Err(Ok(Error::WindowNotElapsed.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_sweep_unrefunded_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.advance_past_deadline(project.id);
+    ctx.client
+        .sweep_unrefunded(&ctx.generate_address(), &project.id);
+}
@@ -0,0 +1,131 @@
+extern crate std;
+
+use soroban_sdk::{testutils::IssuerFlags, token, Vec};
+
+use crate::{test_utils::TestContext, Error, Project, ProjectStatus};
+
+/// Register a two-token project, both priced 1:1, funded with 1000 of the
+/// first token and 300 of the second.
+fn setup_two_token_project(
+    ctx: &TestContext,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token_a, sac_a) = ctx.create_token();
+    // Revocable, so tests can make transfers to the creator fail.
+    let asset_b = ctx
+        .env
+        .register_stellar_asset_contract_v2(ctx.admin.clone());
+    asset_b.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token_b = token::Client::new(&ctx.env, &asset_b.address());
+    let sac_b = token::StellarAssetClient::new(&ctx.env, &asset_b.address());
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000);
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &token_b.address, &10_000_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    sac_b.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &300);
+    (project, token_a, token_b, sac_b)
+}
+
+#[test]
+fn test_safe_release_skips_paused_token_until_retry() {
+    let ctx = TestContext::new();
+    ctx.client.set_safe_release(&ctx.admin, &true);
+    assert!(ctx.client.is_safe_release());
+    let (project, token_a, token_b, _) = setup_two_token_project(&ctx);
+    ctx.client.pause_token(&ctx.admin, &token_b.address);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    assert_eq!(token_a.balance(&ctx.manager), 1000);
+    assert_eq!(token_b.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token_a.address), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token_b.address), 300);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PartiallyReleased
+    );
+    let unpaid = ctx.client.get_unpaid_payouts(&project.id);
+    assert_eq!(unpaid.len(), 1);
+    assert_eq!(unpaid.get(0).unwrap().amount, 300);
+
+    // Still paused: nothing moves.
+    assert_eq!(ctx.client.retry_release(&project.id), 1);
+
+    ctx.client.unpause_token(&ctx.admin, &token_b.address);
+    assert_eq!(ctx.client.retry_release(&project.id), 0);
+    assert_eq!(token_b.balance(&ctx.manager), 300);
+    assert_eq!(ctx.client.get_balance(&project.id, &token_b.address), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert!(ctx.client.get_unpaid_payouts(&project.id).is_empty());
+}
+
+#[test]
+fn test_safe_release_survives_rejected_transfer() {
+    let ctx = TestContext::new();
+    ctx.client.set_safe_release(&ctx.admin, &true);
+    let (project, token_a, token_b, sac_b) = setup_two_token_project(&ctx);
+    sac_b.set_authorized(&ctx.manager, &false);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(token_a.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token_b.address), 300);
+    assert_eq!(ctx.client.get_stats().partially_released, 1);
+
+    sac_b.set_authorized(&ctx.manager, &true);
+    assert_eq!(ctx.client.retry_release(&project.id), 0);
+    assert_eq!(token_b.balance(&ctx.manager), 300);
+    let stats = ctx.client.get_stats();
+    assert_eq!(stats.partially_released, 0);
+    assert_eq!(stats.completed, 1);
+}
+
+#[test]
+fn test_release_without_safe_mode_aborts_on_paused_token() {
+    let ctx = TestContext::new();
+    let (project, token_a, token_b, _) = setup_two_token_project(&ctx);
+    ctx.client.pause_token(&ctx.admin, &token_b.address);
+
+    assert_eq!(
+        ctx.client
+            .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage()),
+        Err(Ok(Error::TokenPaused.into()))
+    );
+    assert_eq!(token_a.balance(&ctx.manager), 0);
+}
+
+#[test]
+fn test_retry_release_requires_partial_release() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    assert_eq!(
+        ctx.client.try_retry_release(&project.id),
+        Err(Ok(Error::InvalidTransition.into()))
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(
+        ctx.client.try_retry_release(&project.id),
+        Err(Ok(Error::InvalidTransition.into()))
+    );
+    assert_eq!(
+        ctx.client.try_set_safe_release(&ctx.manager, &true),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
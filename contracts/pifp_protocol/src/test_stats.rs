@@ -0,0 +1,173 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, vec};
+
+use crate::{test_utils::TestContext, GlobalConfig, RecentAction, TokenBalance};
+
+#[test]
+fn test_stats_start_empty() {
+    let ctx = TestContext::new();
+    let stats = ctx.client.get_stats();
+    assert_eq!(stats.project_count, 0);
+    assert_eq!(stats.funding, 0);
+    assert!(stats.total_locked.is_empty());
+}
+
+#[test]
+fn test_stats_track_transitions_and_locked_value() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
+    let released = ctx.register_project(&tokens, 500);
+    let cancelled = ctx.register_project(&tokens, 500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &700);
+    ctx.client
+        .deposit(&released.id, &donator, &token.address, &500);
+    ctx.client
+        .deposit(&cancelled.id, &donator, &token.address, &200);
+
+    let stats = ctx.client.get_stats();
+    assert_eq!(stats.project_count, 2);
+    assert_eq!((stats.funding, stats.active), (1, 1));
+    assert_eq!(
+        stats.total_locked,
+        vec![
+            &ctx.env,
+            TokenBalance {
+                token: token.address.clone(),
+                balance: 700,
+            }
+        ]
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &released.id, &ctx.dummy_preimage());
+    ctx.client.cancel_project(&ctx.manager, &cancelled.id);
+
+    let stats = ctx.client.get_stats();
+    assert_eq!((stats.funding, stats.active), (0, 0));
+    assert_eq!((stats.completed, stats.cancelled), (1, 1));
+    assert_eq!(stats.total_locked.get(0).unwrap().balance, 200);
+
+    ctx.client.refund(&donator, &cancelled.id, &token.address);
+    assert!(ctx.client.get_stats().total_locked.is_empty());
+}
+
+#[test]
+fn test_recent_actions_log_lifecycle() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    ctx.advance_past_deadline(project.id);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    let now = ctx.env.ledger().timestamp();
+    let actions = ctx.client.get_recent_actions();
+    assert_eq!(actions.len(), 3);
+    assert_eq!(actions.get(0).unwrap().action, symbol_short!("deposit"));
+    assert_eq!(actions.get(1).unwrap().action, symbol_short!("expire"));
+    assert_eq!(
+        actions.get(2).unwrap(),
+        RecentAction {
+            action: symbol_short!("refund"),
+            project_id: project.id,
+            timestamp: now,
+        }
+    );
+}
+
+#[test]
+fn test_recent_actions_keep_latest_32() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &40);
+    for _ in 0..40 {
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &1);
+    }
+    let (second, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &second.id, &ctx.dummy_preimage());
+
+    let actions = ctx.client.get_recent_actions();
+    assert_eq!(actions.len(), 32);
+    // The funded project adds one deposit, then its release.
+    assert_eq!(actions.get(30).unwrap().project_id, second.id);
+    assert_eq!(actions.last().unwrap().action, symbol_short!("release"));
+}
+
+#[test]
+fn test_config_reports_defaults_and_updates() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.get_config(),
+        GlobalConfig {
+            version: ctx.client.get_version(),
+            paused: false,
+            fee_bps: 0,
+            treasury: None,
+            fee_tiers: vec![&ctx.env],
+            native_token: None,
+            min_funding_window: ctx.client.get_min_funding_window(),
+            unique_proofs: false,
+            safe_release: false,
+            allowed_tokens: vec![&ctx.env],
+            grace_period: 0,
+            max_tokens: 10,
+            min_goal: ctx.client.get_min_goal(),
+            max_active_per_creator: None,
+            refund_window: 0,
+            challenge_period: 0,
+            match_ratio_bps: 10_000,
+            oracle_bond_token: None,
+            min_oracle_stake: 0,
+        }
+    );
+
+    let treasury = ctx.generate_address();
+    ctx.client.set_fee_bps(&ctx.admin, &250);
+    ctx.client.set_treasury(&ctx.admin, &treasury);
+    ctx.client.set_grace_period(&ctx.admin, &3600);
+    ctx.client.set_max_tokens(&ctx.admin, &4);
+    ctx.client.set_min_goal(&ctx.admin, &500);
+    ctx.client.pause(&ctx.admin);
+
+    let config = ctx.client.get_config();
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.treasury, Some(treasury));
+    assert_eq!(config.grace_period, 3600);
+    assert_eq!(config.max_tokens, 4);
+    assert_eq!(config.min_goal, 500);
+    assert!(config.paused);
+}
+
+#[test]
+fn test_total_locked_matches_contract_balance() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
+    let released = ctx.register_project(&tokens, 500);
+    let open = ctx.register_project(&tokens, 500);
+    assert_eq!(ctx.client.get_total_locked(&token.address), 0);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &900);
+    ctx.client
+        .deposit(&released.id, &donator, &token.address, &500);
+    ctx.client.deposit(&open.id, &donator, &token.address, &400);
+    ctx.client
+        .withdraw(&open.id, &donator, &token.address, &150);
+    assert_eq!(ctx.client.get_total_locked(&token.address), 750);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &released.id, &ctx.dummy_preimage());
+    assert_eq!(ctx.client.get_total_locked(&token.address), 250);
+    assert_eq!(token.balance(&ctx.client.address), 250);
+}
@@ -0,0 +1,70 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_history_tracks_funding_active_completed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let registered_at = ctx.env.ledger().timestamp();
+
+    ctx.jump_time(10);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.jump_time(10);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    let history = ctx.client.get_status_history(&project.id);
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history.get(0).unwrap(),
+        (ProjectStatus::Funding, registered_at)
+    );
+    assert_eq!(
+        history.get(1).unwrap(),
+        (ProjectStatus::Active, registered_at + 10)
+    );
+    assert_eq!(
+        history.get(2).unwrap(),
+        (ProjectStatus::Completed, registered_at + 20)
+    );
+}
+
+#[test]
+fn test_history_records_expiry() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.advance_past_deadline(project.id);
+    ctx.client.expire_project(&project.id);
+
+    let history = ctx.client.get_status_history(&project.id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().0, ProjectStatus::Funding);
+    assert_eq!(
+        history.get(1).unwrap(),
+        (ProjectStatus::Expired, ctx.env.ledger().timestamp())
+    );
+}
+
+#[test]
+fn test_history_records_cancellation() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    let history = ctx.client.get_status_history(&project.id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(1).unwrap().0, ProjectStatus::Cancelled);
+}
+
+#[test]
+fn test_history_empty_for_unknown_project() {
+    let ctx = TestContext::new();
+    assert!(ctx.client.get_status_history(&7).is_empty());
+}
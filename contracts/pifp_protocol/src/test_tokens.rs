@@ -0,0 +1,360 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, Address, IntoVal, TryIntoVal};
+
+use crate::{test_utils::TestContext, Error};
+
+#[test]
+fn test_add_accepted_token_emits_event() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    let (extra, _) = ctx.create_token();
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &extra.address);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("tok_added").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: Address = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(event_data, extra.address);
+
+    let loaded = ctx.client.get_project(&project.id);
+    assert_eq!(
+        loaded.accepted_tokens,
+        vec![&ctx.env, token.address.clone(), extra.address.clone()]
+    );
+    assert_eq!(
+        ctx.client.get_project_balances(&project.id).balances.len(),
+        2
+    );
+}
+
+#[test]
+fn test_deposit_in_added_token() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let (extra, extra_sac) = ctx.create_token();
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &extra.address);
+    ctx.client
+        .set_token_price(&ctx.oracle, &project.id, &extra.address, &10_000_000);
+
+    let donator = ctx.generate_address();
+    extra_sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &extra.address, &400);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &extra.address), 400);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_add_duplicate_token_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_add_token_beyond_cap_rejected() {
+    let ctx = TestContext::new();
+    let mut tokens = vec![&ctx.env];
+    for _ in 0..10 {
+        tokens.push_back(ctx.generate_address());
+    }
+    let project = ctx.register_project(&tokens, 1000);
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &ctx.generate_address());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_add_token_by_non_creator_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    ctx.client
+        .add_accepted_token(&stranger, &project.id, &ctx.generate_address());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_add_token_after_completion_rejected() {
+    let ctx = TestContext::new();
+    let (project, _) = ctx.setup_funded_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &ctx.generate_address());
+}
+
+fn addresses(ctx: &TestContext, n: u32) -> soroban_sdk::Vec<Address> {
+    let mut tokens = vec![&ctx.env];
+    for _ in 0..n {
+        tokens.push_back(ctx.generate_address());
+    }
+    tokens
+}
+
+#[test]
+fn test_raising_max_tokens_allows_larger_projects() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_max_tokens(), 10);
+
+    ctx.client.set_max_tokens(&ctx.admin, &12);
+    let project = ctx.register_project(&addresses(&ctx, 11), 1000);
+    assert_eq!(project.accepted_tokens.len(), 11);
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &ctx.generate_address());
+    assert_eq!(
+        ctx.client
+            .try_add_accepted_token(&ctx.manager, &project.id, &ctx.generate_address()),
+        Err(Ok(Error::TooManyTokens.into()))
+    );
+}
+
+#[test]
+fn test_lowering_max_tokens_rejects_registration() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_tokens(&ctx.admin, &2);
+
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &addresses(&ctx, 3),
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::TooManyTokens.into())));
+}
+
+#[test]
+fn test_set_max_tokens_guards() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.try_set_max_tokens(&ctx.admin, &0),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    assert!(ctx.client.try_set_max_tokens(&ctx.manager, &20).is_err());
+}
+
+#[test]
+fn test_token_allowlist_gates_registration_and_additions() {
+    let ctx = TestContext::new();
+    let (allowed, _) = ctx.create_token();
+    let (other, _) = ctx.create_token();
+    assert!(ctx.client.is_token_allowed(&other.address));
+
+    ctx.client.allow_token(&ctx.admin, &allowed.address);
+    assert!(ctx.client.is_token_allowed(&allowed.address));
+    assert!(!ctx.client.is_token_allowed(&other.address));
+
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &vec![&ctx.env, allowed.address.clone(), other.address.clone()],
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::TokenNotAllowed.into())));
+
+    let project = ctx.register_project(&vec![&ctx.env, allowed.address.clone()], 1000);
+    assert_eq!(
+        ctx.client
+            .try_add_accepted_token(&ctx.manager, &project.id, &other.address),
+        Err(Ok(Error::TokenNotAllowed.into()))
+    );
+
+    // Emptying the list allows every token again.
+    ctx.client.disallow_token(&ctx.admin, &allowed.address);
+    assert!(ctx.client.get_config().allowed_tokens.is_empty());
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &other.address);
+}
+
+#[test]
+fn test_token_allowlist_requires_admin() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    assert_eq!(
+        ctx.client.try_allow_token(&ctx.manager, &token.address),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    ctx.client.allow_token(&ctx.admin, &token.address);
+    assert_eq!(
+        ctx.client.try_disallow_token(&ctx.manager, &token.address),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_project_balances_follow_accepted_token_order() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let (token_c, sac_c) = ctx.create_token();
+    let tokens = vec![
+        &ctx.env,
+        token_a.address.clone(),
+        token_b.address.clone(),
+        token_c.address.clone(),
+    ];
+    let project = ctx.register_project(&tokens, 10_000);
+    for token in [&token_b, &token_c] {
+        ctx.client
+            .set_token_price(&ctx.oracle, &project.id, &token.address, &10_000_000);
+    }
+
+    // Deposit in reverse order of acceptance.
+    let donator = ctx.generate_address();
+    sac_c.mint(&donator, &30);
+    sac_b.mint(&donator, &20);
+    sac_a.mint(&donator, &10);
+    ctx.client
+        .deposit(&project.id, &donator, &token_c.address, &30);
+    ctx.client
+        .deposit(&project.id, &donator, &token_b.address, &20);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &10);
+
+    let balances = ctx.client.get_project_balances(&project.id).balances;
+    assert_eq!(balances.len(), 3);
+    for (i, (token, amount)) in [(&token_a, 10), (&token_b, 20), (&token_c, 30)]
+        .into_iter()
+        .enumerate()
+    {
+        let entry = balances.get(i as u32).unwrap();
+        assert_eq!(entry.token, token.address);
+        assert_eq!(entry.balance, amount);
+    }
+}
+
+#[test]
+fn test_deposit_native_and_release() {
+    let ctx = TestContext::new();
+    // Tests stand in a Stellar Asset Contract for the native asset.
+    let (native, native_sac) = ctx.create_token();
+    ctx.client.set_native_token(&ctx.admin, &native.address);
+    assert_eq!(ctx.client.get_native_token(), Some(native.address.clone()));
+
+    let project = ctx.register_project(&vec![&ctx.env, native.address.clone()], 1000);
+    let donator = ctx.generate_address();
+    native_sac.mint(&donator, &1000);
+    ctx.client.deposit_native(&project.id, &donator, &1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &native.address), 1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    assert_eq!(native.balance(&ctx.manager), 1000);
+    assert_eq!(native.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_deposit_native_refund_after_cancel() {
+    let ctx = TestContext::new();
+    let (native, native_sac) = ctx.create_token();
+    ctx.client.set_native_token(&ctx.admin, &native.address);
+
+    let project = ctx.register_project(&vec![&ctx.env, native.address.clone()], 1000);
+    let donator = ctx.generate_address();
+    native_sac.mint(&donator, &400);
+    ctx.client.deposit_native(&project.id, &donator, &400);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    ctx.client.refund(&donator, &project.id, &native.address);
+    assert_eq!(native.balance(&donator), 400);
+}
+
+#[test]
+fn test_deposit_native_guards() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    assert_eq!(
+        ctx.client.try_deposit_native(&project.id, &donator, &10),
+        Err(Ok(Error::NotInitialized.into()))
+    );
+
+    // A project that does not accept the native token rejects it.
+    let (native, _) = ctx.create_token();
+    ctx.client.set_native_token(&ctx.admin, &native.address);
+    assert_eq!(
+        ctx.client.try_deposit_native(&project.id, &donator, &10),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+    assert!(ctx
+        .client
+        .try_set_native_token(&ctx.manager, &native.address)
+        .is_err());
+}
+
+#[test]
+fn test_project_balances_paged() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_tokens(&ctx.admin, &12);
+    let tokens = addresses(&ctx, 12);
+    let project = ctx.register_project(&tokens, 1000);
+
+    let page = ctx
+        .client
+        .get_project_balances_paged(&project.id, &5, &4)
+        .balances;
+    assert_eq!(page.len(), 4);
+    for (i, entry) in page.iter().enumerate() {
+        assert_eq!(entry.token, tokens.get(5 + i as u32).unwrap());
+        assert_eq!(entry.balance, 0);
+    }
+
+    let tail = ctx
+        .client
+        .get_project_balances_paged(&project.id, &10, &50)
+        .balances;
+    assert_eq!(tail.len(), 2);
+    assert!(ctx
+        .client
+        .get_project_balances_paged(&project.id, &12, &5)
+        .balances
+        .is_empty());
+    assert_eq!(
+        ctx.client.get_project_balances(&project.id).balances.len(),
+        12
+    );
+}
+
+#[test]
+fn test_get_accepted_tokens_in_registration_order() {
+    let ctx = TestContext::new();
+    let tokens = addresses(&ctx, 3);
+    let project = ctx.register_project(&tokens, 1000);
+    assert_eq!(ctx.client.get_accepted_tokens(&project.id), tokens);
+
+    let extra = ctx.generate_address();
+    ctx.client
+        .add_accepted_token(&ctx.manager, &project.id, &extra);
+    let mut expected = tokens.clone();
+    expected.push_back(extra);
+    assert_eq!(ctx.client.get_accepted_tokens(&project.id), expected);
+
+    assert_eq!(
+        ctx.client.try_get_accepted_tokens(&99),
+        Err(Ok(Error::ProjectNotFound.into()))
+    );
+}
@@ -0,0 +1,66 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Events, Ledger},
+    vec, IntoVal, Symbol, TryIntoVal,
+};
+
+use crate::events::TtlBumped;
+use crate::{test_utils::TestContext, Error};
+
+fn jump_to(ctx: &TestContext, sequence_number: u32) {
+    ctx.env
+        .ledger()
+        .with_mut(|li| li.sequence_number = sequence_number);
+}
+
+#[test]
+fn test_bumped_project_survives_ledger_jump() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    // Entries written at ledger 100 live until about ledger 1100.
+    jump_to(&ctx, 900);
+    ctx.client.bump_project_ttl(&project.id, &900);
+
+    jump_to(&ctx, 1700);
+    let loaded = ctx.client.get_project(&project.id);
+    assert_eq!(loaded.id, project.id);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_bump_project_ttl_caps_and_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let max_ttl = ctx
+        .env
+        .as_contract(&ctx.client.address, || ctx.env.storage().max_ttl());
+
+    ctx.client.bump_project_ttl(&project.id, &u32::MAX);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        Symbol::new(&ctx.env, "ttl_bumped").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: TtlBumped = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        TtlBumped {
+            project_id: project.id,
+            ledgers: max_ttl,
+        }
+    );
+}
+
+#[test]
+fn test_bump_project_ttl_unknown_project() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.try_bump_project_ttl(&7, &100),
+        Err(Ok(Error::ProjectNotFound.into()))
+    );
+}
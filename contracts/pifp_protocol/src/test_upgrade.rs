@@ -0,0 +1,77 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Deployer as _, Events},
+    vec, Bytes, BytesN, IntoVal, TryIntoVal,
+};
+
+use crate::events::Upgraded;
+use crate::test_utils::TestContext;
+
+/// Smallest Wasm module the host accepts: the header plus the
+/// `contractenvmetav0` section declaring protocol 22.
+const EMPTY_CONTRACT_WASM: [u8; 40] = [
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic, version
+    0x00, 0x1e, 0x11, // custom section, size, name length
+    b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v',
+    b'0', // name
+    0x00, 0x00, 0x00, 0x00, // SC_ENV_META_KIND_INTERFACE_VERSION
+    0x00, 0x00, 0x00, 0x16, // protocol 22
+    0x00, 0x00, 0x00, 0x00, // pre-release 0
+];
+
+fn upload_empty_wasm(ctx: &TestContext) -> BytesN<32> {
+    ctx.env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_array(&ctx.env, &EMPTY_CONTRACT_WASM))
+}
+
+#[test]
+fn test_version_starts_at_one() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_version(), 1);
+}
+
+#[test]
+fn test_upgrade_installs_wasm_and_bumps_version() {
+    let ctx = TestContext::new();
+    let wasm_hash = upload_empty_wasm(&ctx);
+
+    ctx.client.upgrade(&ctx.admin, &wasm_hash);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![&ctx.env, symbol_short!("upgraded").into_val(&ctx.env)];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: Upgraded = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        Upgraded {
+            new_wasm_hash: wasm_hash,
+            version: 2,
+        }
+    );
+
+    // The instance now points at the uploaded code entry.
+    assert!(
+        ctx.env
+            .deployer()
+            .get_contract_code_ttl(&ctx.client.address)
+            > 0
+    );
+
+    // The new code exports nothing, so read the version straight from storage.
+    let version = ctx.env.as_contract(&ctx.client.address, || {
+        crate::storage::get_version(&ctx.env)
+    });
+    assert_eq!(version, 2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_upgrade_requires_admin() {
+    let ctx = TestContext::new();
+    let wasm_hash = upload_empty_wasm(&ctx);
+
+    ctx.client.upgrade(&ctx.manager, &wasm_hash);
+}
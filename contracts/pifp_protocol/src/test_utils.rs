@@ -2,10 +2,10 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
-    token, Address, BytesN, Env, Vec,
+    token, Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 
-use crate::{types::Project, PifpProtocol, PifpProtocolClient, Role};
+use crate::{types::Project, PifpProtocol, PifpProtocolClient, ProjectMetadata, Role};
 
 pub struct TestContext {
     pub env: Env,
@@ -76,15 +76,63 @@ impl TestContext {
         (project, token, sac)
     }
 
+    /// Register a single-token project and deposit exactly `goal` from a
+    /// fresh donator, leaving it `Active` and ready for `verify_and_release`.
+    ///
+    /// Grants no roles: it relies on the `manager` and `oracle` set up by
+    /// [`TestContext::new`].
+    pub fn setup_funded_project(&self, goal: i128) -> (Project, token::Client<'static>) {
+        let (project, token, sac) = self.setup_project(goal);
+        let donator = self.generate_address();
+        sac.mint(&donator, &goal);
+        self.client
+            .deposit(&project.id, &donator, &token.address, &goal);
+        let project = self.client.get_project(&project.id);
+        (project, token)
+    }
+
     pub fn register_project(&self, tokens: &Vec<Address>, goal: i128) -> Project {
         let proof_hash = self.dummy_proof();
         let deadline = self.env.ledger().timestamp() + 86400;
-        self.client
-            .register_project(&self.manager, tokens, &goal, &proof_hash, &deadline)
+        self.client.register_project(
+            &self.manager,
+            tokens,
+            &goal,
+            &proof_hash,
+            &deadline,
+            &self.dummy_metadata(),
+            &None,
+        )
     }
 
+    /// Preimage the oracle submits to `verify_and_release`.
+    pub fn dummy_preimage(&self) -> Bytes {
+        Bytes::from_slice(&self.env, b"proof-of-impact")
+    }
+
+    /// SHA-256 of [`TestContext::dummy_preimage`], committed at registration.
     pub fn dummy_proof(&self) -> BytesN<32> {
-        BytesN::from_array(&self.env, &[0xabu8; 32])
+        self.env.crypto().sha256(&self.dummy_preimage()).into()
+    }
+
+    pub fn dummy_name(&self) -> Symbol {
+        Symbol::new(&self.env, "project")
+    }
+
+    pub fn dummy_metadata_uri(&self) -> String {
+        String::from_str(&self.env, "ipfs://project")
+    }
+
+    pub fn dummy_category(&self) -> Symbol {
+        Symbol::new(&self.env, "general")
+    }
+
+    pub fn dummy_metadata(&self) -> ProjectMetadata {
+        ProjectMetadata {
+            name: self.dummy_name(),
+            metadata_uri: self.dummy_metadata_uri(),
+            category: self.dummy_category(),
+        }
     }
 
     pub fn jump_time(&self, seconds: u64) {
@@ -93,6 +141,15 @@ impl TestContext {
         self.env.ledger().set(ledger);
     }
 
+    /// Move the ledger to one second past `project_id`'s deadline, the
+    /// first moment the project counts as expired.
+    pub fn advance_past_deadline(&self, project_id: u64) {
+        let deadline = self.client.get_project(&project_id).deadline;
+        let mut ledger = self.env.ledger().get();
+        ledger.timestamp = deadline + 1;
+        self.env.ledger().set(ledger);
+    }
+
     pub fn generate_address(&self) -> Address {
         Address::generate(&self.env)
     }
@@ -0,0 +1,118 @@
+extern crate std;
+
+use soroban_sdk::{token, Address, Vec};
+
+use crate::{test_utils::TestContext, types::Project, Error, ProjectStatus};
+
+const DURATION: u64 = 1_000;
+
+fn try_register_vesting(
+    ctx: &TestContext,
+    token: &Address,
+    duration: u64,
+) -> Result<Project, soroban_sdk::Error> {
+    let tokens = Vec::from_array(&ctx.env, [token.clone()]);
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    ctx.client
+        .try_register_project_with_vesting(
+            &ctx.manager,
+            &tokens,
+            &1000,
+            &ctx.dummy_proof(),
+            &deadline,
+            &ctx.dummy_metadata(),
+            &duration,
+        )
+        .map(|project| project.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+/// Register, fully fund and verify a vesting project.
+fn setup_vested_project(ctx: &TestContext) -> (Project, token::Client<'static>) {
+    let (token, sac) = ctx.create_token();
+    let project = try_register_vesting(ctx, &token.address, DURATION).unwrap();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_preimage());
+    (project, token)
+}
+
+#[test]
+fn test_release_keeps_funds_until_claimed() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vested_project(&ctx);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(
+        ctx.client
+            .try_claim_vested(&ctx.manager, &project.id, &token.address),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_claims_follow_linear_schedule() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vested_project(&ctx);
+
+    ctx.jump_time(DURATION / 4);
+    assert_eq!(ctx.client.get_claimable(&project.id, &token.address), 250);
+    assert_eq!(
+        ctx.client
+            .claim_vested(&ctx.manager, &project.id, &token.address),
+        250
+    );
+    assert_eq!(token.balance(&ctx.manager), 250);
+
+    ctx.jump_time(DURATION / 4);
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(token.balance(&ctx.manager), 500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+
+    // Past the end the remainder unlocks, and nothing more after that.
+    ctx.jump_time(DURATION * 10);
+    assert_eq!(
+        ctx.client
+            .claim_vested(&ctx.manager, &project.id, &token.address),
+        500
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    assert_eq!(
+        ctx.client
+            .try_claim_vested(&ctx.manager, &project.id, &token.address),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_only_creator_claims() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vested_project(&ctx);
+    ctx.jump_time(DURATION);
+
+    assert_eq!(
+        ctx.client
+            .try_claim_vested(&ctx.generate_address(), &project.id, &token.address),
+        Err(Ok(Error::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_zero_vesting_duration_rejected() {
+    let ctx = TestContext::new();
+    let token = ctx.generate_address();
+    assert_eq!(
+        try_register_vesting(&ctx, &token, 0),
+        Err(Error::InvalidSchedule.into())
+    );
+}
@@ -0,0 +1,125 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::FundsWithdrawn;
+use crate::test_utils::TestContext;
+use crate::Error;
+
+#[test]
+fn test_withdraw_partial_amount() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    ctx.client
+        .withdraw(&project.id, &donator, &token.address, &200);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("withdraw").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: FundsWithdrawn = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        FundsWithdrawn {
+            project_id: project.id,
+            donator: donator.clone(),
+            token: token.address.clone(),
+            amount: 200,
+        }
+    );
+
+    assert_eq!(token.balance(&donator), 200);
+    assert_eq!(token.balance(&ctx.client.address), 300);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #24)")]
+fn test_withdraw_more_than_deposited_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+    ctx.client
+        .withdraw(&project.id, &donator, &token.address, &301);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_withdraw_from_active_project_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .withdraw(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_withdraw_zero_amount_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    ctx.client
+        .withdraw(&project.id, &donator, &token.address, &0);
+}
+
+#[test]
+fn test_withdraw_allowed_until_deadline_passes() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    // Deposits are still accepted at the deadline, so withdrawals are too.
+    let now = ctx.env.ledger().timestamp();
+    ctx.jump_time(project.deadline - now);
+    ctx.client
+        .withdraw(&project.id, &donator, &token.address, &100);
+
+    ctx.jump_time(1);
+    assert_eq!(
+        ctx.client
+            .try_withdraw(&project.id, &donator, &token.address, &100),
+        Err(Ok(Error::ProjectExpired.into()))
+    );
+}
+
+#[test]
+fn test_withdraw_blocked_while_paused() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    ctx.client.pause(&ctx.admin);
+    assert_eq!(
+        ctx.client
+            .try_withdraw(&project.id, &donator, &token.address, &100),
+        Err(Ok(Error::ProtocolPaused.into()))
+    );
+}
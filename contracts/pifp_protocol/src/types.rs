@@ -8,7 +8,8 @@
 //!
 //! A `Project` is internally stored as two separate ledger entries:
 //!
-//! - [`ProjectConfig`] — written once at registration; never mutated.
+//! - [`ProjectConfig`] — written at registration; only rewritten when the
+//!   creator extends the deadline, updates the goal or adds a token.
 //! - [`ProjectState`] — written on every deposit and on verification.
 //!
 //! The public API exposes the reconstructed [`Project`] struct for convenience.
@@ -21,17 +22,24 @@
 //! Funding ──► Active ──► Completed
 //!     └──────────────────►┘
 //!     └──► Expired
+//!     └──► Cancelled
 //! Active ──► Expired
+//! Funding | Active ──► PendingRelease ──► Completed
+//! Completed ──► PartiallyReleased ──► Completed
 //! ```
 //!
 //! Backward transitions and transitions out of terminal states (`Completed`,
-//! `Expired`) are rejected by `verify_and_release`.
+//! `Expired`, `Cancelled`) are rejected by `verify_and_release`. The
+//! exceptions are `cancel_release`, which returns a `PendingRelease` project
+//! to `Funding` or `Active` during its challenge period, and a safe release
+//! whose transfers did not all go through, which leaves `Completed` for
+//! `PartiallyReleased` until `retry_release` pays the rest.
 
-use soroban_sdk::{contracttype, Address, BytesN, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Current lifecycle state of a funding project.
 #[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ProjectStatus {
     /// Accepting donations, goal not yet reached.
     Funding,
@@ -41,9 +49,49 @@ pub enum ProjectStatus {
     Completed,
     /// Deadline passed without reaching goal or verification.
     Expired,
+    /// Creator aborted the project while it was still funding; donors
+    /// may reclaim their deposits.
+    Cancelled,
+    /// Verified, but funds stay locked until the challenge period ends and
+    /// `finalize_release` is called.
+    PendingRelease,
+    /// Released in safe mode, but some transfers failed; they are kept as
+    /// `PendingPayout`s until `retry_release` pays them.
+    PartiallyReleased,
 }
 
-/// Immutable project configuration, written once at registration.
+/// Where a project stands relative to its deadline — returned by
+/// `get_deadline_status`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeadlineStatus {
+    /// More than 24 hours until the deadline.
+    Upcoming,
+    /// The deadline is at most 24 hours away.
+    Imminent,
+    /// Past the deadline but within the grace period; deposits are still
+    /// accepted and the project cannot yet be expired.
+    InGracePeriod,
+    /// Past the deadline and any grace period.
+    Passed,
+}
+
+/// What `deposit_with_nonce` did with a submission.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NonceOutcome {
+    /// The nonce was new and the deposit was made.
+    Deposited,
+    /// The nonce equals the donor's latest one: a retry of the last deposit.
+    Replayed,
+    /// The nonce is below the donor's latest one and was not processed.
+    Stale,
+}
+
+/// Project configuration, written at registration.
+///
+/// Only `deadline`, `goal` and `accepted_tokens` may change afterwards, via
+/// `extend_deadline`, `update_goal` and `add_accepted_token`.
 ///
 /// Stored separately from mutable state to reduce write costs on deposits
 /// and verification (only ~20 bytes for state vs ~150 bytes for the full struct).
@@ -56,6 +104,21 @@ pub struct ProjectConfig {
     pub goal: i128,
     pub proof_hash: BytesN<32>,
     pub deadline: u64,
+    pub hard_cap: Option<i128>,
+}
+
+/// Human-readable project context, stored apart from the financial entries.
+///
+/// Projects registered before metadata existed have no entry and read back
+/// with an empty name, URI and category.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectMetadata {
+    pub name: Symbol,
+    /// Link to off-chain details, e.g. an IPFS URI.
+    pub metadata_uri: String,
+    /// Grouping tag used by `list_projects_by_category`.
+    pub category: Symbol,
 }
 
 /// Mutable project state, updated on deposits and verification.
@@ -67,6 +130,10 @@ pub struct ProjectState {
     pub status: ProjectStatus,
     /// Count of unique (donator, token) pairs that have deposited.
     pub donation_count: u32,
+    /// Count of addresses currently holding a positive balance.
+    pub donor_count: u32,
+    /// Smallest accepted deposit amount; `0` means no floor.
+    pub min_deposit: i128,
 }
 
 /// Full on-chain representation of a funding project.
@@ -81,21 +148,40 @@ pub struct Project {
     /// Address that registered and will receive released funds.
     pub creator: Address,
     /// Ordered list of SAC token addresses this project accepts.
-    /// Set once at registration; cannot be changed after creation.
-    /// Length: 1–10 tokens.
+    /// Set at registration; the creator may append tokens later.
+    /// Length: 1 to the admin-set maximum (10 by default).
     pub accepted_tokens: soroban_sdk::Vec<Address>,
     /// Funding goal expressed in the *first* accepted token's units.
-    /// Used as a reference denominator; cross-token goals require off-chain logic.
+    /// Deposits of other tokens count toward it at their oracle-set price.
     pub goal: i128,
     /// Content hash (e.g. IPFS CID digest) of proof artifacts.
     pub proof_hash: soroban_sdk::BytesN<32>,
     /// Ledger timestamp by which the project must be completed.
     pub deadline: u64,
+    /// Upper bound on the priced value raised, in goal units.
+    /// `None` means uncapped.
+    pub hard_cap: Option<i128>,
     /// Current lifecycle state.
     pub status: ProjectStatus,
     /// Count of unique (token, donator) pairs that have donated.
     /// Informational; incremented on each new deposit.
     pub donation_count: u32,
+    /// Count of unique donor addresses with a positive balance in any
+    /// token. Withdrawing down to zero decrements it; refunds do not, so
+    /// an expired project still reports who backed it.
+    pub donor_count: u32,
+    /// Smallest accepted deposit amount, set by the creator.
+    /// Defaults to `0` (no floor).
+    pub min_deposit: i128,
+    /// Latest impact metric reported after completion, e.g. beneficiaries
+    /// reached or tonnes of CO2 avoided. `0` until one is reported.
+    pub reported_impact: i128,
+    /// Short display name chosen at registration.
+    pub name: Symbol,
+    /// Link to off-chain project details; updatable by the creator.
+    pub metadata_uri: String,
+    /// Grouping tag chosen at registration.
+    pub category: Symbol,
 }
 
 impl Project {
@@ -118,10 +204,153 @@ pub struct TokenBalance {
     pub balance: i128,
 }
 
+/// A donator's refundable balance of one token — returned by
+/// `get_donor_contribution`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonorBalance {
+    pub token: Address,
+    pub balance: i128,
+}
+
 /// Full balance view returned by `get_project_balances`.
+///
+/// `balances` holds one entry per accepted token, in `accepted_tokens` order.
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct ProjectBalances {
     pub project_id: u64,
     pub balances: Vec<TokenBalance>,
 }
+
+/// Compact project view returned by `get_project_summary` for list pages.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectSummary {
+    pub project_id: u64,
+    pub status: ProjectStatus,
+    pub goal: i128,
+    /// Price-normalized value raised, in goal units.
+    pub raised: i128,
+    /// Number of accepted tokens.
+    pub token_count: u32,
+    /// Unique donors currently holding a balance.
+    pub donor_count: u32,
+    pub deadline: u64,
+}
+
+/// Protocol-wide metrics returned by `get_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolStats {
+    /// Projects registered so far.
+    pub project_count: u64,
+    pub funding: u32,
+    pub active: u32,
+    pub completed: u32,
+    pub expired: u32,
+    pub cancelled: u32,
+    pub pending_release: u32,
+    pub partially_released: u32,
+    /// Sum of all project balances, one entry per token with funds locked.
+    pub total_locked: Vec<TokenBalance>,
+}
+
+/// One transfer of a release: the protocol fee or a beneficiary's share.
+///
+/// Transfers that fail during a safe release are stored as these until
+/// `retry_release` succeeds in sending them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPayout {
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    /// Whether this is the protocol fee owed to the treasury.
+    pub is_fee: bool,
+}
+
+/// Every admin-set protocol parameter, returned by `get_config`.
+///
+/// Unset parameters hold the same defaults their individual getters return.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlobalConfig {
+    pub version: u32,
+    pub paused: bool,
+    pub fee_bps: u32,
+    pub treasury: Option<Address>,
+    /// `(min_completed, fee_bps)` veteran fee tiers, ascending.
+    pub fee_tiers: Vec<(u32, u32)>,
+    pub native_token: Option<Address>,
+    pub min_funding_window: u64,
+    pub unique_proofs: bool,
+    pub safe_release: bool,
+    /// Tokens projects may accept; empty when any token is allowed.
+    pub allowed_tokens: Vec<Address>,
+    pub grace_period: u64,
+    pub max_tokens: u32,
+    pub min_goal: i128,
+    /// Most live projects per creator; `None` when unlimited.
+    pub max_active_per_creator: Option<u32>,
+    pub refund_window: u64,
+    pub challenge_period: u64,
+    pub match_ratio_bps: u32,
+    /// Token oracles bond in, if a bond was configured.
+    pub oracle_bond_token: Option<Address>,
+    /// Stake oracles need in `oracle_bond_token` to verify.
+    pub min_oracle_stake: i128,
+}
+
+/// One entry of the on-chain activity log returned by `get_recent_actions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecentAction {
+    /// `deposit`, `release`, `expire` or `refund`.
+    pub action: Symbol,
+    pub project_id: u64,
+    pub timestamp: u64,
+}
+
+/// A donator's standing order of repeated deposits into one project.
+///
+/// Stored per project and drawn down by `execute_due_recurring`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringSchedule {
+    pub donator: Address,
+    pub token: Address,
+    /// Amount deposited on each run.
+    pub amount: i128,
+    /// Seconds between runs.
+    pub interval_secs: u64,
+    /// Ledger timestamp at or after which the next run is due.
+    pub next_run: u64,
+    /// Runs left before the schedule is removed.
+    pub remaining: u32,
+}
+
+/// One token's share of a vesting project's released funds.
+///
+/// Created when the project completes and drawn down by `claim_vested`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrant {
+    /// Balance held when the project completed, before fees.
+    pub total: i128,
+    /// Amount already claimed by the creator, before fees.
+    pub claimed: i128,
+}
+
+/// Sponsor funds set aside to match donations in one token of a project.
+///
+/// Kept apart from the project's token balance, so refunds never draw on
+/// it; whatever is not paid out as a match goes back to `sponsor`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchPool {
+    /// Address that funded the pool and receives any unused match.
+    pub sponsor: Address,
+    /// Amount held in the pool.
+    pub balance: i128,
+}